@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{
+  Path,
+  PathBuf
+};
+use std::time::{
+  Duration,
+  SystemTime
+};
+
+use ::ggez::{
+  GameResult,
+  Context
+};
+
+use ::deltatime::Deltatime;
+use super::{
+  Asset,
+  Assets
+};
+
+type ReloadFn<C> = fn(&mut Assets, &mut C, &Path) -> GameResult<()>;
+type PathsFn     = fn(&Assets) -> Vec<PathBuf>;
+
+fn reload_asset<C: ?Sized, T: Asset<C>>(assets: &mut Assets, ctx: &mut C, path: &Path) -> GameResult<()> {
+  match path.to_str().and_then( |path| assets.handle::<T>(path) ) {
+    Some(handle) => assets.reload(ctx, &handle),
+    None         => Ok(())
+  }
+}
+
+fn asset_paths<T: 'static>(assets: &Assets) -> Vec<PathBuf> {
+  assets.paths::<T>().into_iter().map(Path::to_path_buf).collect()
+}
+
+struct Watch<C: ?Sized> {
+  dir:    PathBuf,
+  reload: ReloadFn<C>,
+  paths:  PathsFn
+}
+
+/// The result of reloading one asset.
+#[derive(Debug)]
+pub struct Reloaded {
+  /// The asset path, like `/images/player.png`.
+  pub path:   PathBuf,
+  /// The error if the file couldn't be loaded; the old asset is kept then.
+  pub result: GameResult<()>
+}
+
+/// Watches asset directories during development, and reloads loaded assets in place when their files change,
+/// by polling their modification times. Only enabled with the `hot-reload` cargo feature.
+/// Code using a reloaded asset notices the change through `Assets::generation` of its handle.
+/// # Example
+///   ```no_run
+///   # extern crate ggez;
+///   # extern crate noframe;
+///   # fn run(ctx: &mut ggez::Context, assets: &mut noframe::assets::Assets) {
+///   use noframe::assets::HotReload;
+///   use ggez::graphics::Image;
+///
+///   let mut hot_reload = HotReload::new("resources")
+///     .watch::<Image>("/images")
+///     .watch::<String>("/config");
+///   // Every tick:
+///   for reloaded in hot_reload.poll(assets, ctx) {
+///     println!("Reloaded {}: {:?}", reloaded.path.display(), reloaded.result);
+///   }
+///   # }
+///   # fn main() {}
+///   ```
+pub struct HotReload<C: ?Sized = Context> {
+  root:     PathBuf,
+  watches:  Vec<Watch<C>>,
+  modified: HashMap<(usize, PathBuf), SystemTime>,
+  interval: Duration,
+  elapsed:  Duration
+}
+
+impl<C: ?Sized> HotReload<C> {
+  /// Returns a new `HotReload` for assets in the `root` directory on disk,
+  /// the directory ggez loads resources from.
+  pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+    Self {
+      root:     root.into(),
+      watches:  Vec::new(),
+      modified: HashMap::new(),
+      interval: Duration::from_millis(500),
+      elapsed:  Duration::new(0, 0)
+    }
+  }
+
+  /// Watches loaded assets of type `T` in the asset directory `dir`, like `/images`.
+  pub fn watch<T: Asset<C>>(mut self, dir: &str) -> Self {
+    self.watches.push(Watch {
+      dir:    PathBuf::from(dir),
+      reload: reload_asset::<C, T>,
+      paths:  asset_paths::<T>
+    });
+    self
+  }
+
+  /// Sets how often `update` polls the files; every 500 milliseconds by default.
+  pub fn with_interval(mut self, interval: Duration) -> Self {
+    self.interval = interval;
+    self
+  }
+
+  /// Returns the file on disk of the asset path.
+  pub fn file(&self, path: &Path) -> PathBuf {
+    self.root.join(path.strip_prefix("/").unwrap_or(path))
+  }
+
+  /// Polls the files once the interval has passed; see `poll`.
+  pub fn update(&mut self, dt: &Deltatime, assets: &mut Assets, ctx: &mut C) -> Vec<Reloaded> {
+    self.elapsed += dt.get();
+    if self.elapsed < self.interval {
+      return Vec::new();
+    }
+    self.elapsed = Duration::new(0, 0);
+    self.poll(assets, ctx)
+  }
+
+  /// Reloads all watched assets whose files were modified since the last poll.
+  /// Assets seen for the first time are only remembered, not reloaded.
+  pub fn poll(&mut self, assets: &mut Assets, ctx: &mut C) -> Vec<Reloaded> {
+    let mut reloaded = Vec::new();
+    for index in 0 .. self.watches.len() {
+      let (reload, mut paths) = {
+        let watch = &self.watches[index];
+        let mut paths = (watch.paths)(assets);
+        paths.retain( |path| path.starts_with(&watch.dir) );
+        (watch.reload, paths)
+      };
+      paths.sort();
+      for path in paths {
+        let modified = match fs::metadata(self.file(&path)).and_then( |metadata| metadata.modified() ) {
+          Ok(modified) => modified,
+          Err(_)       => continue
+        };
+        match self.modified.insert((index, path.clone()), modified) {
+          Some(previous) if previous != modified => {
+            let result = reload(assets, ctx, &path);
+            reloaded.push(Reloaded { path, result });
+          }
+          _ => ()
+        }
+      }
+    }
+    reloaded
+  }
+}
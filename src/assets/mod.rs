@@ -0,0 +1,422 @@
+//! Loads images, fonts, sounds and other assets once, and hands out typed `Handle`s to them.
+//! Assets are cached by path, so loading the same path again returns the same handle,
+//! and they are reference counted, so they are unloaded when the last user releases them.
+//! Assets are loaded with ggez by default, or through `Resources` from a `Pack` or a loose directory.
+//! With the `hot-reload` cargo feature, `HotReload` reloads assets when their files change.
+
+use std::any::{
+  Any,
+  TypeId
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{
+  Hash,
+  Hasher
+};
+use std::io::Read;
+use std::marker::PhantomData;
+use std::path::{
+  Path,
+  PathBuf
+};
+
+use ::ggez::{
+  GameResult,
+  GameError,
+  Context,
+  audio::SoundData,
+  graphics::{
+    Font,
+    Image
+  }
+};
+
+/// Something that can be loaded from a path, with a loading context `C`; a ggez `Context` by default.
+pub trait Asset<C: ?Sized = Context>: Sized + 'static {
+  fn load(ctx: &mut C, path: &Path) -> GameResult<Self>;
+}
+
+impl Asset for Image {
+  fn load(ctx: &mut Context, path: &Path) -> GameResult<Self> {
+    Image::new(ctx, path)
+  }
+}
+
+impl Asset for SoundData {
+  fn load(ctx: &mut Context, path: &Path) -> GameResult<Self> {
+    SoundData::new(ctx, path)
+  }
+}
+
+/// The contents of a font file; ggez fonts are created with a fixed size, so this creates them with `font`.
+#[derive(Debug, Clone)]
+pub struct FontData {
+  name:  String,
+  bytes: Vec<u8>
+}
+
+impl FontData {
+  pub fn new(name: &str, bytes: Vec<u8>) -> Self {
+    Self {
+      name: name.to_string(),
+      bytes
+    }
+  }
+
+  /// Returns a new ggez `Font` of this font, which draws lines that are `size` pixels high.
+  pub fn font(&self, size: u32) -> GameResult<Font> {
+    Font::from_bytes_px(&self.name, &self.bytes, size)
+  }
+}
+
+impl Asset for FontData {
+  fn load(ctx: &mut Context, path: &Path) -> GameResult<Self> {
+    let mut bytes = Vec::new();
+    ctx.filesystem.open(path)?.read_to_end(&mut bytes)?;
+    Ok(Self::new(&path.to_string_lossy(), bytes))
+  }
+}
+
+/// Text files, for example config files to parse after loading.
+impl Asset for String {
+  fn load(ctx: &mut Context, path: &Path) -> GameResult<Self> {
+    let mut text = String::new();
+    ctx.filesystem.open(path)?.read_to_string(&mut text)?;
+    Ok(text)
+  }
+}
+
+/// A typed reference to a loaded asset in `Assets`.
+pub struct Handle<T> {
+  id:      u64,
+  phantom: PhantomData<fn() -> T>
+}
+
+impl<T> Handle<T> {
+  fn new(id: u64) -> Self {
+    Self { id, phantom: PhantomData }
+  }
+}
+
+impl<T> Clone for Handle<T> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+  }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> Hash for Handle<T> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.id.hash(state);
+  }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Handle({})", self.id)
+  }
+}
+
+struct Entry<T> {
+  path:       PathBuf,
+  asset:      T,
+  refs:       usize,
+  generation: u64
+}
+
+struct Store<T> {
+  entries: HashMap<u64, Entry<T>>,
+  paths:   HashMap<PathBuf, u64>
+}
+
+impl<T> Store<T> {
+  fn new() -> Self {
+    Self {
+      entries: HashMap::new(),
+      paths:   HashMap::new()
+    }
+  }
+}
+
+/// Holds loaded assets of any type implementing `Asset`.
+/// # Example
+///   ```no_run
+///   # extern crate ggez;
+///   # extern crate noframe;
+///   # fn run(ctx: &mut ggez::Context) -> ggez::GameResult<()> {
+///   use noframe::assets::Assets;
+///   use ggez::graphics::Image;
+///
+///   let mut assets = Assets::new();
+///   let player = assets.load::<_, Image>(ctx, "/images/player.png")?;
+///   let again  = assets.load::<_, Image>(ctx, "/images/player.png")?;
+///   assert_eq!(player, again);
+///   let image: &Image = assets.get(&player).unwrap();
+///   # Ok(())
+///   # }
+///   # fn main() {}
+///   ```
+pub struct Assets {
+  stores:  HashMap<TypeId, Box<dyn Any>>,
+  next_id: u64
+}
+
+impl Assets {
+  pub fn new() -> Self {
+    Self {
+      stores:  HashMap::new(),
+      next_id: 0
+    }
+  }
+
+  fn store<T: 'static>(&self) -> Option<&Store<T>> {
+    self.stores.get(&TypeId::of::<T>()).and_then( |store| store.downcast_ref() )
+  }
+
+  fn store_mut<T: 'static>(&mut self) -> Option<&mut Store<T>> {
+    self.stores.get_mut(&TypeId::of::<T>()).and_then( |store| store.downcast_mut() )
+  }
+
+  /// Loads the asset at `path`, or returns the handle of the already loaded asset.
+  /// Either way, the asset's reference count is increased; see `release`.
+  pub fn load<C: ?Sized, T: Asset<C>>(&mut self, ctx: &mut C, path: &str) -> GameResult<Handle<T>> {
+    let path = PathBuf::from(path);
+    if let Some(store) = self.store_mut::<T>() {
+      if let Some(&id) = store.paths.get(&path) {
+        if let Some(entry) = store.entries.get_mut(&id) {
+          entry.refs += 1;
+        }
+        return Ok(Handle::new(id));
+      }
+    }
+    let asset = T::load(ctx, &path).map_err( |error| GameError::ResourceLoadError(
+      format!("Could not load asset \"{}\": {}", path.display(), error)
+    ))?;
+    Ok(self.insert(path, asset))
+  }
+
+  /// Adds an asset created in code under `path`, with a reference count of `1`.
+  /// An asset of the same type at the same path is replaced; its handle stays valid.
+  pub fn insert<T: 'static, P: Into<PathBuf>>(&mut self, path: P, asset: T) -> Handle<T> {
+    let path = path.into();
+    let new_id = self.next_id;
+    let store = self.stores.entry(TypeId::of::<T>())
+      .or_insert_with( || Box::new(Store::<T>::new()) )
+      .downcast_mut::<Store<T>>()
+      .expect("Asset store has the wrong type");
+    let id = *store.paths.entry(path.clone()).or_insert(new_id);
+    let (refs, generation) = store.entries.get(&id).map_or((1, 0), |entry| (entry.refs, entry.generation + 1));
+    store.entries.insert(id, Entry { path, asset, refs, generation });
+    if id == new_id {
+      self.next_id += 1;
+    }
+    Handle::new(id)
+  }
+
+  pub fn get<T: 'static>(&self, handle: &Handle<T>) -> Option<&T> {
+    self.store::<T>()?.entries.get(&handle.id).map( |entry| &entry.asset )
+  }
+
+  pub fn get_mut<T: 'static>(&mut self, handle: &Handle<T>) -> Option<&mut T> {
+    self.store_mut::<T>()?.entries.get_mut(&handle.id).map( |entry| &mut entry.asset )
+  }
+
+  /// Loads the asset again from its path and replaces it in place, keeping its handle and reference count.
+  /// If loading fails, the old asset is kept.
+  pub fn reload<C: ?Sized, T: Asset<C>>(&mut self, ctx: &mut C, handle: &Handle<T>) -> GameResult<()> {
+    let path = self.path(handle).map(Path::to_path_buf).ok_or_else( || GameError::ResourceLoadError(
+      format!("Could not reload asset {:?}, it isn't loaded", handle)
+    ))?;
+    let asset = T::load(ctx, &path).map_err( |error| GameError::ResourceLoadError(
+      format!("Could not reload asset \"{}\": {}", path.display(), error)
+    ))?;
+    self.insert(path, asset);
+    Ok(())
+  }
+
+  /// Returns how often the asset was replaced since it was first loaded, by `reload` or `insert`.
+  /// Code that builds something from an asset can keep the generation, and rebuild when it changes.
+  pub fn generation<T: 'static>(&self, handle: &Handle<T>) -> Option<u64> {
+    self.store::<T>()?.entries.get(&handle.id).map( |entry| entry.generation )
+  }
+
+  /// Returns the paths of all loaded assets of the type.
+  pub fn paths<T: 'static>(&self) -> Vec<&Path> {
+    self.store::<T>().map_or_else(Vec::new, |store| store.paths.keys().map(PathBuf::as_path).collect() )
+  }
+
+  /// Returns the handle of the loaded asset at `path`, without loading it or changing its reference count.
+  pub fn handle<T: 'static>(&self, path: &str) -> Option<Handle<T>> {
+    self.store::<T>()?.paths.get(Path::new(path)).map( |&id| Handle::new(id) )
+  }
+
+  pub fn path<T: 'static>(&self, handle: &Handle<T>) -> Option<&Path> {
+    self.store::<T>()?.entries.get(&handle.id).map( |entry| entry.path.as_path() )
+  }
+
+  pub fn is_loaded<T: 'static>(&self, handle: &Handle<T>) -> bool {
+    self.get(handle).is_some()
+  }
+
+  /// Returns the reference count of the asset, `0` if it isn't loaded.
+  pub fn refs<T: 'static>(&self, handle: &Handle<T>) -> usize {
+    self.store::<T>().and_then( |store| store.entries.get(&handle.id) ).map_or(0, |entry| entry.refs)
+  }
+
+  /// Increases the reference count of the asset, for another user of the handle.
+  pub fn retain<T: 'static>(&mut self, handle: &Handle<T>) {
+    if let Some(entry) = self.store_mut::<T>().and_then( |store| store.entries.get_mut(&handle.id) ) {
+      entry.refs += 1;
+    }
+  }
+
+  /// Decreases the reference count of the asset and unloads it when it reaches `0`.
+  /// Returns `true` if the asset was unloaded.
+  pub fn release<T: 'static>(&mut self, handle: &Handle<T>) -> bool {
+    let refs = match self.store_mut::<T>().and_then( |store| store.entries.get_mut(&handle.id) ) {
+      Some(entry) => {
+        entry.refs = entry.refs.saturating_sub(1);
+        entry.refs
+      }
+      None => return false
+    };
+    if refs == 0 {
+      self.unload(handle);
+    }
+    refs == 0
+  }
+
+  /// Unloads the asset, regardless of its reference count, and returns it.
+  pub fn unload<T: 'static>(&mut self, handle: &Handle<T>) -> Option<T> {
+    let store = self.store_mut::<T>()?;
+    let entry = store.entries.remove(&handle.id)?;
+    store.paths.remove(&entry.path);
+    Some(entry.asset)
+  }
+
+  /// Returns the number of loaded assets of the type.
+  pub fn count<T: 'static>(&self) -> usize {
+    self.store::<T>().map_or(0, |store| store.entries.len())
+  }
+}
+
+impl Default for Assets {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A list of assets to load before they are needed, one at a time, so a loading screen can show the progress.
+/// # Example
+///   ```no_run
+///   # extern crate ggez;
+///   # extern crate noframe;
+///   # fn run(ctx: &mut ggez::Context) -> ggez::GameResult<()> {
+///   use noframe::assets::{ Assets, Preload };
+///   use ggez::{ audio::SoundData, graphics::Image };
+///
+///   let mut assets = Assets::new();
+///   let mut preload = Preload::new()
+///     .with::<Image>("/images/player.png")
+///     .with::<SoundData>("/sounds/jump.ogg");
+///   while !preload.is_done() {
+///     preload.step(&mut assets, ctx)?;
+///     println!("Loading... {}%", (preload.progress() * 100.0) as u32);
+///   }
+///   # Ok(())
+///   # }
+///   # fn main() {}
+///   ```
+pub struct Preload<C: ?Sized = Context> {
+  items:  Vec<(String, LoadFn<C>)>,
+  loaded: usize
+}
+
+type LoadFn<C> = fn(&mut Assets, &mut C, &str) -> GameResult<()>;
+
+fn preload_asset<C: ?Sized, T: Asset<C>>(assets: &mut Assets, ctx: &mut C, path: &str) -> GameResult<()> {
+  assets.load::<C, T>(ctx, path).map( |_| () )
+}
+
+impl<C: ?Sized> Preload<C> {
+  pub fn new() -> Self {
+    Self {
+      items:  Vec::new(),
+      loaded: 0
+    }
+  }
+
+  /// Adds the asset at `path` to the list.
+  pub fn with<T: Asset<C>>(mut self, path: &str) -> Self {
+    self.items.push((path.to_string(), preload_asset::<C, T>));
+    self
+  }
+
+  /// Loads the next asset; returns the error of a missing or broken asset,
+  /// after which the remaining assets can still be loaded.
+  pub fn step(&mut self, assets: &mut Assets, ctx: &mut C) -> GameResult<()> {
+    if let Some((path, load)) = self.items.get(self.loaded) {
+      self.loaded += 1;
+      load(assets, ctx, path)?;
+    }
+    Ok(())
+  }
+
+  /// Loads all remaining assets; stops at the first error.
+  pub fn load_all(&mut self, assets: &mut Assets, ctx: &mut C) -> GameResult<()> {
+    while !self.is_done() {
+      self.step(assets, ctx)?;
+    }
+    Ok(())
+  }
+
+  /// Returns the path of the asset `step` loads next.
+  pub fn next_path(&self) -> Option<&str> {
+    self.items.get(self.loaded).map( |(path, _)| path.as_str() )
+  }
+
+  /// Returns how many assets were loaded (or failed to load), from `0.0` to `1.0`.
+  pub fn progress(&self) -> f32 {
+    if self.items.is_empty() { return 1.0; }
+    self.loaded as f32 / self.items.len() as f32
+  }
+
+  pub fn is_done(&self) -> bool {
+    self.loaded >= self.items.len()
+  }
+}
+
+impl<C: ?Sized> Default for Preload<C> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+pub mod resources;
+
+pub use self::resources::{
+  Loader,
+  Resources
+};
+
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+
+#[cfg(feature = "hot-reload")]
+pub use self::hot_reload::{
+  HotReload,
+  Reloaded
+};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,176 @@
+use std::convert::TryFrom;
+use std::fs::{
+  self,
+  File
+};
+use std::io::{
+  self,
+  BufReader
+};
+use std::path::{
+  Component,
+  Path,
+  PathBuf
+};
+
+use ::ggez::{
+  GameResult,
+  GameError,
+  Context,
+  audio::SoundData,
+  graphics::Image
+};
+use ::image;
+
+use ::pack::Pack;
+use super::{
+  Asset,
+  FontData
+};
+
+enum Location {
+  Dir(PathBuf),
+  Pack(Pack<BufReader<File>>)
+}
+
+/// Reads resource files from a pack or a loose directory, whichever the game ships with.
+/// Asset paths like `/images/player.png` work the same for both.
+/// # Example
+///   ```no_run
+///   # extern crate ggez;
+///   # extern crate noframe;
+///   # fn run(ctx: &mut ggez::Context) -> ggez::GameResult<()> {
+///   use noframe::assets::{ Assets, Loader, Resources };
+///   use ggez::graphics::Image;
+///
+///   let mut resources = Resources::open("resources", "resources.pack")?;
+///   let mut assets = Assets::new();
+///   let player = assets.load::<_, Image>(&mut Loader::new(ctx, &mut resources), "/images/player.png")?;
+///   # Ok(())
+///   # }
+///   # fn main() {}
+///   ```
+pub struct Resources {
+  location: Location
+}
+
+impl Resources {
+  /// Reads resources from the directory on disk.
+  pub fn dir<P: Into<PathBuf>>(dir: P) -> Self {
+    Self { location: Location::Dir(dir.into()) }
+  }
+
+  /// Reads resources from the pack file.
+  pub fn pack<P: AsRef<Path>>(file: P) -> GameResult<Self> {
+    Ok(Self { location: Location::Pack(Pack::open(file)?) })
+  }
+
+  /// Reads resources from the pack file if it exists, otherwise from the directory.
+  pub fn open<D: Into<PathBuf>, P: AsRef<Path>>(dir: D, pack: P) -> GameResult<Self> {
+    if pack.as_ref().is_file() {
+      Self::pack(pack)
+    } else {
+      Ok(Self::dir(dir))
+    }
+  }
+
+  pub fn is_pack(&self) -> bool {
+    match self.location {
+      Location::Pack(_) => true,
+      Location::Dir(_)  => false
+    }
+  }
+
+  /// Returns the file of the asset path in the directory, or `None` if the path contains `..`,
+  /// so assets can't be read from outside of the directory.
+  fn file(dir: &Path, path: &Path) -> Option<PathBuf> {
+    if path.components().any( |component| component == Component::ParentDir ) {
+      return None;
+    }
+    Some(dir.join(path.strip_prefix("/").unwrap_or(path)))
+  }
+
+  pub fn exists(&self, path: &Path) -> bool {
+    match self.location {
+      Location::Dir(ref dir)   => Self::file(dir, path).map_or(false, |file| file.is_file() ),
+      Location::Pack(ref pack) => pack.contains(&path.to_string_lossy())
+    }
+  }
+
+  /// Reads the whole file at the asset path.
+  pub fn read(&mut self, path: &Path) -> GameResult<Vec<u8>> {
+    let result = match self.location {
+      Location::Dir(ref dir)       => match Self::file(dir, path) {
+        Some(file) => fs::read(file),
+        None       => Err(io::Error::new(io::ErrorKind::InvalidInput, "asset paths must not contain \"..\""))
+      },
+      Location::Pack(ref mut pack) => pack.read(&path.to_string_lossy())
+    };
+    result.map_err( |error| GameError::ResourceNotFound(
+      path.display().to_string(),
+      vec![(path.to_path_buf(), GameError::from(error))]
+    ))
+  }
+}
+
+impl Asset<Resources> for Vec<u8> {
+  fn load(resources: &mut Resources, path: &Path) -> GameResult<Self> {
+    resources.read(path)
+  }
+}
+
+impl Asset<Resources> for String {
+  fn load(resources: &mut Resources, path: &Path) -> GameResult<Self> {
+    String::from_utf8(resources.read(path)?).map_err( |_| GameError::ResourceLoadError(
+      format!("\"{}\" is not valid UTF-8", path.display())
+    ))
+  }
+}
+
+/// The loading context for assets which need ggez, read through `Resources`.
+pub struct Loader<'a> {
+  pub ctx:       &'a mut Context,
+  pub resources: &'a mut Resources
+}
+
+impl<'a> Loader<'a> {
+  pub fn new(ctx: &'a mut Context, resources: &'a mut Resources) -> Self {
+    Self { ctx, resources }
+  }
+}
+
+/// PNG images only.
+impl<'a> Asset<Loader<'a>> for Image {
+  fn load(loader: &mut Loader<'a>, path: &Path) -> GameResult<Self> {
+    let bytes = loader.resources.read(path)?;
+    let image = image::load_from_memory(&bytes).map_err( |error| GameError::ResourceLoadError(
+      format!("\"{}\" is not a valid image: {}", path.display(), error)
+    ))?.to_rgba();
+    let (width, height) = image.dimensions();
+    // ggez images are at most 65535 pixels wide and high
+    let too_large = || GameError::ResourceLoadError(
+      format!("\"{}\" is too large: {}x{} pixels", path.display(), width, height)
+    );
+    let width  = u16::try_from(width).map_err( |_| too_large() )?;
+    let height = u16::try_from(height).map_err( |_| too_large() )?;
+    Image::from_rgba8(loader.ctx, width, height, &image.into_raw())
+  }
+}
+
+impl<'a> Asset<Loader<'a>> for SoundData {
+  fn load(loader: &mut Loader<'a>, path: &Path) -> GameResult<Self> {
+    Ok(SoundData::from_bytes(&loader.resources.read(path)?))
+  }
+}
+
+impl<'a> Asset<Loader<'a>> for FontData {
+  fn load(loader: &mut Loader<'a>, path: &Path) -> GameResult<Self> {
+    Ok(FontData::new(&path.to_string_lossy(), loader.resources.read(path)?))
+  }
+}
+
+impl<'a> Asset<Loader<'a>> for String {
+  fn load(loader: &mut Loader<'a>, path: &Path) -> GameResult<Self> {
+    String::load(loader.resources, path)
+  }
+}
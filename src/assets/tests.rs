@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use ::ggez::{
+  GameResult,
+  GameError
+};
+
+use super::*;
+
+/// A fake file system, counting how often files are read.
+struct Files {
+  files: HashMap<&'static str, &'static str>,
+  reads: usize
+}
+
+impl Files {
+  fn new() -> Self {
+    let mut files = HashMap::new();
+    files.insert("/levels/one.txt", "one");
+    files.insert("/levels/two.txt", "two");
+    files.insert("/shaders/glow.txt", "glow");
+    Self { files, reads: 0 }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+struct Text(String);
+
+impl Asset<Files> for Text {
+  fn load(files: &mut Files, path: &Path) -> GameResult<Self> {
+    files.reads += 1;
+    files.files.get(path.to_str().unwrap())
+      .map( |text| Text(text.to_string()) )
+      .ok_or_else( || GameError::ResourceNotFound(path.display().to_string(), Vec::new()) )
+  }
+}
+
+#[derive(Debug, PartialEq)]
+struct Length(usize);
+
+impl Asset<Files> for Length {
+  fn load(files: &mut Files, path: &Path) -> GameResult<Self> {
+    Text::load(files, path).map( |text| Length(text.0.len()) )
+  }
+}
+
+#[test]
+fn assets_are_loaded_once_per_path_and_type() {
+  let mut files  = Files::new();
+  let mut assets = Assets::new();
+  let one   = assets.load::<_, Text>(&mut files, "/levels/one.txt").unwrap();
+  let again = assets.load::<_, Text>(&mut files, "/levels/one.txt").unwrap();
+  let two   = assets.load::<_, Text>(&mut files, "/levels/two.txt").unwrap();
+  assert_eq!(one, again);
+  assert_ne!(one, two);
+  assert_eq!(files.reads, 2);
+  assert_eq!(assets.get(&one), Some(&Text("one".to_string())));
+  assert_eq!(assets.handle::<Text>("/levels/two.txt"), Some(two));
+  assert_eq!(assets.count::<Text>(), 2);
+
+  let length = assets.load::<_, Length>(&mut files, "/levels/one.txt").unwrap();
+  assert_eq!(assets.get(&length), Some(&Length(3)));
+  assert_eq!(files.reads, 3);
+}
+
+#[test]
+fn missing_assets_fail_with_their_path() {
+  let mut files  = Files::new();
+  let mut assets = Assets::new();
+  let error = assets.load::<_, Text>(&mut files, "/levels/missing.txt").unwrap_err();
+  assert!(error.to_string().contains("/levels/missing.txt"));
+  assert_eq!(assets.count::<Text>(), 0);
+}
+
+#[test]
+fn assets_are_unloaded_when_released() {
+  let mut files  = Files::new();
+  let mut assets = Assets::new();
+  let one = assets.load::<_, Text>(&mut files, "/levels/one.txt").unwrap();
+  assets.load::<_, Text>(&mut files, "/levels/one.txt").unwrap();
+  assets.retain(&one);
+  assert_eq!(assets.refs(&one), 3);
+  assert!(!assets.release(&one));
+  assert!(!assets.release(&one));
+  assert!(assets.release(&one));
+  assert!(!assets.is_loaded(&one));
+  assert_eq!(assets.handle::<Text>("/levels/one.txt"), None);
+  assert!(!assets.release(&one));
+
+  let one = assets.load::<_, Text>(&mut files, "/levels/one.txt").unwrap();
+  assert_eq!(assets.unload(&one), Some(Text("one".to_string())));
+}
+
+#[test]
+fn inserted_assets_replace_loaded_ones() {
+  let mut files  = Files::new();
+  let mut assets = Assets::new();
+  let one = assets.load::<_, Text>(&mut files, "/levels/one.txt").unwrap();
+  let replaced = assets.insert("/levels/one.txt", Text("new".to_string()));
+  assert_eq!(one, replaced);
+  assert_eq!(assets.get(&one), Some(&Text("new".to_string())));
+  assert_eq!(assets.refs(&one), 1);
+}
+
+#[test]
+fn preload_reports_progress_and_errors() {
+  let mut files  = Files::new();
+  let mut assets = Assets::new();
+  let mut preload = Preload::new()
+    .with::<Text>("/levels/one.txt")
+    .with::<Text>("/levels/missing.txt")
+    .with::<Length>("/shaders/glow.txt");
+  assert_eq!(preload.progress(), 0.0);
+  assert_eq!(preload.next_path(), Some("/levels/one.txt"));
+  preload.step(&mut assets, &mut files).unwrap();
+  assert!(preload.step(&mut assets, &mut files).is_err());
+  assert!(!preload.is_done());
+  preload.load_all(&mut assets, &mut files).unwrap();
+  assert!(preload.is_done());
+  assert_eq!(preload.progress(), 1.0);
+  let glow = assets.handle::<Length>("/shaders/glow.txt").unwrap();
+  assert_eq!(assets.get(&glow), Some(&Length(4)));
+}
+
+#[cfg(feature = "hot-reload")]
+mod hot_reload {
+  use std::fs;
+  use std::path::{ Path, PathBuf };
+  use std::time::{ Duration, SystemTime };
+
+  use ::ggez::GameResult;
+  use ::filetime::{
+    self,
+    FileTime
+  };
+  use super::super::*;
+
+  /// Loads text files from a directory on disk.
+  struct Disk(PathBuf);
+
+  struct Text(String);
+
+  impl Asset<Disk> for Text {
+    fn load(disk: &mut Disk, path: &Path) -> GameResult<Self> {
+      let text = fs::read_to_string(disk.0.join(path.strip_prefix("/").unwrap()))?;
+      if text.is_empty() {
+        return Err(::ggez::GameError::ResourceLoadError("Empty file".to_string()));
+      }
+      Ok(Text(text))
+    }
+  }
+
+  fn write(file: &Path, text: &str, age: u64) {
+    fs::write(file, text).unwrap();
+    let modified = SystemTime::now() - Duration::from_secs(age);
+    filetime::set_file_mtime(file, FileTime::from_system_time(modified)).unwrap();
+  }
+
+  #[test]
+  fn changed_files_are_reloaded() {
+    let root = ::std::env::temp_dir().join(format!("noframe-hot-reload-{}", ::std::process::id()));
+    fs::create_dir_all(root.join("config")).unwrap();
+    write(&root.join("config/speed.txt"), "10", 60);
+    write(&root.join("config/jump.txt"),  "5",  60);
+    let mut disk   = Disk(root.clone());
+    let mut assets = Assets::new();
+    let speed = assets.load::<_, Text>(&mut disk, "/config/speed.txt").unwrap();
+    let jump  = assets.load::<_, Text>(&mut disk, "/config/jump.txt").unwrap();
+    let mut hot_reload = HotReload::new(root.clone()).watch::<Text>("/config");
+    assert!(hot_reload.poll(&mut assets, &mut disk).is_empty());
+
+    write(&root.join("config/speed.txt"), "20", 30);
+    let reloaded = hot_reload.poll(&mut assets, &mut disk);
+    assert_eq!(reloaded.len(), 1);
+    assert_eq!(reloaded[0].path, Path::new("/config/speed.txt"));
+    assert_eq!(assets.get(&speed).unwrap().0, "20");
+    assert_eq!(assets.generation(&speed), Some(1));
+    assert_eq!(assets.generation(&jump), Some(0));
+    assert!(hot_reload.poll(&mut assets, &mut disk).is_empty());
+
+    write(&root.join("config/speed.txt"), "", 10);
+    let reloaded = hot_reload.poll(&mut assets, &mut disk);
+    assert!(reloaded[0].result.is_err());
+    assert_eq!(assets.get(&speed).unwrap().0, "20");
+    fs::remove_dir_all(root).unwrap();
+  }
+}
+
+#[test]
+fn resources_read_from_pack_or_directory() {
+  use std::fs;
+  use ::pack::pack_dir;
+
+  let root = ::std::env::temp_dir().join(format!("noframe-resources-{}", ::std::process::id()));
+  fs::create_dir_all(root.join("resources/config")).unwrap();
+  fs::write(root.join("resources/config/speed.txt"), "10").unwrap();
+
+  let mut loose = Resources::open(root.join("resources"), root.join("resources.pack")).unwrap();
+  assert!(!loose.is_pack());
+  pack_dir(&root.join("resources"), &root.join("resources.pack"), false).unwrap();
+  let mut packed = Resources::open(root.join("missing"), root.join("resources.pack")).unwrap();
+  assert!(packed.is_pack());
+
+  for resources in [&mut loose, &mut packed] {
+    let mut assets = Assets::new();
+    let speed = assets.load::<_, String>(resources, "/config/speed.txt").unwrap();
+    assert_eq!(assets.get(&speed).unwrap(), "10");
+    let error = assets.load::<_, Vec<u8>>(resources, "/config/jump.txt").unwrap_err();
+    assert!(error.to_string().contains("/config/jump.txt"));
+  }
+  fs::write(root.join("secret.txt"), "42").unwrap();
+  assert!(loose.exists(Path::new("/config/speed.txt")));
+  assert!(!loose.exists(Path::new("/../secret.txt")));
+  assert!(loose.read(Path::new("/config/../../secret.txt")).is_err());
+  fs::remove_dir_all(root).unwrap();
+}
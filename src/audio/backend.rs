@@ -0,0 +1,235 @@
+use std::collections::{
+  BTreeMap,
+  HashMap,
+  HashSet
+};
+use std::path::Path;
+
+use ::ggez::{
+  GameResult,
+  GameError,
+  Context,
+  audio::{
+    Source,
+    SoundData
+  }
+};
+
+/// Identifies a playing sound instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SoundId(pub u64);
+
+/// How a sound instance is played.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayParams {
+  pub volume:  f32,
+  /// Playback speed, `1.0` is the original pitch.
+  pub pitch:   f32,
+  /// Stereo panning, from `-1.0` (left) over `0.0` (center) to `1.0` (right).
+  pub pan:     f32,
+  pub looping: bool
+}
+
+/// Plays sounds for an `AudioManager`.
+pub trait AudioBackend {
+  /// What the backend needs to load and play sounds; a ggez `Context` for `GgezBackend`.
+  type Context: ?Sized;
+
+  /// Loads the sound at `path` as `name`, so up to `instances` of it can play at once.
+  fn load(&mut self, ctx: &mut Self::Context, name: &str, path: &Path, instances: usize) -> GameResult<()>;
+  fn is_loaded(&self, name: &str) -> bool;
+  fn play(&mut self, ctx: &mut Self::Context, name: &str, params: PlayParams) -> GameResult<SoundId>;
+  fn stop(&mut self, id: SoundId);
+  fn set_volume(&mut self, id: SoundId, volume: f32);
+  fn set_pan(&mut self, id: SoundId, pan: f32);
+  /// Returns `false` once the instance has finished or was stopped.
+  fn is_playing(&self, id: SoundId) -> bool;
+}
+
+fn not_loaded(name: &str) -> GameError {
+  GameError::AudioError(format!("Sound \"{}\" is not loaded", name))
+}
+
+/// A loaded sound of the `GgezBackend` and its pool of `Source`s.
+/// A stopped `Source` never plays again, so stopping one empties its slot, which is refilled from `data` when it is played next.
+struct GgezSound {
+  data:    SoundData,
+  sources: Vec<Option<Source>>
+}
+
+/// Plays sounds with ggez; every loaded sound gets a pool of `Source`s, one per instance.
+/// ggez 0.4 can't change the pitch or panning of a `Source`, so `PlayParams::pitch` and `pan` are ignored:
+/// with this backend, positional audio only attenuates the volume by distance,
+/// and `SoundConfig::pitch_variance` has no effect. Other backends, like the `NullBackend`, receive both.
+pub struct GgezBackend {
+  sounds:  HashMap<String, GgezSound>,
+  playing: HashMap<SoundId, (String, usize)>,
+  next_id: u64
+}
+
+impl GgezBackend {
+  pub fn new() -> Self {
+    Self {
+      sounds:  HashMap::new(),
+      playing: HashMap::new(),
+      next_id: 0
+    }
+  }
+
+  /// Makes the already loaded sound `data` playable as `name`; used for sounds loaded with `Assets`.
+  pub fn insert(&mut self, ctx: &mut Context, name: &str, data: &SoundData, instances: usize) -> GameResult<()> {
+    let sources = (0 .. instances.max(1))
+      .map( |_| Source::from_data(ctx, data.clone()).map(Some) )
+      .collect::<GameResult<Vec<Option<Source>>>>()?;
+    self.sounds.insert(name.to_string(), GgezSound { data: data.clone(), sources });
+    Ok(())
+  }
+
+  fn source(&self, id: SoundId) -> Option<&Source> {
+    let (name, slot) = self.playing.get(&id)?;
+    self.sounds.get(name).and_then( |sound| sound.sources.get(*slot) ).and_then(Option::as_ref)
+  }
+
+  fn source_mut(&mut self, id: SoundId) -> Option<&mut Source> {
+    let (name, slot) = self.playing.get(&id)?;
+    self.sounds.get_mut(name).and_then( |sound| sound.sources.get_mut(*slot) ).and_then(Option::as_mut)
+  }
+}
+
+impl Default for GgezBackend {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl AudioBackend for GgezBackend {
+  type Context = Context;
+
+  fn load(&mut self, ctx: &mut Context, name: &str, path: &Path, instances: usize) -> GameResult<()> {
+    let data = SoundData::new(ctx, path)?;
+    self.insert(ctx, name, &data, instances)
+  }
+
+  fn is_loaded(&self, name: &str) -> bool {
+    self.sounds.contains_key(name)
+  }
+
+  fn play(&mut self, ctx: &mut Context, name: &str, params: PlayParams) -> GameResult<SoundId> {
+    let sound = self.sounds.get_mut(name).ok_or_else( || not_loaded(name) )?;
+    let slot = sound.sources.iter()
+      .position( |source| source.as_ref().map_or(true, Source::stopped) )
+      .ok_or_else( || GameError::AudioError(format!("All instances of sound \"{}\" are playing", name)) )?;
+    if sound.sources[slot].is_none() {
+      sound.sources[slot] = Some(Source::from_data(ctx, sound.data.clone())?);
+    }
+    let source = sound.sources[slot].as_mut().expect("Source slot was just filled");
+    source.set_volume(params.volume);
+    source.set_repeat(params.looping);
+    source.play()?;
+    let id = SoundId(self.next_id);
+    self.next_id += 1;
+    self.playing.retain( |_, (playing, index)| !(playing == name && *index == slot) );
+    self.playing.insert(id, (name.to_string(), slot));
+    Ok(id)
+  }
+
+  fn stop(&mut self, id: SoundId) {
+    if let Some((name, slot)) = self.playing.remove(&id) {
+      let source = self.sounds.get_mut(&name).and_then( |sound| sound.sources.get_mut(slot) ).and_then(Option::take);
+      if let Some(source) = source {
+        source.stop();
+      }
+    }
+  }
+
+  fn set_volume(&mut self, id: SoundId, volume: f32) {
+    if let Some(source) = self.source_mut(id) {
+      source.set_volume(volume);
+    }
+  }
+
+  fn set_pan(&mut self, _id: SoundId, _pan: f32) {}
+
+  fn is_playing(&self, id: SoundId) -> bool {
+    self.source(id).map_or(false, |source| !source.stopped() )
+  }
+}
+
+/// A sound instance played by the `NullBackend`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NullSound {
+  pub name:   String,
+  pub params: PlayParams
+}
+
+/// Plays nothing, but keeps track of what would be playing;
+/// for tests and headless mode. Sounds play until they are stopped, or finished with `finish`.
+#[derive(Debug, Default)]
+pub struct NullBackend {
+  loaded:  HashSet<String>,
+  playing: BTreeMap<SoundId, NullSound>,
+  next_id: u64
+}
+
+impl NullBackend {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the playing instances, oldest first.
+  pub fn playing(&self) -> impl Iterator<Item = (SoundId, &NullSound)> {
+    self.playing.iter().map( |(id, sound)| (*id, sound) )
+  }
+
+  pub fn get(&self, id: SoundId) -> Option<&NullSound> {
+    self.playing.get(&id)
+  }
+
+  /// Ends the instance, as if it had played to the end.
+  pub fn finish(&mut self, id: SoundId) {
+    self.playing.remove(&id);
+  }
+}
+
+impl AudioBackend for NullBackend {
+  type Context = ();
+
+  fn load(&mut self, _ctx: &mut (), name: &str, _path: &Path, _instances: usize) -> GameResult<()> {
+    self.loaded.insert(name.to_string());
+    Ok(())
+  }
+
+  fn is_loaded(&self, name: &str) -> bool {
+    self.loaded.contains(name)
+  }
+
+  fn play(&mut self, _ctx: &mut (), name: &str, params: PlayParams) -> GameResult<SoundId> {
+    if !self.is_loaded(name) {
+      return Err(not_loaded(name));
+    }
+    let id = SoundId(self.next_id);
+    self.next_id += 1;
+    self.playing.insert(id, NullSound { name: name.to_string(), params });
+    Ok(id)
+  }
+
+  fn stop(&mut self, id: SoundId) {
+    self.playing.remove(&id);
+  }
+
+  fn set_volume(&mut self, id: SoundId, volume: f32) {
+    if let Some(sound) = self.playing.get_mut(&id) {
+      sound.params.volume = volume;
+    }
+  }
+
+  fn set_pan(&mut self, id: SoundId, pan: f32) {
+    if let Some(sound) = self.playing.get_mut(&id) {
+      sound.params.pan = pan;
+    }
+  }
+
+  fn is_playing(&self, id: SoundId) -> bool {
+    self.playing.contains_key(&id)
+  }
+}
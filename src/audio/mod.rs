@@ -0,0 +1,470 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+use ::ggez::{
+  GameResult,
+  GameError,
+  Context,
+  audio::SoundData
+};
+
+use ::geo::{
+  point::Point,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::assets::{
+  Assets,
+  Handle
+};
+use ::deltatime::Deltatime;
+use ::rng::Rng;
+
+pub mod backend;
+pub mod positional;
+
+pub use self::backend::{
+  AudioBackend,
+  GgezBackend,
+  NullBackend,
+  NullSound,
+  PlayParams,
+  SoundId
+};
+pub use self::positional::{
+  Attenuation,
+  Mix,
+  Spatial
+};
+
+/// The volume groups; every sound is in `Music` or `Sfx`, and `Master` applies to both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VolumeGroup {
+  Master,
+  Music,
+  Sfx
+}
+
+/// The volume of each `VolumeGroup`, from `0.0` to `1.0`; can be saved with the game's settings with the `serde` feature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct VolumeSettings {
+  pub master: f32,
+  pub music:  f32,
+  pub sfx:    f32
+}
+
+impl VolumeSettings {
+  pub fn get(&self, group: VolumeGroup) -> f32 {
+    match group {
+      VolumeGroup::Master => self.master,
+      VolumeGroup::Music  => self.music,
+      VolumeGroup::Sfx    => self.sfx
+    }
+  }
+
+  pub fn set(&mut self, group: VolumeGroup, volume: f32) {
+    let volume = volume.clamp(0.0, 1.0);
+    match group {
+      VolumeGroup::Master => self.master = volume,
+      VolumeGroup::Music  => self.music  = volume,
+      VolumeGroup::Sfx    => self.sfx    = volume
+    }
+  }
+
+  /// Returns the volume of the group multiplied with the master volume.
+  pub fn effective(&self, group: VolumeGroup) -> f32 {
+    match group {
+      VolumeGroup::Master => self.master,
+      group               => self.master * self.get(group)
+    }
+  }
+}
+
+impl Default for VolumeSettings {
+  fn default() -> Self {
+    Self {
+      master: 1.0,
+      music:  1.0,
+      sfx:    1.0
+    }
+  }
+}
+
+/// How a loaded sound is played.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SoundConfig {
+  pub volume:          f32,
+  /// Every one-shot's volume is randomly changed by up to this fraction, up or down.
+  pub volume_variance: f32,
+  /// Every one-shot's pitch is randomly changed by up to this fraction, up or down.
+  /// Whether the pitch is heard depends on the `AudioBackend`; see `GgezBackend`.
+  pub pitch_variance:  f32,
+  /// How many instances of the sound may play at once;
+  /// playing another one stops the oldest.
+  pub max_instances:   usize
+}
+
+impl Default for SoundConfig {
+  fn default() -> Self {
+    Self {
+      volume:          1.0,
+      volume_variance: 0.0,
+      pitch_variance:  0.0,
+      max_instances:   4
+    }
+  }
+}
+
+struct Instance {
+  id:       SoundId,
+  name:     String,
+  volume:   f32,
+  position: Option<Point>,
+  mix:      Mix
+}
+
+const CENTERED: Mix = Mix { volume: 1.0, pan: 0.0 };
+
+struct Fade {
+  from:     f32,
+  to:       f32,
+  duration: Duration,
+  elapsed:  Duration
+}
+
+impl Fade {
+  fn new(from: f32, to: f32, duration: Duration) -> Self {
+    Self { from, to, duration, elapsed: Duration::new(0, 0) }
+  }
+
+  fn advance(&mut self, delta: Duration) {
+    self.elapsed = (self.elapsed + delta).min(self.duration);
+  }
+
+  fn value(&self) -> f32 {
+    if self.is_finished() { return self.to; }
+    let t = (self.elapsed.as_secs_f64() / self.duration.as_secs_f64()) as f32;
+    self.from + (self.to - self.from) * t
+  }
+
+  fn is_finished(&self) -> bool {
+    self.elapsed >= self.duration
+  }
+}
+
+struct Track {
+  instance: Instance,
+  fade:     Fade
+}
+
+/// Loads sounds by name and plays one-shot sound effects and a music channel through an `AudioBackend`.
+/// `update` must be called every tick, for music fades and to forget finished sounds.
+/// # Example
+///   ```
+///   use noframe::audio::{ AudioManager, NullBackend, VolumeGroup };
+///
+///   let mut audio = AudioManager::new(NullBackend::new());
+///   audio.load(&mut (), "jump", "/sounds/jump.ogg").unwrap();
+///   audio.set_volume(VolumeGroup::Sfx, 0.5);
+///   let id = audio.play(&mut (), "jump").unwrap();
+///   assert_eq!(audio.backend().get(id).unwrap().params.volume, 0.5);
+///   ```
+pub struct AudioManager<B: AudioBackend> {
+  backend:   B,
+  sounds:    HashMap<String, SoundConfig>,
+  instances: Vec<Instance>,
+  music:     Option<Track>,
+  fading:    Vec<Track>,
+  volumes:   VolumeSettings,
+  spatial:   Spatial,
+  listener:  Point,
+  rng:       Rng
+}
+
+impl<B: AudioBackend> AudioManager<B> {
+  pub fn new(backend: B) -> Self {
+    Self::with_rng(backend, Rng::from_time())
+  }
+
+  /// Returns a new `AudioManager` with the given random number generator, used for volume and pitch variance.
+  pub fn with_rng(backend: B, rng: Rng) -> Self {
+    Self {
+      backend,
+      sounds:    HashMap::new(),
+      instances: Vec::new(),
+      music:     None,
+      fading:    Vec::new(),
+      volumes:   VolumeSettings::default(),
+      spatial:   Spatial::default(),
+      listener:  Point::new(0.0, 0.0),
+      rng
+    }
+  }
+
+  pub fn backend(&self) -> &B {
+    &self.backend
+  }
+
+  pub fn backend_mut(&mut self) -> &mut B {
+    &mut self.backend
+  }
+
+  /// Loads the sound at `path` as `name`, with the default `SoundConfig`.
+  pub fn load<P: AsRef<Path>>(&mut self, ctx: &mut B::Context, name: &str, path: P) -> GameResult<()> {
+    self.load_with(ctx, name, path, SoundConfig::default())
+  }
+
+  pub fn load_with<P: AsRef<Path>>(&mut self, ctx: &mut B::Context, name: &str, path: P, config: SoundConfig) -> GameResult<()> {
+    self.backend.load(ctx, name, path.as_ref(), config.max_instances)?;
+    self.sounds.insert(name.to_string(), config);
+    Ok(())
+  }
+
+  pub fn is_loaded(&self, name: &str) -> bool {
+    self.sounds.contains_key(name)
+  }
+
+  pub fn config(&self, name: &str) -> Option<&SoundConfig> {
+    self.sounds.get(name)
+  }
+
+  fn config_or_err(&self, name: &str) -> GameResult<SoundConfig> {
+    self.sounds.get(name).cloned().ok_or_else( || GameError::AudioError(
+      format!("Sound \"{}\" is not loaded", name)
+    ))
+  }
+
+  fn vary(&mut self, value: f32, variance: f32) -> f32 {
+    if variance == 0.0 { return value; }
+    value * (1.0 + self.rng.range(-variance, variance))
+  }
+
+  /// Plays the sound once, in the `Sfx` group, with a randomized volume and pitch.
+  /// If `max_instances` of it are already playing, the oldest one is stopped.
+  pub fn play(&mut self, ctx: &mut B::Context, name: &str) -> GameResult<SoundId> {
+    self.play_instance(ctx, name, None)
+  }
+
+  /// Plays the sound once at the `point`; its volume and panning depend on where it is
+  /// relative to the listener, see `set_listener`. It is played even if it is out of earshot.
+  /// Whether the panning is heard depends on the `AudioBackend`; see `GgezBackend`.
+  pub fn play_at(&mut self, ctx: &mut B::Context, name: &str, point: &Point) -> GameResult<SoundId> {
+    self.play_instance(ctx, name, Some(point.clone()))
+  }
+
+  /// Plays the sound once at the center of the `Mask`, usually an entity.
+  /// Use `follow` every tick to keep it attached to the entity.
+  pub fn play_attached<M: Mask + ?Sized>(&mut self, ctx: &mut B::Context, name: &str, mask: &M) -> GameResult<SoundId> {
+    self.play_at(ctx, name, &mask.origin_point(&Origin::Center))
+  }
+
+  fn play_instance(&mut self, ctx: &mut B::Context, name: &str, position: Option<Point>) -> GameResult<SoundId> {
+    let config = self.config_or_err(name)?;
+    let playing = self.instances.iter().filter( |instance| instance.name == name ).count();
+    if playing >= config.max_instances.max(1) {
+      if let Some(index) = self.instances.iter().position( |instance| instance.name == name ) {
+        let oldest = self.instances.remove(index);
+        self.backend.stop(oldest.id);
+      }
+    }
+    let volume = self.vary(config.volume, config.volume_variance).max(0.0);
+    let pitch  = self.vary(1.0, config.pitch_variance);
+    let mix = position.as_ref().map_or(CENTERED, |point| self.spatial.mix(&self.listener, point) );
+    let id = self.backend.play(ctx, name, PlayParams {
+      volume:  volume * mix.volume * self.volumes.effective(VolumeGroup::Sfx),
+      pitch,
+      pan:     mix.pan,
+      looping: false
+    })?;
+    self.instances.push(Instance { id, name: name.to_string(), volume, position, mix });
+    Ok(id)
+  }
+
+  /// Returns `true` if the one-shot sound instance is still playing.
+  pub fn is_playing(&self, id: SoundId) -> bool {
+    self.instances.iter().any( |instance| instance.id == id ) && self.backend.is_playing(id)
+  }
+
+  pub fn stop(&mut self, id: SoundId) {
+    self.instances.retain( |instance| instance.id != id );
+    self.backend.stop(id);
+  }
+
+  /// Moves the positional sound instance to the `point`.
+  pub fn move_sound(&mut self, id: SoundId, point: &Point) {
+    let mix = self.spatial.mix(&self.listener, point);
+    if let Some(instance) = self.instances.iter_mut().find( |instance| instance.id == id ) {
+      instance.position = Some(point.clone());
+      instance.mix      = mix;
+    }
+    self.apply_volumes();
+  }
+
+  /// Moves the positional sound instance to the center of the `Mask`.
+  pub fn follow<M: Mask + ?Sized>(&mut self, id: SoundId, mask: &M) {
+    self.move_sound(id, &mask.origin_point(&Origin::Center));
+  }
+
+  /// Moves the listener of positional sounds to the center of the `Mask`, usually the `Camera`.
+  /// Call this every tick, so the mix follows the camera.
+  pub fn set_listener<M: Mask + ?Sized>(&mut self, listener: &M) {
+    self.listener = listener.origin_point(&Origin::Center);
+    self.remix();
+  }
+
+  pub fn listener(&self) -> &Point {
+    &self.listener
+  }
+
+  pub fn spatial(&self) -> &Spatial {
+    &self.spatial
+  }
+
+  /// Changes the positional settings; playing sounds are changed immediately.
+  pub fn set_spatial(&mut self, spatial: Spatial) {
+    self.spatial = spatial;
+    self.remix();
+  }
+
+  fn remix(&mut self) {
+    for instance in self.instances.iter_mut() {
+      if let Some(ref position) = instance.position {
+        instance.mix = self.spatial.mix(&self.listener, position);
+      }
+    }
+    self.apply_volumes();
+  }
+
+  /// Stops all one-shot sounds; the music keeps playing.
+  pub fn stop_all(&mut self) {
+    for instance in self.instances.drain(..) {
+      self.backend.stop(instance.id);
+    }
+  }
+
+  /// Plays the sound as music, fading it in and the current music out over `fade`.
+  /// Does nothing if it is already the current music.
+  pub fn play_music(&mut self, ctx: &mut B::Context, name: &str, looping: bool, fade: Duration) -> GameResult<()> {
+    if self.music() == Some(name) {
+      return Ok(());
+    }
+    let config = self.config_or_err(name)?;
+    let start = if fade == Duration::new(0, 0) { 1.0 } else { 0.0 };
+    let id = self.backend.play(ctx, name, PlayParams {
+      volume:  config.volume * start * self.volumes.effective(VolumeGroup::Music),
+      pitch:   1.0,
+      pan:     0.0,
+      looping
+    })?;
+    self.stop_music(fade);
+    self.music = Some(Track {
+      instance: Instance { id, name: name.to_string(), volume: config.volume, position: None, mix: CENTERED },
+      fade:     Fade::new(start, 1.0, fade)
+    });
+    Ok(())
+  }
+
+  /// Fades out and stops the current music.
+  pub fn stop_music(&mut self, fade: Duration) {
+    if let Some(mut track) = self.music.take() {
+      let volume = track.fade.value();
+      if fade == Duration::new(0, 0) {
+        self.backend.stop(track.instance.id);
+      } else {
+        track.fade = Fade::new(volume, 0.0, fade);
+        self.fading.push(track);
+      }
+    }
+  }
+
+  /// Returns the name of the current music.
+  pub fn music(&self) -> Option<&str> {
+    self.music.as_ref().map( |track| track.instance.name.as_str() )
+  }
+
+  pub fn volumes(&self) -> &VolumeSettings {
+    &self.volumes
+  }
+
+  /// Replaces all group volumes, for example with loaded settings.
+  pub fn set_volumes(&mut self, volumes: VolumeSettings) {
+    self.volumes = volumes;
+    self.apply_volumes();
+  }
+
+  pub fn volume(&self, group: VolumeGroup) -> f32 {
+    self.volumes.get(group)
+  }
+
+  /// Sets the volume of the group; playing sounds are changed immediately.
+  pub fn set_volume(&mut self, group: VolumeGroup, volume: f32) {
+    self.volumes.set(group, volume);
+    self.apply_volumes();
+  }
+
+  fn apply_volumes(&mut self) {
+    let sfx   = self.volumes.effective(VolumeGroup::Sfx);
+    let music = self.volumes.effective(VolumeGroup::Music);
+    for instance in self.instances.iter() {
+      self.backend.set_volume(instance.id, instance.volume * instance.mix.volume * sfx);
+      self.backend.set_pan(instance.id, instance.mix.pan);
+    }
+    for track in self.music.iter().chain(self.fading.iter()) {
+      self.backend.set_volume(track.instance.id, track.instance.volume * track.fade.value() * music);
+    }
+  }
+
+  pub fn update(&mut self, dt: &Deltatime) {
+    self.update_by(dt.get());
+  }
+
+  /// Advances music fades by `delta`, stops faded out music and forgets finished one-shots.
+  pub fn update_by(&mut self, delta: Duration) {
+    for track in self.music.iter_mut().chain(self.fading.iter_mut()) {
+      track.fade.advance(delta);
+    }
+    let backend = &mut self.backend;
+    self.fading.retain( |track| {
+      let done = track.fade.is_finished();
+      if done {
+        backend.stop(track.instance.id);
+      }
+      !done
+    });
+    if self.music.as_ref().map_or(false, |track| !backend.is_playing(track.instance.id) ) {
+      self.music = None;
+    }
+    self.instances.retain( |instance| backend.is_playing(instance.id) );
+    self.apply_volumes();
+  }
+}
+
+impl AudioManager<GgezBackend> {
+  /// Loads the sound at `path` through `Assets` and makes it playable as `name`,
+  /// so sound data shared with other code is only loaded once.
+  /// The sound is only registered if the backend could make it playable; otherwise the handle is released again.
+  pub fn load_asset(&mut self, ctx: &mut Context, assets: &mut Assets, name: &str, path: &str, config: SoundConfig) -> GameResult<Handle<SoundData>> {
+    let handle = assets.load::<_, SoundData>(ctx, path)?;
+    let inserted = match assets.get(&handle) {
+      Some(data) => self.backend.insert(ctx, name, data, config.max_instances),
+      None       => Err(GameError::AudioError(format!("Sound \"{}\" is missing from the assets", path)))
+    };
+    if let Err(error) = inserted {
+      assets.release(&handle);
+      return Err(error);
+    }
+    self.sounds.insert(name.to_string(), config);
+    Ok(handle)
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,108 @@
+use ::geo::{
+  NumType,
+  point::Point,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+
+/// How the volume of a positional sound falls off between `Spatial::min_distance` and `max_distance`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Attenuation {
+  /// No falloff; the sound is at full volume until `max_distance`.
+  None,
+  /// Falls off evenly to `0.0` at `max_distance`.
+  #[default]
+  Linear,
+  /// Falls off quickly close to the listener, and slowly further away.
+  Quadratic,
+  /// Falls off like `min / (min + rolloff * (distance - min))`, like real sound, but cut off at `max_distance`.
+  Inverse(NumType)
+}
+
+impl Attenuation {
+  /// Returns the gain, from `0.0` to `1.0`, at the `distance`.
+  pub fn gain(&self, distance: NumType, min_distance: NumType, max_distance: NumType) -> f32 {
+    if distance <= min_distance { return 1.0; }
+    if distance >= max_distance { return 0.0; }
+    let t = (distance - min_distance) / (max_distance - min_distance);
+    match self {
+      Attenuation::None             => 1.0,
+      Attenuation::Linear           => 1.0 - t,
+      Attenuation::Quadratic        => (1.0 - t).powi(2),
+      Attenuation::Inverse(rolloff) => {
+        let min = min_distance.max(1.0);
+        min / (min + rolloff * (distance - min_distance))
+      }
+    }
+  }
+}
+
+/// The volume and panning of a sound, see `Spatial::mix`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mix {
+  pub volume: f32,
+  pub pan:    f32
+}
+
+/// Settings for sounds played at a `Point`, heard from a listener (usually the `Camera`).
+/// Whether the panning is heard depends on the `AudioBackend`; see `GgezBackend`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Spatial {
+  /// Sounds closer than this are at full volume.
+  pub min_distance: NumType,
+  /// Sounds further away than this are silent.
+  pub max_distance: NumType,
+  pub attenuation:  Attenuation,
+  /// The horizontal offset at which a sound is panned fully to one side; `0` disables panning.
+  pub pan_distance: NumType,
+  /// How strongly sounds are panned, from `0.0` (never) to `1.0` (fully to one side).
+  pub pan_strength: f32
+}
+
+impl Default for Spatial {
+  fn default() -> Self {
+    Self {
+      min_distance: 64.0,
+      max_distance: 1024.0,
+      attenuation:  Attenuation::Linear,
+      pan_distance: 512.0,
+      pan_strength: 0.75
+    }
+  }
+}
+
+impl Spatial {
+  /// Returns the volume and panning of a sound at `point`, heard from `listener`.
+  /// # Example
+  ///   ```
+  ///   use noframe::geo::point::Point;
+  ///   use noframe::audio::Spatial;
+  ///
+  ///   let spatial = Spatial::default();
+  ///   let mix = spatial.mix(&Point::new(0.0, 0.0), &Point::new(2048.0, 0.0));
+  ///   assert_eq!(mix.volume, 0.0);
+  ///   assert_eq!(mix.pan, 0.75);
+  ///   ```
+  pub fn mix(&self, listener: &Point, point: &Point) -> Mix {
+    let offset = point.clone() - listener.clone();
+    let pan = if self.pan_distance > 0.0 {
+      (offset.x / self.pan_distance).clamp(-1.0, 1.0)
+    } else {
+      0.0
+    };
+    Mix {
+      volume: self.attenuation.gain(offset.length(), self.min_distance, self.max_distance),
+      pan:    pan * self.pan_strength
+    }
+  }
+
+  /// Returns the mix of a sound at `point`, heard from the center of the `listener`, usually the `Camera`.
+  pub fn mix_for<M: Mask + ?Sized>(&self, listener: &M, point: &Point) -> Mix {
+    self.mix(&listener.origin_point(&Origin::Center), point)
+  }
+}
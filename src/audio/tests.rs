@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use ::geo::prelude::*;
+use ::camera::Camera;
+use ::rng::Rng;
+use super::*;
+
+fn audio() -> AudioManager<NullBackend> {
+  let mut audio = AudioManager::with_rng(NullBackend::new(), Rng::new(1));
+  audio.load(&mut (), "jump", "/sounds/jump.ogg").unwrap();
+  audio.load(&mut (), "title", "/music/title.ogg").unwrap();
+  audio.load(&mut (), "level", "/music/level.ogg").unwrap();
+  audio
+}
+
+fn millis(millis: u64) -> Duration {
+  Duration::from_millis(millis)
+}
+
+fn volume_of(audio: &AudioManager<NullBackend>, name: &str) -> Vec<f32> {
+  audio.backend().playing()
+    .filter( |(_, sound)| sound.name == name )
+    .map( |(_, sound)| sound.params.volume )
+    .collect()
+}
+
+#[test]
+fn playing_unloaded_sound_fails() {
+  let mut audio = audio();
+  assert!(audio.play(&mut (), "missing").is_err());
+  assert!(audio.play_music(&mut (), "missing", true, millis(0)).is_err());
+}
+
+#[test]
+fn one_shots_are_capped_and_forgotten_when_finished() {
+  let mut audio = audio();
+  audio.load_with(&mut (), "coin", "/sounds/coin.ogg", SoundConfig {
+    max_instances: 2,
+    .. SoundConfig::default()
+  }).unwrap();
+  let first  = audio.play(&mut (), "coin").unwrap();
+  let second = audio.play(&mut (), "coin").unwrap();
+  let third  = audio.play(&mut (), "coin").unwrap();
+  assert!(!audio.is_playing(first));
+  assert!(audio.is_playing(second) && audio.is_playing(third));
+  assert_eq!(audio.backend().playing().count(), 2);
+
+  audio.backend_mut().finish(second);
+  audio.update_by(millis(16));
+  assert!(!audio.is_playing(second));
+  assert!(audio.is_playing(third));
+}
+
+#[test]
+fn volume_and_pitch_are_randomized() {
+  let mut audio = audio();
+  audio.load_with(&mut (), "hit", "/sounds/hit.ogg", SoundConfig {
+    volume:          0.5,
+    volume_variance: 0.2,
+    pitch_variance:  0.1,
+    max_instances:   8
+  }).unwrap();
+  let mut pitches = Vec::new();
+  for _ in 0 .. 8 {
+    let id = audio.play(&mut (), "hit").unwrap();
+    let params = audio.backend().get(id).unwrap().params;
+    assert!(params.volume >= 0.4 && params.volume <= 0.6);
+    assert!(params.pitch  >= 0.9 && params.pitch  <= 1.1);
+    pitches.push(params.pitch);
+  }
+  assert!(pitches.iter().any( |pitch| *pitch != pitches[0] ));
+}
+
+#[test]
+fn group_volumes_apply_to_playing_sounds() {
+  let mut audio = audio();
+  audio.play(&mut (), "jump").unwrap();
+  audio.play_music(&mut (), "title", true, millis(0)).unwrap();
+  audio.set_volume(VolumeGroup::Master, 0.5);
+  audio.set_volume(VolumeGroup::Sfx, 0.5);
+  assert_eq!(volume_of(&audio, "jump"), vec![0.25]);
+  assert_eq!(volume_of(&audio, "title"), vec![0.5]);
+  audio.set_volume(VolumeGroup::Music, 2.0);
+  assert_eq!(audio.volume(VolumeGroup::Music), 1.0);
+}
+
+#[test]
+fn music_crossfades_and_loops() {
+  let mut audio = audio();
+  audio.play_music(&mut (), "title", true, millis(0)).unwrap();
+  assert_eq!(audio.music(), Some("title"));
+  let (_, title) = audio.backend().playing().next().unwrap();
+  assert!(title.params.looping);
+
+  audio.play_music(&mut (), "level", true, millis(1000)).unwrap();
+  assert_eq!(audio.music(), Some("level"));
+  audio.update_by(millis(250));
+  assert_eq!(volume_of(&audio, "title"), vec![0.75]);
+  assert_eq!(volume_of(&audio, "level"), vec![0.25]);
+  audio.update_by(millis(750));
+  assert!(volume_of(&audio, "title").is_empty());
+  assert_eq!(volume_of(&audio, "level"), vec![1.0]);
+
+  audio.play_music(&mut (), "level", true, millis(1000)).unwrap();
+  assert_eq!(audio.backend().playing().count(), 1);
+  audio.stop_music(millis(0));
+  assert_eq!(audio.music(), None);
+  assert_eq!(audio.backend().playing().count(), 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn volume_settings_round_trip() {
+  let mut volumes = VolumeSettings::default();
+  volumes.set(VolumeGroup::Music, 0.25);
+  let json = ::serde_json::to_string(&volumes).unwrap();
+  assert_eq!(::serde_json::from_str::<VolumeSettings>(&json).unwrap(), volumes);
+  assert_eq!(::serde_json::from_str::<VolumeSettings>("{\"sfx\":0.5}").unwrap().master, 1.0);
+}
+
+#[test]
+fn attenuation_curves() {
+  assert_eq!(Attenuation::Linear.gain(50.0, 100.0, 300.0), 1.0);
+  assert_eq!(Attenuation::Linear.gain(200.0, 100.0, 300.0), 0.5);
+  assert_eq!(Attenuation::Quadratic.gain(200.0, 100.0, 300.0), 0.25);
+  assert_eq!(Attenuation::None.gain(299.0, 100.0, 300.0), 1.0);
+  assert_eq!(Attenuation::Inverse(1.0).gain(200.0, 100.0, 300.0), 0.5);
+  assert_eq!(Attenuation::Inverse(1.0).gain(300.0, 100.0, 300.0), 0.0);
+}
+
+#[test]
+fn spatial_mix_pans_by_horizontal_offset() {
+  let spatial = Spatial {
+    min_distance: 0.0,
+    max_distance: 400.0,
+    attenuation:  Attenuation::Linear,
+    pan_distance: 200.0,
+    pan_strength: 1.0
+  };
+  let listener = Point::new(100.0, 100.0);
+  assert_eq!(spatial.mix(&listener, &Point::new(0.0, 100.0)),   Mix { volume: 0.75, pan: -0.5 });
+  assert_eq!(spatial.mix(&listener, &Point::new(500.0, 100.0)), Mix { volume: 0.0,  pan: 1.0 });
+  assert_eq!(spatial.mix(&listener, &Point::new(100.0, 300.0)), Mix { volume: 0.5,  pan: 0.0 });
+}
+
+#[test]
+fn positional_sounds_follow_the_camera() {
+  let mut audio = audio();
+  audio.set_spatial(Spatial {
+    min_distance: 0.0,
+    max_distance: 400.0,
+    attenuation:  Attenuation::Linear,
+    pan_distance: 200.0,
+    pan_strength: 1.0
+  });
+  let mut camera = Camera::new(Size::new(200.0, 200.0));
+  audio.set_listener(&camera);
+  let id = audio.play_at(&mut (), "jump", &Point::new(-100.0, 0.0)).unwrap();
+  assert_eq!(audio.backend().get(id).unwrap().params.volume, 0.75);
+  assert_eq!(audio.backend().get(id).unwrap().params.pan, -0.5);
+
+  camera.move_to(&Point::new(-100.0, 0.0));
+  audio.set_listener(&camera);
+  assert_eq!(audio.backend().get(id).unwrap().params.volume, 1.0);
+  assert_eq!(audio.backend().get(id).unwrap().params.pan, 0.0);
+
+  let emitter = Rect::new(Point::new(100.0, 0.0), Size::new(20.0, 20.0), Origin::Center);
+  audio.follow(id, &emitter);
+  assert_eq!(audio.backend().get(id).unwrap().params.volume, 0.5);
+  assert_eq!(audio.backend().get(id).unwrap().params.pan, 1.0);
+
+  audio.set_volume(VolumeGroup::Sfx, 0.5);
+  assert_eq!(audio.backend().get(id).unwrap().params.volume, 0.25);
+}
@@ -0,0 +1,73 @@
+//! Packs a game's resource directory into a single pack file, and lists and verifies pack files.
+
+extern crate noframe;
+
+use std::env;
+use std::path::Path;
+use std::process;
+
+use noframe::pack::{
+  self,
+  Pack
+};
+
+const USAGE: &str = "\
+Usage:
+  noframe-pack pack <DIRECTORY> <PACK> [--compress]
+      Packs all files in DIRECTORY into the new pack file PACK.
+  noframe-pack list <PACK>
+      Lists the files in PACK.
+  noframe-pack verify <PACK>
+      Checks the checksums of all files in PACK.";
+
+fn run(args: &[String]) -> Result<(), String> {
+  let args = args.iter().map(String::as_str).collect::<Vec<&str>>();
+  match args.as_slice() {
+    ["pack", dir, out, options @ ..] => {
+      let compress = match options {
+        []             => false,
+        ["--compress"] => true,
+        _              => return Err(USAGE.to_string())
+      };
+      let entries = pack::pack_dir(Path::new(dir), Path::new(out), compress)
+        .map_err( |error| format!("Could not pack \"{}\": {}", dir, error) )?;
+      let original = entries.iter().map( |entry| entry.original_size ).sum::<u64>();
+      let stored   = entries.iter().map( |entry| entry.size ).sum::<u64>();
+      println!("Packed {} files into \"{}\" ({} of {} bytes)", entries.len(), out, stored, original);
+      Ok(())
+    }
+
+    ["list", file] => {
+      let pack = Pack::open(file).map_err( |error| format!("Could not open \"{}\": {}", file, error) )?;
+      for entry in pack.entries() {
+        let compressed = if entry.compressed { "compressed" } else { "" };
+        println!("{:>10} {:>10} {:<10} {}", entry.original_size, entry.size, compressed, entry.path);
+      }
+      Ok(())
+    }
+
+    ["verify", file] => {
+      let mut pack = Pack::open(file).map_err( |error| format!("Could not open \"{}\": {}", file, error) )?;
+      let broken = pack.verify();
+      for (path, error) in &broken {
+        eprintln!("{}: {}", path, error);
+      }
+      if broken.is_empty() {
+        println!("All {} files are intact", pack.entries().count());
+        Ok(())
+      } else {
+        Err(format!("{} files are broken", broken.len()))
+      }
+    }
+
+    _ => Err(USAGE.to_string())
+  }
+}
+
+fn main() {
+  let args = env::args().skip(1).collect::<Vec<String>>();
+  if let Err(message) = run(&args) {
+    eprintln!("{}", message);
+    process::exit(1);
+  }
+}
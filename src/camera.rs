@@ -47,7 +47,13 @@ impl Camera {
     self.move_by(&Point::new(0.0, incr));
   }
 
-  pub fn draw<E: Entity>(&self, ctx: &mut Context, entity: &E) -> GameResult<()> {
+  /// Resize the camera to the new window size, keeping the passed `anchor` in place.
+  /// This should be called from ggez's `EventHandler::resize_event`.
+  pub fn resize_window(&mut self, width: u32, height: u32, anchor: &Origin) {
+    self.resize_from(Size::new(width as NumType, height as NumType), anchor);
+  }
+
+  pub fn draw<E: Entity + ?Sized>(&self, ctx: &mut Context, entity: &E) -> GameResult<()> {
     if self.intersects(entity) {
       entity.draw_offset(ctx, &self.top_left().inverted())?;
     }
@@ -65,7 +71,13 @@ impl Mask for Camera {
   fn size(&self) -> &Size {
     &self.size
   }
+  fn size_mut(&mut self) -> &mut Size {
+    &mut self.size
+  }
   fn origin(&self) -> &Origin {
     &self.origin
   }
+  fn origin_mut(&mut self) -> &mut Origin {
+    &mut self.origin
+  }
 }
@@ -1,7 +0,0 @@
-pub type Color = [f32; 4];
-
-pub const BLACK: Color = [0.0, 0.0, 0.0, 1.0];
-pub const WHITE: Color = [1.0, 1.0, 1.0, 1.0];
-pub const RED:   Color = [1.0, 0.0, 0.0, 1.0];
-pub const GREEN: Color = [0.0, 1.0, 0.0, 1.0];
-pub const BLUE:  Color = [0.0, 0.0, 1.0, 1.0];
@@ -0,0 +1,69 @@
+pub type Color = [f32; 4];
+
+pub const BLACK: Color = [0.0, 0.0, 0.0, 1.0];
+pub const WHITE: Color = [1.0, 1.0, 1.0, 1.0];
+pub const RED:   Color = [1.0, 0.0, 0.0, 1.0];
+pub const GREEN: Color = [0.0, 1.0, 0.0, 1.0];
+pub const BLUE:  Color = [0.0, 0.0, 1.0, 1.0];
+
+/// Returns the `Color` as a hex string, like `"#ff8000ff"` (red, green, blue, alpha).
+/// # Example
+///   ```
+///   use noframe::color;
+///
+///   assert_eq!("#ff0000ff", color::to_hex(&color::RED));
+///   ```
+pub fn to_hex(color: &Color) -> String {
+  color.iter().fold(String::from("#"), |hex, channel| {
+    let byte = (channel.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("{}{:02x}", hex, byte)
+  })
+}
+
+/// Parses a hex string, like `"#ff8000"` or `"#ff8000ff"`, into a `Color`.
+/// The leading `#` is optional; if the alpha channel is omitted, it defaults to `1.0`.
+/// Returns `None` if the string is not a valid hex color.
+/// # Example
+///   ```
+///   use noframe::color;
+///
+///   assert_eq!(Some(color::BLUE), color::from_hex("#0000ff"));
+///   assert_eq!(None,              color::from_hex("#0000f"));
+///   ```
+pub fn from_hex(hex: &str) -> Option<Color> {
+  let hex = hex.strip_prefix('#').unwrap_or(hex);
+  if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+    return None;
+  }
+  let mut color: Color = [1.0; 4];
+  for (i, channel) in color.iter_mut().enumerate().take(hex.len() / 2) {
+    let byte = u8::from_str_radix(&hex[i * 2 .. i * 2 + 2], 16).ok()?;
+    *channel = byte as f32 / 255.0;
+  }
+  Some(color)
+}
+
+/// Serializes a `Color` as a hex string instead of an array of floats.
+/// Use it on `Color` fields with `#[serde(with = "::color::serde_hex")]`.
+#[cfg(feature = "serde")]
+pub mod serde_hex {
+  use ::serde::{
+    Serializer,
+    Deserializer,
+    Deserialize,
+    de::Error
+  };
+  use super::{ Color, to_hex, from_hex };
+
+  pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_hex(color))
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    from_hex(&hex).ok_or_else( || D::Error::custom(format!("invalid hex color `{}`", hex)) )
+  }
+}
+
+#[cfg(test)]
+mod tests;
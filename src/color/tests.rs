@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn hex_round_trip() {
+  let color: Color = [1.0, 0.2, 0.0, 0.6];
+  let hex = to_hex(&color);
+  assert_eq!(hex, "#ff330099");
+  assert_eq!(from_hex(&hex), Some(color));
+}
+
+#[test]
+fn hex_without_alpha() {
+  assert_eq!(from_hex("00ff00"), Some(GREEN));
+  assert_eq!(from_hex("#00ff0"), None);
+  assert_eq!(from_hex("#00gg00"), None);
+  assert_eq!(from_hex("##ff00ff"), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_as_hex() {
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Style {
+    #[serde(with = "::color::serde_hex")]
+    color: Color
+  }
+
+  let style = Style { color: RED };
+  let json = ::serde_json::to_string(&style).unwrap();
+  assert_eq!(json, r##"{"color":"#ff0000ff"}"##);
+  assert_eq!(::serde_json::from_str::<Style>(&json).unwrap(), style);
+  assert!(::serde_json::from_str::<Style>(r#"{"color":"red"}"#).is_err());
+}
@@ -18,6 +18,15 @@ impl Deltatime {
     }
   }
 
+  /// Returns a new `Deltatime` with a fixed value, which is kept until `update` or `reset` is called.
+  /// This is useful for tests and for fixed time steps.
+  pub fn fixed(value: Duration) -> Self {
+    Self {
+      value,
+      last_update: Instant::now()
+    }
+  }
+
   /// Returns the current deltatime value as a `Duration`.
   pub fn get(&self) -> Duration {
     self.value
@@ -47,6 +56,12 @@ impl Deltatime {
   }
 }
 
+impl Default for Deltatime {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl fmt::Display for Deltatime {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(f, "{}.{}", self.value.as_secs(), self.value.subsec_millis())
@@ -0,0 +1,143 @@
+use ::ggez::{
+  Context,
+  GameResult,
+  graphics
+};
+
+use ::settings::entity::DEFAULT_COLOR;
+use ::color::Color;
+use ::geo::{
+  point::Point,
+  size::Size,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::entity::{
+  Entity,
+  Velocity,
+  Movement
+};
+use ::world::EntityId;
+
+use super::World;
+use super::component::*;
+
+/// A copy of an ECS entity's components, which implements the `Entity`, `Velocity` and `Movement` traits.
+/// Read it from the `World` with `read`, use the traits' methods on it, and `write` the changes back.
+/// Because it is a copy, the `World` may be borrowed immutably while the adapter is being moved.
+pub struct EntityAdapter {
+  id:           EntityId,
+  point:        Point,
+  size:         Size,
+  origin:       Origin,
+  velocity:     Point,
+  max_velocity: Point,
+  color:        Option<Color>,
+  sprite:       Option<Sprite>
+}
+
+impl EntityAdapter {
+  /// Reads the entity's components from the `World`.
+  /// Returns `None` if the entity doesn't have both a `Position` and `Bounds` component.
+  pub fn read(world: &World, id: EntityId) -> Option<Self> {
+    let position = world.positions.get(id)?;
+    let bounds   = world.bounds.get(id)?;
+    let (velocity, max_velocity) = match world.velocities.get(id) {
+      Some(velocity) => (velocity.velocity.clone(), velocity.max.clone()),
+      None           => (Point::new(0.0, 0.0), Point::new(0.0, 0.0))
+    };
+    Some(Self {
+      id,
+      point:  position.0.clone(),
+      size:   bounds.size.clone(),
+      origin: bounds.origin.clone(),
+      velocity,
+      max_velocity,
+      color:  world.colors.get(id).map( |color| color.0 ),
+      sprite: world.sprites.get(id).cloned()
+    })
+  }
+
+  /// Writes the position, bounds and velocity back to the entity's components.
+  /// The velocity is only written if the entity has a `Velocity` component.
+  pub fn write(&self, world: &mut World) {
+    if let Some(position) = world.positions.get_mut(self.id) {
+      position.0.set(&self.point);
+    }
+    if let Some(bounds) = world.bounds.get_mut(self.id) {
+      bounds.size   = self.size.clone();
+      bounds.origin = self.origin.clone();
+    }
+    if let Some(velocity) = world.velocities.get_mut(self.id) {
+      velocity.velocity.set(&self.velocity);
+    }
+  }
+
+  pub fn id(&self) -> EntityId {
+    self.id
+  }
+}
+
+impl Mask for EntityAdapter {
+  fn point(&self) -> &Point {
+    &self.point
+  }
+  fn point_mut(&mut self) -> &mut Point {
+    &mut self.point
+  }
+  fn size(&self) -> &Size {
+    &self.size
+  }
+  fn size_mut(&mut self) -> &mut Size {
+    &mut self.size
+  }
+  fn origin(&self) -> &Origin {
+    &self.origin
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    &mut self.origin
+  }
+}
+
+impl Entity for EntityAdapter {
+  fn color(&self) -> Color {
+    self.color.unwrap_or(DEFAULT_COLOR)
+  }
+
+  fn draw_rect(&self, ctx: &mut Context, rect: [f32; 4]) -> GameResult<()> {
+    let sprite = match self.sprite {
+      Some(ref sprite) => sprite,
+      None             => {
+        graphics::set_color(ctx, self.color().into())?;
+        return graphics::rectangle(ctx, graphics::DrawMode::Fill, [
+          rect[0].round(), rect[1].round(), rect[2].round(), rect[3].round()
+        ].into());
+      }
+    };
+    graphics::set_color(ctx, ::color::WHITE.into())?;
+    graphics::draw_ex(ctx, &sprite.image, graphics::DrawParam {
+      dest:  graphics::Point2::new(rect[0].round(), rect[1].round()),
+      scale: graphics::Point2::new(
+        rect[2] / sprite.image.width()  as f32,
+        rect[3] / sprite.image.height() as f32
+      ),
+      .. Default::default()
+    })
+  }
+}
+
+impl Velocity for EntityAdapter {
+  fn velocity(&self) -> &Point {
+    &self.velocity
+  }
+  fn velocity_mut(&mut self) -> &mut Point {
+    &mut self.velocity
+  }
+  fn max_velocity(&self) -> Point {
+    self.max_velocity.clone()
+  }
+}
+
+impl Movement for EntityAdapter {}
@@ -0,0 +1,81 @@
+use ::ggez::graphics::Image;
+
+use ::assets::{
+  Assets,
+  Handle
+};
+use ::color::Color;
+use ::geo::{
+  point::Point,
+  size::Size,
+  mask::misc::Origin
+};
+
+/// The entity's position; where exactly the position is on the entity is determined by its `Bounds`' `Origin`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position(pub Point);
+
+/// The entity's size and origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bounds {
+  pub size:   Size,
+  pub origin: Origin
+}
+
+impl Bounds {
+  pub fn new(size: Size, origin: Origin) -> Self {
+    Self { size, origin }
+  }
+}
+
+/// The entity's velocity and its maximum velocity, like the `Velocity` trait's `velocity` and `max_velocity`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Velocity {
+  pub velocity: Point,
+  pub max:      Point
+}
+
+impl Velocity {
+  pub fn new(max: Point) -> Self {
+    Self {
+      velocity: Point::new(0.0, 0.0),
+      max
+    }
+  }
+
+  /// Adds to the velocity and limits it to the maximum velocity, like `Velocity::add_velocity`.
+  pub fn add(&mut self, incr_velocity: &Point) {
+    self.velocity.add(incr_velocity);
+    self.velocity.x = self.velocity.x.max(-self.max.x).min(self.max.x);
+    self.velocity.y = self.velocity.y.max(-self.max.y).min(self.max.y);
+  }
+}
+
+/// Is added to the entity's `Velocity` every frame by the `accelerate` system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Acceleration(pub Point);
+
+/// The color the entity is drawn with, if it has no `Sprite`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorComponent(pub Color);
+
+/// An image which is drawn stretched over the entity's `Bounds`.
+#[derive(Debug, Clone)]
+pub struct Sprite {
+  pub image: Image
+}
+
+impl Sprite {
+  pub fn new(image: Image) -> Self {
+    Self { image }
+  }
+
+  /// Returns a sprite of an image loaded with `Assets`, or `None` if it isn't loaded.
+  pub fn from_asset(assets: &Assets, handle: &Handle<Image>) -> Option<Self> {
+    assets.get(handle).cloned().map(Self::new)
+  }
+}
+
+/// Marks an entity as solid; the `movement` system doesn't let entities move into solid entities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solid;
@@ -0,0 +1,102 @@
+//! An optional, lightweight entity-component-system, enabled with the `ecs` cargo feature.
+//! Instead of implementing the `Entity`, `Velocity` and `Movement` traits for every entity type,
+//! entities are plain IDs with components, and the built-in systems do the work of those traits.
+//! The `EntityAdapter` implements the traits for an entity in the ECS `World`,
+//! so code written against the traits can still be used.
+//!
+//! The `World` only stores the built-in components; to add your own components,
+//! keep a `Storage` of them next to the `World` and remove components when you despawn their entities.
+
+pub mod storage;
+pub mod component;
+pub mod adapter;
+pub mod systems;
+
+pub use self::storage::Storage;
+pub use self::adapter::EntityAdapter;
+
+use ::geo::rect::Rect;
+use ::world::EntityId;
+
+use self::component::*;
+
+/// Holds the entities and the storages of the built-in components.
+/// The storages are public fields, so different storages can be borrowed at the same time.
+pub struct World {
+  generations:       Vec<u32>,
+  alive:             Vec<bool>,
+  free:              Vec<usize>,
+  pub positions:     Storage<Position>,
+  pub bounds:        Storage<Bounds>,
+  pub velocities:    Storage<Velocity>,
+  pub accelerations: Storage<Acceleration>,
+  pub colors:        Storage<ColorComponent>,
+  pub sprites:       Storage<Sprite>,
+  pub solids:        Storage<Solid>
+}
+
+impl World {
+  pub fn new() -> Self {
+    Self {
+      generations:   Vec::new(),
+      alive:         Vec::new(),
+      free:          Vec::new(),
+      positions:     Storage::new(),
+      bounds:        Storage::new(),
+      velocities:    Storage::new(),
+      accelerations: Storage::new(),
+      colors:        Storage::new(),
+      sprites:       Storage::new(),
+      solids:        Storage::new()
+    }
+  }
+
+  /// Creates a new entity without any components.
+  pub fn spawn(&mut self) -> EntityId {
+    if let Some(index) = self.free.pop() {
+      self.alive[index] = true;
+      return EntityId::new(index, self.generations[index]);
+    }
+    self.generations.push(0);
+    self.alive.push(true);
+    EntityId::new(self.generations.len() - 1, 0)
+  }
+
+  /// Removes the entity and all of its built-in components.
+  pub fn despawn(&mut self, id: EntityId) {
+    if !self.is_alive(id) {
+      return;
+    }
+    self.positions.remove(id);
+    self.bounds.remove(id);
+    self.velocities.remove(id);
+    self.accelerations.remove(id);
+    self.colors.remove(id);
+    self.sprites.remove(id);
+    self.solids.remove(id);
+    self.alive[id.index()] = false;
+    self.generations[id.index()] += 1;
+    self.free.push(id.index());
+  }
+
+  pub fn is_alive(&self, id: EntityId) -> bool {
+    self.alive.get(id.index()) == Some(&true) &&
+      self.generations[id.index()] == id.generation()
+  }
+
+  /// Returns the entity's `Rect`, if it has a `Position` and `Bounds`.
+  pub fn rect(&self, id: EntityId) -> Option<Rect> {
+    let position = self.positions.get(id)?;
+    let bounds   = self.bounds.get(id)?;
+    Some(Rect::new(position.0.clone(), bounds.size.clone(), bounds.origin.clone()))
+  }
+}
+
+impl Default for World {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
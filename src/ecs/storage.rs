@@ -0,0 +1,115 @@
+use ::world::EntityId;
+
+/// Dense component storage.
+/// Components are stored contiguously, so iterating over a `Storage` is fast;
+/// a sparse index maps each entity's slot to its component.
+pub struct Storage<T> {
+  dense:    Vec<T>,
+  entities: Vec<EntityId>,
+  sparse:   Vec<Option<usize>>
+}
+
+impl<T> Storage<T> {
+  pub fn new() -> Self {
+    Self {
+      dense:    Vec::new(),
+      entities: Vec::new(),
+      sparse:   Vec::new()
+    }
+  }
+
+  fn dense_index(&self, id: EntityId) -> Option<usize> {
+    match self.sparse.get(id.index()) {
+      Some(&Some(index)) if self.entities[index] == id => Some(index),
+      _                                                => None
+    }
+  }
+
+  /// Inserts the component for the entity, returning the previous component if there was one.
+  pub fn insert(&mut self, id: EntityId, component: T) -> Option<T> {
+    if let Some(index) = self.dense_index(id) {
+      return Some(::std::mem::replace(&mut self.dense[index], component));
+    }
+    if self.sparse.len() <= id.index() {
+      self.sparse.resize(id.index() + 1, None);
+    }
+    // A stale component of a despawned entity with the same slot is replaced.
+    if let Some(index) = self.sparse[id.index()] {
+      self.entities[index] = id;
+      self.dense[index]    = component;
+      return None;
+    }
+    self.sparse[id.index()] = Some(self.dense.len());
+    self.dense.push(component);
+    self.entities.push(id);
+    None
+  }
+
+  /// Removes the entity's component and returns it.
+  pub fn remove(&mut self, id: EntityId) -> Option<T> {
+    let index = self.dense_index(id)?;
+    self.sparse[id.index()] = None;
+    self.entities.swap_remove(index);
+    let component = self.dense.swap_remove(index);
+    if let Some(moved) = self.entities.get(index) {
+      self.sparse[moved.index()] = Some(index);
+    }
+    Some(component)
+  }
+
+  pub fn get(&self, id: EntityId) -> Option<&T> {
+    self.dense_index(id).map( move |index| &self.dense[index] )
+  }
+
+  pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+    match self.dense_index(id) {
+      Some(index) => Some(&mut self.dense[index]),
+      None        => None
+    }
+  }
+
+  pub fn contains(&self, id: EntityId) -> bool {
+    self.dense_index(id).is_some()
+  }
+
+  pub fn len(&self) -> usize {
+    self.dense.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.dense.is_empty()
+  }
+
+  /// Returns the IDs of all entities with this component.
+  pub fn ids(&self) -> &[EntityId] {
+    &self.entities
+  }
+
+  pub fn iter<'a>(&'a self) -> impl Iterator<Item = (EntityId, &'a T)> + 'a {
+    self.entities.iter().cloned().zip(self.dense.iter())
+  }
+
+  pub fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (EntityId, &'a mut T)> + 'a {
+    self.entities.iter().cloned().zip(self.dense.iter_mut())
+  }
+
+  /// Iterates over all entities which have components in both storages.
+  pub fn join<'a, U>(&'a self, other: &'a Storage<U>) -> impl Iterator<Item = (EntityId, &'a T, &'a U)> + 'a {
+    self.iter().filter_map( move |(id, component)| {
+      other.get(id).map( |other_component| (id, component, other_component) )
+    })
+  }
+
+  /// Same as `join`, but this storage's components are borrowed mutably.
+  pub fn join_mut<'a, U>(&'a mut self, other: &'a Storage<U>) -> impl Iterator<Item = (EntityId, &'a mut T, &'a U)> + 'a {
+    self.iter_mut().filter_map( move |(id, component)| {
+      other.get(id).map( |other_component| (id, component, other_component) )
+    })
+  }
+}
+
+impl<T> Default for Storage<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
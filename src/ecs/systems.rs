@@ -0,0 +1,55 @@
+use ::ggez::{
+  Context,
+  GameResult
+};
+
+use ::geo::{
+  mask::Mask,
+  rect::Rect
+};
+use ::entity::Movement;
+use ::camera::Camera;
+use ::world::EntityId;
+
+use super::World;
+use super::adapter::EntityAdapter;
+
+/// Adds each entity's `Acceleration` to its `Velocity`, like `Velocity::add_velocity`.
+pub fn accelerate(world: &mut World) {
+  for (_, velocity, acceleration) in world.velocities.join_mut(&world.accelerations) {
+    velocity.add(&acceleration.0);
+  }
+}
+
+/// Moves every entity with a `Position`, `Bounds` and `Velocity`, like `Movement::move_while`.
+/// Entities can't move into entities marked as `Solid`.
+pub fn movement(world: &mut World) {
+  let ids: Vec<EntityId> = world.velocities.ids().to_vec();
+  for id in ids {
+    let new_position = match EntityAdapter::read(world, id) {
+      Some(adapter) => adapter.get_move_while( |rect| !intersects_solid(world, rect, id) ),
+      None          => continue
+    };
+    if let Some(position) = world.positions.get_mut(id) {
+      position.0 = new_position;
+    }
+  }
+}
+
+/// Returns `true` if the `Rect` intersects with any `Solid` entity, other than the entity with the given ID.
+pub fn intersects_solid(world: &World, rect: &Rect, id: EntityId) -> bool {
+  world.solids.ids().iter().any( |&other| {
+    other != id && world.rect(other).map_or(false, |other_rect| rect.intersects(&other_rect) )
+  })
+}
+
+/// Draws every entity with a `Position` and `Bounds` through the `Camera`, like `Camera::draw`.
+/// Entities are drawn with their `Sprite` if they have one, otherwise with their `ColorComponent`.
+pub fn draw(world: &World, ctx: &mut Context, camera: &Camera) -> GameResult<()> {
+  for &id in world.positions.ids() {
+    if let Some(adapter) = EntityAdapter::read(world, id) {
+      camera.draw(ctx, &adapter)?;
+    }
+  }
+  Ok(())
+}
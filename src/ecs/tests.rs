@@ -0,0 +1,93 @@
+use ::geo::prelude::*;
+use ::entity::{
+  Velocity as VelocityTrait,
+  Movement
+};
+use super::{ World, Storage, EntityAdapter, systems };
+use super::component::*;
+
+fn spawn_box(world: &mut World, x: NumType, y: NumType) -> ::world::EntityId {
+  let id = world.spawn();
+  world.positions.insert(id, Position(Point::new(x, y)));
+  world.bounds.insert(id, Bounds::new(Size::new(10.0, 10.0), Origin::TopLeft));
+  id
+}
+
+#[test]
+fn storage_insert_and_remove() {
+  let mut world = World::new();
+  let one = world.spawn();
+  let two = world.spawn();
+  let mut storage = Storage::new();
+  storage.insert(one, "one");
+  storage.insert(two, "two");
+  assert_eq!(storage.remove(one), Some("one"));
+  assert_eq!(storage.get(one), None);
+  assert_eq!(storage.get(two), Some(&"two"));
+  assert_eq!(storage.len(), 1);
+}
+
+#[test]
+fn despawn_removes_components() {
+  let mut world = World::new();
+  let id = spawn_box(&mut world, 0.0, 0.0);
+  world.despawn(id);
+  assert!(!world.is_alive(id));
+  assert!(world.positions.get(id).is_none());
+  let new_id = world.spawn();
+  assert_eq!(new_id.index(), id.index());
+  assert!(world.is_alive(new_id));
+  assert!(!world.is_alive(id));
+}
+
+#[test]
+fn join_storages() {
+  let mut world = World::new();
+  let moving = spawn_box(&mut world, 0.0, 0.0);
+  spawn_box(&mut world, 20.0, 0.0);
+  world.velocities.insert(moving, Velocity::new(Point::new(5.0, 5.0)));
+  let joined: Vec<_> = world.positions.join(&world.velocities).map( |(id, _, _)| id ).collect();
+  assert_eq!(joined, vec![moving]);
+}
+
+#[test]
+fn accelerate_limits_velocity() {
+  let mut world = World::new();
+  let id = spawn_box(&mut world, 0.0, 0.0);
+  world.velocities.insert(id, Velocity::new(Point::new(5.0, 5.0)));
+  world.accelerations.insert(id, Acceleration(Point::new(3.0, -1.0)));
+  systems::accelerate(&mut world);
+  systems::accelerate(&mut world);
+  assert_eq!(world.velocities.get(id).unwrap().velocity, Point::new(5.0, -2.0));
+}
+
+#[test]
+fn movement_stops_at_solids() {
+  let mut world = World::new();
+  let player = spawn_box(&mut world, 0.0, 0.0);
+  let wall   = spawn_box(&mut world, 25.0, 0.0);
+  world.solids.insert(wall, Solid);
+  let mut velocity = Velocity::new(Point::new(20.0, 20.0));
+  velocity.velocity = Point::new(10.0, 0.0);
+  world.velocities.insert(player, velocity);
+
+  systems::movement(&mut world);
+  assert_eq!(world.positions.get(player).unwrap().0, Point::new(10.0, 0.0));
+  systems::movement(&mut world);
+  assert_eq!(world.positions.get(player).unwrap().0, Point::new(15.0, 0.0));
+}
+
+#[test]
+fn adapter_implements_traits() {
+  let mut world = World::new();
+  let id = spawn_box(&mut world, 0.0, 0.0);
+  world.velocities.insert(id, Velocity::new(Point::new(4.0, 4.0)));
+  let mut adapter = EntityAdapter::read(&world, id).unwrap();
+  adapter.add_velocity(&Point::new(10.0, 2.0));
+  adapter.move_while( |_| true );
+  adapter.resize_from(Size::new(10.0, 5.0), &Origin::BottomLeft);
+  adapter.write(&mut world);
+  assert_eq!(world.positions.get(id).unwrap().0, Point::new(4.0, 7.0));
+  assert_eq!(world.velocities.get(id).unwrap().velocity, Point::new(4.0, 2.0));
+  assert_eq!(world.bounds.get(id).unwrap().size, Size::new(10.0, 5.0));
+}
@@ -0,0 +1,12 @@
+pub mod platformer;
+pub mod top_down;
+
+pub use self::platformer::{
+  PlatformerController,
+  PlatformerConfig,
+  PlatformerInput
+};
+pub use self::top_down::{
+  TopDownController,
+  TopDownConfig
+};
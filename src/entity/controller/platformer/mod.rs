@@ -0,0 +1,287 @@
+use ::ggez::event::Keycode;
+
+use ::geo::{
+  NumType,
+  point::Point,
+  rect::Rect,
+  mask::misc::Side
+};
+use ::deltatime::Deltatime;
+use ::input_manager::InputManager;
+use super::super::traits::movement::Movement;
+
+/// Movements smaller than this are treated as not having moved at all.
+const EPSILON: NumType = 0.0001;
+
+/// How many times the remaining distance to an obstacle is halved, when moving into contact with it.
+const CONTACT_STEPS: usize = 8;
+
+/// The tuning values of a `PlatformerController`.
+/// Speeds are in pixels per second, accelerations in pixels per second squared, and times in seconds.
+/// With the `serde` feature, this can be loaded from a data file; missing fields use the default values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PlatformerConfig {
+  /// Downwards acceleration.
+  pub gravity:             NumType,
+  /// Maximum downwards speed.
+  pub max_fall_speed:      NumType,
+  /// Maximum horizontal speed.
+  pub run_speed:           NumType,
+  /// Horizontal acceleration on the ground, while moving.
+  pub ground_acceleration: NumType,
+  /// Horizontal deceleration on the ground, when there is no horizontal input.
+  pub ground_deceleration: NumType,
+  /// Horizontal acceleration in the air, while moving.
+  pub air_acceleration:    NumType,
+  /// Horizontal deceleration in the air, when there is no horizontal input.
+  pub air_deceleration:    NumType,
+  /// Upwards speed at the start of a jump.
+  pub jump_speed:          NumType,
+  /// The upwards speed is multiplied by this when the jump button is released early,
+  /// so holding the jump button jumps higher; `1.0` disables variable jump height.
+  pub jump_cut:            NumType,
+  /// How long after walking off a ledge the entity can still jump.
+  pub coyote_time:         NumType,
+  /// How long before landing a jump input is remembered.
+  pub jump_buffer_time:    NumType,
+  /// Maximum downwards speed while sliding down a wall; wall sliding is disabled if this is `None`.
+  pub wall_slide_speed:    Option<NumType>,
+  /// The velocity of a wall jump; `x` is away from the wall, `y` is upwards.
+  /// Wall jumping is disabled if this is `None`.
+  pub wall_jump_speed:     Option<Point>
+}
+
+impl Default for PlatformerConfig {
+  fn default() -> Self {
+    Self {
+      gravity:             1000.0,
+      max_fall_speed:      600.0,
+      run_speed:           200.0,
+      ground_acceleration: 1500.0,
+      ground_deceleration: 2000.0,
+      air_acceleration:    1000.0,
+      air_deceleration:    500.0,
+      jump_speed:          400.0,
+      jump_cut:            0.5,
+      coyote_time:         0.1,
+      jump_buffer_time:    0.1,
+      wall_slide_speed:    Some(100.0),
+      wall_jump_speed:     Some(Point::new(250.0, 350.0))
+    }
+  }
+}
+
+/// The input for one frame of a `PlatformerController`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlatformerInput {
+  /// Horizontal direction, from `-1.0` (left) to `1.0` (right).
+  pub horizontal:   NumType,
+  /// `true` in the frame the jump button was pressed.
+  pub jump_pressed: bool,
+  /// `true` while the jump button is held down.
+  pub jump_held:    bool
+}
+
+impl PlatformerInput {
+  /// Returns the input from the `InputManager`'s keyboard state, using the passed keys.
+  pub fn from_keys(input_manager: &InputManager, left: Keycode, right: Keycode, jump: Keycode) -> Self {
+    let pressed = input_manager.keys_pressed();
+    let mut horizontal = 0.0;
+    if pressed.contains(&left) {
+      horizontal -= 1.0;
+    }
+    if pressed.contains(&right) {
+      horizontal += 1.0;
+    }
+    Self {
+      horizontal,
+      jump_pressed: input_manager.keys_down().contains(&jump),
+      jump_held:    pressed.contains(&jump)
+    }
+  }
+}
+
+/// A configurable platformer character controller, with gravity, variable jump height, coyote time,
+/// jump buffering, wall sliding and wall jumping.
+/// It uses the Entity's `Velocity` in pixels per second, and moves it with `Movement::get_move_by`.
+pub struct PlatformerController {
+  pub config:        PlatformerConfig,
+  grounded:          bool,
+  wall:              Option<Side>,
+  coyote_timer:      NumType,
+  jump_buffer_timer: NumType,
+  jumping:           bool
+}
+
+impl PlatformerController {
+  pub fn new(config: PlatformerConfig) -> Self {
+    Self {
+      config,
+      grounded:          false,
+      wall:              None,
+      coyote_timer:      0.0,
+      jump_buffer_timer: 0.0,
+      jumping:           false
+    }
+  }
+
+  /// Returns `true` if the entity was standing on the ground after the last `update`.
+  pub fn is_grounded(&self) -> bool {
+    self.grounded
+  }
+
+  /// Returns the side (`Side::Left` or `Side::Right`) the entity was touching a wall on
+  /// after the last `update`, while in the air.
+  pub fn wall(&self) -> Option<Side> {
+    self.wall
+  }
+
+  /// Returns `true` if the entity is rising from a jump.
+  pub fn is_jumping(&self) -> bool {
+    self.jumping
+  }
+
+  /// Updates the entity's velocity from the input and moves it.
+  /// The closure `can_move_to` works the same way as the one passed to `Movement::move_while`;
+  /// it is also used to check if the entity is standing on the ground or touching a wall.
+  pub fn update<E, C>(&mut self, entity: &mut E, input: &PlatformerInput, dt: &Deltatime, can_move_to: C)
+  where E: Movement,
+        C: Fn(&Rect) -> bool {
+    let secs = dt.secs();
+    self.grounded = Self::is_blocked(entity, &Point::new(0.0, 1.0), &can_move_to);
+    self.wall = Self::get_wall(entity, self.grounded, &can_move_to);
+
+    if self.grounded {
+      self.coyote_timer = self.config.coyote_time;
+      self.jumping      = false;
+    } else {
+      self.coyote_timer -= secs;
+    }
+    if input.jump_pressed {
+      self.jump_buffer_timer = self.config.jump_buffer_time;
+    } else {
+      self.jump_buffer_timer -= secs;
+    }
+
+    self.update_horizontal(entity, input, secs);
+    self.update_vertical(entity, input, secs);
+    self.update_jump(entity, input);
+
+    let delta = entity.velocity().mult_axes_by(secs);
+    let new_position = entity.get_move_by(&delta, &can_move_to);
+    let moved = new_position.clone() - entity.point().clone();
+    entity.point_mut().set(&new_position);
+    if (moved.x - delta.x).abs() > EPSILON {
+      Self::move_into_contact(entity, &Point::new(delta.x.signum(), 0.0), &can_move_to);
+      entity.set_velocity_x(0.0);
+    }
+    if (moved.y - delta.y).abs() > EPSILON {
+      Self::move_into_contact(entity, &Point::new(0.0, delta.y.signum()), &can_move_to);
+      entity.set_velocity_y(0.0);
+      self.jumping = false;
+    }
+
+    self.grounded = entity.velocity().y >= 0.0 &&
+      Self::is_blocked(entity, &Point::new(0.0, 1.0), &can_move_to);
+    self.wall = Self::get_wall(entity, self.grounded, &can_move_to);
+  }
+
+  fn is_blocked<E: Movement, C: Fn(&Rect) -> bool>(entity: &E, offset: &Point, can_move_to: &C) -> bool {
+    let rect = Rect::new(
+      entity.point().clone() + offset.clone(),
+      entity.size().clone(),
+      entity.origin().clone()
+    );
+    !can_move_to(&rect)
+  }
+
+  /// `Movement` stops up to one pixel before an obstacle, if the entity is not aligned to the pixel grid.
+  /// This closes the remaining gap in the passed direction, so the entity doesn't hover over the ground.
+  fn move_into_contact<E: Movement, C: Fn(&Rect) -> bool>(entity: &mut E, direction: &Point, can_move_to: &C) {
+    let mut step = 0.5;
+    for _ in 0 .. CONTACT_STEPS {
+      let offset = direction.mult_axes_by(step);
+      if !Self::is_blocked(entity, &offset, can_move_to) {
+        entity.point_mut().add(&offset);
+      }
+      step *= 0.5;
+    }
+  }
+
+  fn get_wall<E: Movement, C: Fn(&Rect) -> bool>(entity: &E, grounded: bool, can_move_to: &C) -> Option<Side> {
+    if grounded {
+      None
+    } else if Self::is_blocked(entity, &Point::new(-1.0, 0.0), can_move_to) {
+      Some(Side::Left)
+    } else if Self::is_blocked(entity, &Point::new(1.0, 0.0), can_move_to) {
+      Some(Side::Right)
+    } else {
+      None
+    }
+  }
+
+  fn update_horizontal<E: Movement>(&self, entity: &mut E, input: &PlatformerInput, secs: NumType) {
+    let config = &self.config;
+    let target = input.horizontal.clamp(-1.0, 1.0) * config.run_speed;
+    if target == 0.0 {
+      let deceleration = if self.grounded { config.ground_deceleration } else { config.air_deceleration };
+      entity.decrease_velocity(&Point::new(deceleration * secs, 0.0));
+      return;
+    }
+    let acceleration = if self.grounded { config.ground_acceleration } else { config.air_acceleration };
+    let velocity = entity.velocity().x;
+    let step = acceleration * secs;
+    let new_velocity = if velocity < target {
+      (velocity + step).min(target)
+    } else {
+      (velocity - step).max(target)
+    };
+    entity.set_velocity_x(new_velocity);
+  }
+
+  fn update_vertical<E: Movement>(&mut self, entity: &mut E, input: &PlatformerInput, secs: NumType) {
+    let config = &self.config;
+    let mut velocity = (entity.velocity().y + config.gravity * secs).min(config.max_fall_speed);
+    if let (Some(wall), Some(wall_slide_speed)) = (self.wall, config.wall_slide_speed) {
+      let pushing_into_wall = match wall {
+        Side::Left  => input.horizontal < 0.0,
+        Side::Right => input.horizontal > 0.0,
+        _           => false
+      };
+      if pushing_into_wall {
+        velocity = velocity.min(wall_slide_speed);
+      }
+    }
+    if self.jumping && !input.jump_held && velocity < 0.0 {
+      velocity *= config.jump_cut;
+      self.jumping = false;
+    }
+    if velocity >= 0.0 {
+      self.jumping = false;
+    }
+    entity.set_velocity_y(velocity);
+  }
+
+  fn update_jump<E: Movement>(&mut self, entity: &mut E, input: &PlatformerInput) {
+    if self.jump_buffer_timer <= 0.0 {
+      return;
+    }
+    if self.grounded || self.coyote_timer > 0.0 {
+      entity.set_velocity_y(-self.config.jump_speed);
+    } else if let (Some(wall), Some(wall_jump_speed)) = (self.wall, self.config.wall_jump_speed.clone()) {
+      let direction = if wall == Side::Left { 1.0 } else { -1.0 };
+      entity.set_velocity(&Point::new(wall_jump_speed.x * direction, -wall_jump_speed.y));
+    } else {
+      return;
+    }
+    self.jump_buffer_timer = 0.0;
+    self.coyote_timer      = 0.0;
+    self.grounded          = false;
+    self.jumping           = input.jump_held || input.jump_pressed;
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,162 @@
+use ::geo::prelude::*;
+use ::geo::mask::misc::Side;
+use ::testing::{
+  Body,
+  deltatime,
+  assert_near,
+  assert_within
+};
+use super::super::super::prelude::*;
+use super::{
+  PlatformerController,
+  PlatformerConfig,
+  PlatformerInput
+};
+
+const FLOOR: NumType = 100.0;
+
+/// A floor at `FLOOR`, which ends at x = 100, and a wall at x >= 200.
+fn can_move_to(rect: &Rect) -> bool {
+  let on_floor = rect.side(Side::Left) < 100.0;
+  !(on_floor && rect.side(Side::Bottom) > FLOOR) && rect.side(Side::Right) <= 200.0
+}
+
+fn jump_input(frame: usize, hold_frames: usize) -> PlatformerInput {
+  PlatformerInput {
+    horizontal:   0.0,
+    jump_pressed: frame == 0,
+    jump_held:    frame < hold_frames
+  }
+}
+
+/// Jumps from the floor and returns the height above the floor after every frame, until landing.
+fn jump_arc(hold_frames: usize) -> Vec<NumType> {
+  let config = PlatformerConfig { jump_speed: 400.0, gravity: 1000.0, .. PlatformerConfig::default() };
+  let mut controller = PlatformerController::new(config);
+  let mut body = Body::new(50.0, FLOOR).with_origin(Origin::BottomCenter);
+  let dt = deltatime(20);
+  let mut heights = Vec::new();
+  for frame in 0 .. 100 {
+    controller.update(&mut body, &jump_input(frame, hold_frames), &dt, can_move_to);
+    heights.push(FLOOR - body.point().y);
+    if frame > 0 && controller.is_grounded() {
+      break;
+    }
+  }
+  heights
+}
+
+#[test]
+fn full_jump_arc() {
+  let heights = jump_arc(100);
+  // Velocity goes from -400 to 0 in steps of 20 per frame (20ms at 1000 px/s²),
+  // so the entity rises by 8, 7.6, 7.2, ... 0.4 pixels.
+  assert_near(heights[0], 8.0);
+  assert_near(heights[1], 15.6);
+  assert_near(heights[19], 84.0);
+  assert_near(heights[20], 84.0);
+  let apex = heights.iter().cloned().fold(0.0, NumType::max);
+  assert_near(apex, 84.0);
+  assert_eq!(heights.len(), 41);
+  // Lands up to a fraction of the last step above the floor
+  assert_within(*heights.last().unwrap(), 0.0, 0.01);
+}
+
+#[test]
+fn releasing_jump_early_jumps_lower() {
+  let full  = jump_arc(100);
+  let short = jump_arc(5);
+  // After 5 frames the entity is 36 pixels high with a velocity of -300,
+  // which is cut in half; it then rises by 3, 2.6, 2.2, ... 0.2 pixels.
+  let short_apex = short.iter().cloned().fold(0.0, NumType::max);
+  assert_near(short_apex, 48.8);
+  assert!(short.len() < full.len());
+  assert_within(*short.last().unwrap(), 0.0, 0.01);
+}
+
+#[test]
+fn coyote_time_allows_late_jump() {
+  let mut controller = PlatformerController::new(PlatformerConfig::default());
+  let dt = deltatime(20);
+  // Standing right at the ledge, walking off it.
+  let mut body = Body::new(104.0, FLOOR).with_origin(Origin::BottomCenter);
+  let walk = PlatformerInput { horizontal: 1.0, .. PlatformerInput::default() };
+  body.set_velocity_x(200.0);
+  controller.update(&mut body, &walk, &dt, can_move_to);
+  controller.update(&mut body, &walk, &dt, can_move_to);
+  assert!(!controller.is_grounded());
+  assert!(body.point().y > FLOOR);
+  let jump = PlatformerInput { horizontal: 1.0, jump_pressed: true, jump_held: true };
+  controller.update(&mut body, &jump, &dt, can_move_to);
+  assert!(body.velocity().y < 0.0);
+}
+
+#[test]
+fn no_jump_after_coyote_time() {
+  let config = PlatformerConfig { coyote_time: 0.05, .. PlatformerConfig::default() };
+  let mut controller = PlatformerController::new(config);
+  let dt = deltatime(20);
+  // Standing on the floor, close to the ledge.
+  let mut body = Body::new(98.0, FLOOR).with_origin(Origin::BottomCenter);
+  controller.update(&mut body, &PlatformerInput::default(), &dt, can_move_to);
+  assert!(controller.is_grounded());
+  // Walking off the ledge, then falling for longer than the coyote time.
+  let walk = PlatformerInput { horizontal: 1.0, .. PlatformerInput::default() };
+  body.set_velocity_x(200.0);
+  while controller.is_grounded() {
+    controller.update(&mut body, &walk, &dt, can_move_to);
+  }
+  for _ in 0 .. 3 {
+    controller.update(&mut body, &walk, &dt, can_move_to);
+  }
+  assert!(body.point().y > FLOOR);
+  let jump = PlatformerInput { horizontal: 1.0, jump_pressed: true, jump_held: true };
+  controller.update(&mut body, &jump, &dt, can_move_to);
+  assert!(!controller.is_jumping());
+  assert!(body.velocity().y > 0.0);
+}
+
+#[test]
+fn buffered_jump_triggers_on_landing() {
+  let config = PlatformerConfig { jump_buffer_time: 0.1, .. PlatformerConfig::default() };
+  let mut controller = PlatformerController::new(config);
+  let dt = deltatime(20);
+  // Falling just above the floor.
+  let mut body = Body::new(50.0, FLOOR - 5.0).with_origin(Origin::BottomCenter);
+  body.set_velocity_y(300.0);
+  let jump = PlatformerInput { jump_pressed: true, jump_held: true, .. PlatformerInput::default() };
+  let hold = PlatformerInput { jump_held: true, .. PlatformerInput::default() };
+  controller.update(&mut body, &jump, &dt, can_move_to);
+  assert!(controller.is_grounded());
+  assert!(body.velocity().y >= 0.0);
+  controller.update(&mut body, &hold, &dt, can_move_to);
+  assert!(body.velocity().y < 0.0);
+  assert!(controller.is_jumping());
+}
+
+#[test]
+fn wall_slide_and_wall_jump() {
+  let mut controller = PlatformerController::new(PlatformerConfig::default());
+  let dt = deltatime(20);
+  let mut body = Body::new(195.0, 0.0).with_origin(Origin::BottomCenter);
+  let push = PlatformerInput { horizontal: 1.0, .. PlatformerInput::default() };
+  for _ in 0 .. 20 {
+    controller.update(&mut body, &push, &dt, can_move_to);
+  }
+  assert_eq!(controller.wall(), Some(Side::Right));
+  assert_near(body.velocity().y, 100.0);
+  let jump = PlatformerInput { horizontal: 1.0, jump_pressed: true, jump_held: true };
+  controller.update(&mut body, &jump, &dt, can_move_to);
+  assert!(body.velocity().x < 0.0);
+  assert!(body.velocity().y < 0.0);
+  assert_eq!(controller.wall(), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn load_config_from_data() {
+  let config: PlatformerConfig = ::serde_json::from_str(r#"{ "jump_speed": 500.0, "wall_jump_speed": null }"#).unwrap();
+  assert_eq!(config.jump_speed, 500.0);
+  assert_eq!(config.wall_jump_speed, None);
+  assert_eq!(config.gravity, PlatformerConfig::default().gravity);
+}
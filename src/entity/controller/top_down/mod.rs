@@ -0,0 +1,185 @@
+use ::ggez::event::Keycode;
+
+use ::geo::{
+  NumType,
+  point::Point,
+  rect::Rect
+};
+use ::deltatime::Deltatime;
+use ::input_manager::InputManager;
+use super::super::traits::movement::Movement;
+
+/// Movements smaller than this are treated as not having moved at all.
+const EPSILON: NumType = 0.0001;
+
+/// `tan(22.5°)`; inputs closer than this angle to an axis snap to that axis with `Directions::Eight`.
+const TAN_22_5: NumType = 0.414_213_57;
+
+/// How the velocity changes towards the target velocity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Response {
+  /// The velocity changes by a fixed amount of pixels per second, every second.
+  Linear(NumType),
+  /// The velocity approaches the target exponentially;
+  /// the value is the rate per second, higher values are snappier.
+  /// After `1 / rate` seconds, about 63% of the difference has been closed.
+  Exponential(NumType)
+}
+
+impl Response {
+  /// Returns the new velocity, moved from `velocity` towards `target` over `secs` seconds.
+  pub fn approach(&self, velocity: &Point, target: &Point, secs: NumType) -> Point {
+    let difference = target.clone() - velocity.clone();
+    match self {
+      Response::Linear(rate) => {
+        let step = rate * secs;
+        if difference.length() <= step {
+          target.clone()
+        } else {
+          velocity.clone() + difference.with_length(step)
+        }
+      }
+      Response::Exponential(rate) => {
+        let factor = 1.0 - (-rate * secs).exp();
+        velocity.clone() + difference.mult_axes_by(factor)
+      }
+    }
+  }
+}
+
+/// In which directions the entity can move.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Directions {
+  /// Any direction, for analog input.
+  Any,
+  /// Only up, down, left and right; the input's dominant axis wins.
+  Four,
+  /// Horizontal, vertical and diagonal directions; the input snaps to the nearest one.
+  Eight
+}
+
+impl Directions {
+  /// Snaps the input direction to the allowed directions.
+  /// The returned direction is at most `1` long, so diagonals aren't faster than straight lines.
+  pub fn snap(&self, input: &Point) -> Point {
+    match self {
+      Directions::Any   => input.limited(1.0),
+      Directions::Four  => if input.x.abs() >= input.y.abs() {
+        Point::new(sign(input.x), 0.0)
+      } else {
+        Point::new(0.0, sign(input.y))
+      },
+      Directions::Eight => {
+        let x = if input.x.abs() > input.y.abs() * TAN_22_5 { sign(input.x) } else { 0.0 };
+        let y = if input.y.abs() > input.x.abs() * TAN_22_5 { sign(input.y) } else { 0.0 };
+        Point::new(x, y).normalized()
+      }
+    }
+  }
+}
+
+fn sign(value: NumType) -> NumType {
+  if value > EPSILON {
+    1.0
+  } else if value < -EPSILON {
+    -1.0
+  } else {
+    0.0
+  }
+}
+
+/// The tuning values of a `TopDownController`.
+/// Speeds are in pixels per second.
+/// With the `serde` feature, this can be loaded from a data file; missing fields use the default values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TopDownConfig {
+  /// Maximum speed, in any direction.
+  pub max_speed:    NumType,
+  /// How the velocity changes while there is input.
+  pub acceleration: Response,
+  /// How the velocity changes while there is no input; this is the friction.
+  pub deceleration: Response,
+  /// In which directions the entity can move.
+  pub directions:   Directions
+}
+
+impl Default for TopDownConfig {
+  fn default() -> Self {
+    Self {
+      max_speed:    150.0,
+      acceleration: Response::Linear(1200.0),
+      deceleration: Response::Linear(1500.0),
+      directions:   Directions::Eight
+    }
+  }
+}
+
+/// A top-down movement controller, for games like twin-stick shooters and Zelda-likes.
+/// The speed is limited by the length of the velocity, not per axis, so diagonal movement
+/// isn't faster than straight movement. Acceleration and friction are scaled by the deltatime.
+/// It uses the Entity's `Velocity` in pixels per second, and moves it with `Movement::get_move_by`.
+pub struct TopDownController {
+  pub config: TopDownConfig
+}
+
+impl TopDownController {
+  pub fn new(config: TopDownConfig) -> Self {
+    Self { config }
+  }
+
+  /// Returns the input direction from the `InputManager`'s keyboard state, using the passed keys.
+  pub fn direction_from_keys(
+    input_manager: &InputManager, up: Keycode, down: Keycode, left: Keycode, right: Keycode
+  ) -> Point {
+    let pressed = input_manager.keys_pressed();
+    let axis = |negative: Keycode, positive: Keycode| {
+      let mut value = 0.0;
+      if pressed.contains(&negative) {
+        value -= 1.0;
+      }
+      if pressed.contains(&positive) {
+        value += 1.0;
+      }
+      value
+    };
+    Point::new(axis(left, right), axis(up, down))
+  }
+
+  /// Updates the entity's velocity from the input direction and moves it.
+  /// The `direction` may be any length; longer directions are limited to a length of `1`.
+  /// The closure `can_move_to` works the same way as the one passed to `Movement::move_while`.
+  /// When the entity is blocked on an axis, its velocity on that axis is set to `0`,
+  /// so it slides along walls.
+  pub fn update<E, C>(&self, entity: &mut E, direction: &Point, dt: &Deltatime, can_move_to: C)
+  where E: Movement,
+        C: Fn(&Rect) -> bool {
+    let secs = dt.secs();
+    let direction = self.config.directions.snap(direction);
+    let target = direction.mult_axes_by(self.config.max_speed);
+    let response = if direction.length() > 0.0 {
+      &self.config.acceleration
+    } else {
+      &self.config.deceleration
+    };
+    let velocity = response.approach(entity.velocity(), &target, secs).limited(self.config.max_speed);
+    entity.set_velocity(&velocity);
+
+    let delta = velocity.mult_axes_by(secs);
+    let new_position = entity.get_move_by(&delta, can_move_to);
+    let moved = new_position.clone() - entity.point().clone();
+    entity.point_mut().set(&new_position);
+    if (moved.x - delta.x).abs() > EPSILON {
+      entity.set_velocity_x(0.0);
+    }
+    if (moved.y - delta.y).abs() > EPSILON {
+      entity.set_velocity_y(0.0);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use ::geo::prelude::*;
+use ::geo::mask::misc::Side;
+use ::testing::{
+  Body,
+  deltatime,
+  assert_near
+};
+use super::super::super::prelude::*;
+use super::{
+  TopDownController,
+  TopDownConfig,
+  Response,
+  Directions
+};
+
+#[test]
+fn diagonal_movement_is_not_faster() {
+  let controller = TopDownController::new(TopDownConfig {
+    max_speed:    100.0,
+    acceleration: Response::Linear(10000.0),
+    .. TopDownConfig::default()
+  });
+  let mut body = Body::new(0.0, 0.0).with_origin(Origin::Center);
+  controller.update(&mut body, &Point::new(1.0, 1.0), &deltatime(20), |_| true);
+  assert_near(body.velocity().length(), 100.0);
+  assert_near(body.velocity().x, body.velocity().y);
+}
+
+#[test]
+fn four_way_snapping() {
+  assert_eq!(Directions::Four.snap(&Point::new(0.8, -0.5)), Point::new(1.0, 0.0));
+  assert_eq!(Directions::Four.snap(&Point::new(0.2, -0.5)), Point::new(0.0, -1.0));
+  assert_eq!(Directions::Four.snap(&Point::new(0.0,  0.0)), Point::new(0.0, 0.0));
+}
+
+#[test]
+fn eight_way_snapping() {
+  assert_eq!(Directions::Eight.snap(&Point::new(0.3, 0.9)), Point::new(0.0, 1.0));
+  assert_eq!(Directions::Eight.snap(&Point::new(-0.9, 0.35)), Point::new(-1.0, 0.0));
+  let diagonal = Directions::Eight.snap(&Point::new(0.5, -0.9));
+  assert_near(diagonal.x, FRAC_1_SQRT_2);
+  assert_near(diagonal.y, -FRAC_1_SQRT_2);
+  assert_eq!(Directions::Eight.snap(&Point::new(0.0, -0.9)), Point::new(0.0, -1.0));
+  assert_near(Directions::Any.snap(&Point::new(0.3, 0.4)).length(), 0.5);
+}
+
+#[test]
+fn friction_is_scaled_by_deltatime() {
+  let controller = TopDownController::new(TopDownConfig {
+    deceleration: Response::Linear(100.0),
+    .. TopDownConfig::default()
+  });
+  let mut one_step  = Body::new(0.0, 0.0).with_origin(Origin::Center);
+  let mut two_steps = Body::new(0.0, 0.0).with_origin(Origin::Center);
+  one_step.set_velocity(&Point::new(30.0, 40.0));
+  two_steps.set_velocity(&Point::new(30.0, 40.0));
+  controller.update(&mut one_step, &Point::new(0.0, 0.0), &deltatime(100), |_| true);
+  controller.update(&mut two_steps, &Point::new(0.0, 0.0), &deltatime(50), |_| true);
+  controller.update(&mut two_steps, &Point::new(0.0, 0.0), &deltatime(50), |_| true);
+  assert_near(one_step.velocity().length(), 40.0);
+  assert_near(two_steps.velocity().length(), 40.0);
+  assert_near(one_step.velocity().x / one_step.velocity().y, 0.75);
+}
+
+#[test]
+fn exponential_response() {
+  let response = Response::Exponential(2.0);
+  let velocity = response.approach(&Point::new(0.0, 0.0), &Point::new(100.0, 0.0), 0.5);
+  assert_near(velocity.x, 63.212);
+}
+
+#[test]
+fn slide_along_walls() {
+  let controller = TopDownController::new(TopDownConfig {
+    acceleration: Response::Linear(10000.0),
+    .. TopDownConfig::default()
+  });
+  let mut body = Body::new(0.0, 0.0).with_origin(Origin::Center);
+  let wall_right = |rect: &Rect| rect.side(Side::Right) <= 6.0;
+  controller.update(&mut body, &Point::new(1.0, 1.0), &deltatime(100), wall_right);
+  assert_near(body.side(Side::Right), 6.0);
+  assert_eq!(body.velocity().x, 0.0);
+  assert!(body.velocity().y > 0.0);
+  assert!(body.point().y > 10.0);
+}
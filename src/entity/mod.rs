@@ -1,8 +1,20 @@
 pub mod traits;
+pub mod controller;
 
 pub mod prelude;
-pub use self::traits::velocity::Velocity;
-pub use self::traits::movement::Movement;
+pub use self::traits::velocity::{
+  Velocity,
+  Integrator
+};
+pub use self::traits::movement::{
+  Movement,
+  MoveResult
+};
+pub use self::traits::collider::{
+  Collider,
+  ColliderKind
+};
+pub use self::traits::kinematic::Kinematic;
 
 use ::ggez::{
   Context,
@@ -13,6 +25,8 @@ use ::ggez::{
 use ::settings::entity::*;
 
 use ::color::Color;
+use ::layers::Layers;
+use ::world::World;
 use ::geo::{
   point::Point,
   size::Size,
@@ -24,10 +38,28 @@ pub trait Entity: Mask {
     DEFAULT_COLOR
   }
 
+  /// Trigger entities don't collide with anything; a `World` reports when other entities
+  /// enter, stay in or exit them, as `TriggerEvent`s on its `EventBus`.
+  fn is_trigger(&self) -> bool {
+    false
+  }
+
+  /// The collision layers this Entity is on; a `LayerMatrix` decides what it collides and triggers with.
+  fn layers(&self) -> Layers {
+    Layers::default()
+  }
+
   fn update(&mut self, _ctx: &mut Context) -> GameResult<()> {
     Ok(())
   }
 
+  /// Is called by `World::update` instead of `update`; by default it just calls `update`.
+  /// While it runs, the Entity is taken out of the `World`, so the other entities can be looked up
+  /// through the passed reference, and events can be published with `world.events().publish(...)`.
+  fn update_in_world(&mut self, ctx: &mut Context, _world: &World) -> GameResult<()> {
+    self.update(ctx)
+  }
+
   fn draw_rect(&self, ctx: &mut Context, rect: [f32; 4]) -> GameResult<()> {
     graphics::set_color(ctx, self.color().into())?;
     let rect = [
@@ -36,7 +68,7 @@ pub trait Entity: Mask {
       rect[2].round(),
       rect[3].round()
     ];
-    return graphics::rectangle(ctx, graphics::DrawMode::Fill, rect.into());
+    graphics::rectangle(ctx, graphics::DrawMode::Fill, rect.into())
   }
 
   fn draw(&self, ctx: &mut Context) -> GameResult<()> {
@@ -48,7 +80,7 @@ pub trait Entity: Mask {
         size.w,  size.h
       ]
     };
-    return self.draw_rect(ctx, rect);
+    self.draw_rect(ctx, rect)
   }
 
   fn draw_offset(&self, ctx: &mut Context, offset: &Point) -> GameResult<()> {
@@ -60,6 +92,6 @@ pub trait Entity: Mask {
         size.w,  size.h
       ]
     };
-    return self.draw_rect(ctx, rect);
+    self.draw_rect(ctx, rect)
   }
 }
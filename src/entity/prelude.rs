@@ -1,7 +1,18 @@
 pub use super::{
   Entity,
   traits::{
-    velocity::Velocity,
-    movement::Movement
+    velocity::{
+      Velocity,
+      Integrator
+    },
+    movement::{
+      Movement,
+      MoveResult
+    },
+    collider::{
+      Collider,
+      ColliderKind
+    },
+    kinematic::Kinematic
   }
 };
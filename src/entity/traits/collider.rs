@@ -0,0 +1,136 @@
+use ::layers::Layers;
+use ::geo::{
+  NumType,
+  num::{
+    self,
+    Num
+  },
+  point::Point,
+  size::Size,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+
+/// How a `Collider` interacts with moving entities; see `Movement::move_among`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColliderKind<N = NumType> {
+  /// Blocks movement from every side.
+  Solid,
+  /// A jump-through platform; only blocks entities falling onto it from above,
+  /// unless they are dropping through.
+  OneWay,
+  /// A floor surface going from the `left` edge to the `right` edge of the collider.
+  /// The heights are fractions of the collider's height, measured from its bottom;
+  /// `{ left: 0.0, right: 1.0 }` on a square collider is a 45° slope going up to the right.
+  /// Slopes only block from above; back them with `Solid` colliders where entities may hit them from below.
+  /// With integer scalars the heights can only be `0` or `1`, so there are no shallow slopes.
+  Slope {
+    left:  N,
+    right: N
+  }
+}
+
+impl<N: Num> ColliderKind<N> {
+  /// A 45° slope going up to the right, for a square collider.
+  pub fn slope_up() -> Self {
+    ColliderKind::Slope { left: N::zero(), right: N::one() }
+  }
+
+  /// A 45° slope going down to the right, for a square collider.
+  pub fn slope_down() -> Self {
+    ColliderKind::Slope { left: N::one(), right: N::zero() }
+  }
+
+  /// The two halves of a shallow slope going up to the right,
+  /// spanning two square colliders next to each other.
+  pub fn shallow_slope_up() -> (Self, Self) {
+    let half = N::one().half();
+    (ColliderKind::Slope { left: N::zero(), right: half }, ColliderKind::Slope { left: half, right: N::one() })
+  }
+
+  /// The two halves of a shallow slope going down to the right,
+  /// spanning two square colliders next to each other.
+  pub fn shallow_slope_down() -> (Self, Self) {
+    let half = N::one().half();
+    (ColliderKind::Slope { left: N::one(), right: half }, ColliderKind::Slope { left: half, right: N::zero() })
+  }
+}
+
+/// An area of the level that entities using `Movement::move_among` collide with.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Collider<N = NumType> {
+  pub rect:   Rect<N>,
+  pub kind:   ColliderKind<N>,
+  #[cfg_attr(feature = "serde", serde(default))]
+  pub layers: Layers
+}
+
+impl<N: Num> Collider<N> {
+  pub fn new(rect: Rect<N>, kind: ColliderKind<N>) -> Self {
+    Self {
+      rect,
+      kind,
+      layers: Layers::default()
+    }
+  }
+
+  /// Returns this collider on the given collision layers.
+  pub fn with_layers(mut self, layers: Layers) -> Self {
+    self.layers = layers;
+    self
+  }
+
+  pub fn solid(rect: Rect<N>) -> Self {
+    Self::new(rect, ColliderKind::Solid)
+  }
+
+  pub fn one_way(rect: Rect<N>) -> Self {
+    Self::new(rect, ColliderKind::OneWay)
+  }
+
+  /// Returns the highest (smallest) Y position of this collider's walkable surface
+  /// between the X positions `left` and `right`, or `None` if it doesn't overlap them.
+  pub fn floor_in(&self, left: N, right: N) -> Option<N> {
+    let sides = self.rect.sides();
+    let from = num::max(left, sides.left);
+    let to   = num::min(right, sides.right);
+    if from >= to { return None; }
+    match self.kind {
+      ColliderKind::Solid | ColliderKind::OneWay => Some(sides.top),
+      ColliderKind::Slope { left: height_left, right: height_right } => {
+        let width  = sides.right - sides.left;
+        let height = sides.bottom - sides.top;
+        let surface_at = |x: N| {
+          sides.bottom - height * height_left - (height * (height_right - height_left)).mul_div(x - sides.left, width)
+        };
+        Some(num::min(surface_at(from), surface_at(to)))
+      }
+    }
+  }
+}
+
+impl<N: Num> Mask<N> for Collider<N> {
+  fn point(&self) -> &Point<N> {
+    self.rect.point()
+  }
+  fn point_mut(&mut self) -> &mut Point<N> {
+    self.rect.point_mut()
+  }
+  fn size(&self) -> &Size<N> {
+    self.rect.size()
+  }
+  fn size_mut(&mut self) -> &mut Size<N> {
+    self.rect.size_mut()
+  }
+  fn origin(&self) -> &Origin {
+    self.rect.origin()
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    self.rect.origin_mut()
+  }
+}
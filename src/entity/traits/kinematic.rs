@@ -0,0 +1,98 @@
+use ::geo::{
+  NumType,
+  point::Point,
+  rect::Rect,
+  mask::Mask
+};
+use super::{
+  super::Entity,
+  collider::{
+    Collider,
+    ColliderKind
+  },
+  movement::{
+    move_rect_among,
+    overlaps,
+    steps
+  }
+};
+
+/// Distances smaller than this are treated as touching.
+const EPSILON: NumType = 0.001;
+
+/// Returns how far the `actor` has to move to get out of the `solid`, which moved by `step`.
+fn push_out(solid: &Rect, actor: &Rect, step: &Point) -> Point {
+  let (solid, actor) = (solid.sides(), actor.sides());
+  if step.x > 0.0 {
+    Point::new(solid.right - actor.left, 0.0)
+  } else if step.x < 0.0 {
+    Point::new(solid.left - actor.right, 0.0)
+  } else if step.y > 0.0 {
+    Point::new(0.0, solid.bottom - actor.top)
+  } else {
+    Point::new(0.0, solid.top - actor.bottom)
+  }
+}
+
+/// A kinematic solid, like a moving platform, an elevator or a crushing block.
+/// It moves exactly as it is told without ever being blocked, carries the actors riding on it,
+/// and pushes the actors that are in its way.
+pub trait Kinematic: Entity {
+  /// How this solid collides with actors; a `OneWay` platform carries its riders,
+  /// but never pushes any actors.
+  fn collider_kind(&self) -> ColliderKind {
+    ColliderKind::Solid
+  }
+
+  /// Returns this solid as a `Collider`, to pass to `Movement::move_among` of the actors.
+  fn collider(&self) -> Collider {
+    let rect = Rect::new(self.point().clone(), self.size().clone(), self.origin().clone());
+    Collider::new(rect, self.collider_kind())
+  }
+
+  /// Returns `true` if the actor stands on this solid, touching its top `Side`.
+  fn is_riding<M: Mask + ?Sized>(&self, actor: &M) -> bool where Self: Sized {
+    let sides = actor.sides();
+    self.collider().floor_in(sides.left, sides.right)
+      .map_or(false, |floor| (floor - sides.bottom).abs() <= EPSILON )
+  }
+
+  /// Moves the solid by `delta`, horizontally first, then vertically, one pixel at a time,
+  /// so fast solids don't tunnel through actors.
+  /// Actors riding on the solid before the move are carried along, and if the solid is `Solid`,
+  /// other actors in its way are pushed out of it. The actors are moved among the `colliders` of the level,
+  /// which must not include this solid itself. If an actor is blocked by them while the solid overlaps it,
+  /// it is crushed: `on_crush` is called with its index in `actors`, and it is not moved any further.
+  fn move_kinematic<C: FnMut(usize)>(&mut self, delta: &Point, actors: &mut [&mut dyn Mask], colliders: &[Collider], mut on_crush: C) where Self: Sized {
+    let riders: Vec<bool> = actors.iter().map( |actor| self.is_riding(&**actor) ).collect();
+    let mut crushed = vec![false; actors.len()];
+    let x_steps = steps(delta.x).map( |amount| Point::new(amount, 0.0) );
+    let y_steps = steps(delta.y).map( |amount| Point::new(0.0, amount) );
+
+    for step in x_steps.chain(y_steps) {
+      self.point_mut().add(&step);
+      let solid = self.collider();
+      for (index, actor) in actors.iter_mut().enumerate() {
+        if crushed[index] { continue; }
+        let rect = Rect::new(actor.point().clone(), actor.size().clone(), actor.origin().clone());
+        let is_solid = solid.kind == ColliderKind::Solid;
+        // Riders are carried even if they sank into the solid a little, pushing them would throw them off
+        let movement = if riders[index] {
+          step.clone()
+        } else if is_solid && overlaps(&solid.rect, &rect) {
+          push_out(&solid.rect, &rect, &step)
+        } else {
+          continue;
+        };
+        let result = move_rect_among(&rect, &movement, colliders, false);
+        actor.point_mut().set(&result.position);
+        let moved = Rect::new(result.position.clone(), rect.size().clone(), rect.origin().clone());
+        let blocked = if step.x != 0.0 { result.blocked_x } else { result.blocked_y };
+        if blocked && is_solid && overlaps(&solid.rect, &moved) {
+          crushed[index] = true;
+          on_crush(index);
+        }
+      }
+    }
+  }
+}
@@ -1,2 +1,7 @@
 pub mod velocity;
 pub mod movement;
+pub mod collider;
+pub mod kinematic;
+
+#[cfg(test)]
+mod tests;
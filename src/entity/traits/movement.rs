@@ -1,13 +1,35 @@
+use ::layers::{
+  Layers,
+  LayerMatrix
+};
 use ::geo::{
   NumType,
+  num::{
+    self,
+    Num
+  },
   point::Point,
-  rect::Rect
+  rect::Rect,
+  mask::Mask
 };
 use super::{
   super::Entity,
-  velocity::Velocity
+  velocity::Velocity,
+  collider::{
+    Collider,
+    ColliderKind
+  }
 };
 
+/// The steepest slope that can be walked up, as rise per horizontal pixel (45°).
+const MAX_SLOPE: f32 = 1.0;
+
+/// Overlaps and distances smaller than this are treated as touching; integer scalars only touch exactly.
+const EPSILON: f32 = 0.001;
+
+/// Moves only skip stretches which stay at least this far away from every collider.
+const SKIP_MARGIN: f32 = 2.0;
+
 pub enum Polarity {
   Pos,
   Neg,
@@ -56,14 +78,333 @@ impl Axis {
     iterate(Axis::Y);
   }
 
-  fn point(&self, value: NumType) -> Point {
+  fn point<N: Num>(&self, value: N) -> Point<N> {
     match self {
-      Axis::X => Point::new(value, 0.0),
-      Axis::Y => Point::new(0.0, value)
+      Axis::X => Point::new(value, N::zero()),
+      Axis::Y => Point::new(N::zero(), value)
     }
   }
 }
 
+/// The outcome of `Movement::get_move_among`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveResult<N = NumType> {
+  /// The new position of the Entity.
+  pub position:  Point<N>,
+  /// If the Entity stands on a floor at the new position.
+  pub grounded:  bool,
+  /// If the horizontal movement was stopped by a collider.
+  pub blocked_x: bool,
+  /// If the vertical movement was stopped by a collider.
+  pub blocked_y: bool
+}
+
+/// The colliders a move collides with; optionally only those on layers that collide with the mover's layers.
+/// Filtering happens while iterating, so the colliders are never copied.
+struct Obstacles<'a, N: 'a> {
+  colliders: &'a [Collider<N>],
+  layers:    Option<(Layers, &'a LayerMatrix)>
+}
+
+// Derived `Clone` and `Copy` would require `N: Copy`
+impl<'a, N> Clone for Obstacles<'a, N> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<'a, N> Copy for Obstacles<'a, N> {}
+
+impl<'a, N: Num> Obstacles<'a, N> {
+  fn iter(self) -> impl Iterator<Item = &'a Collider<N>> {
+    self.colliders.iter().filter( move |collider| {
+      self.layers.map_or(true, |(layers, matrix)| matrix.collides(layers, collider.layers) )
+    })
+  }
+}
+
+/// Like `Mask::intersects`, but overlaps smaller than `EPSILON` don't count.
+pub(crate) fn overlaps<N: Num>(one: &Rect<N>, two: &Rect<N>) -> bool {
+  let epsilon = N::from_f32(EPSILON);
+  let (one, two) = (one.sides(), two.sides());
+  one.left < two.right  - epsilon && one.right  > two.left + epsilon &&
+    one.top < two.bottom - epsilon && one.bottom > two.top  + epsilon
+}
+
+/// Returns how far `rect` may move by `amount` along the `axis`, before it touches a `Solid` collider,
+/// or `None` if it isn't blocked.
+fn solid_contact<N: Num>(colliders: Obstacles<N>, rect: &Rect<N>, axis: &Axis, amount: N) -> Option<N> {
+  let moved = Rect::new(rect.point().clone() + axis.point(amount), rect.size().clone(), rect.origin().clone());
+  let sides = rect.sides();
+  colliders.iter()
+    .filter( |collider| collider.kind == ColliderKind::Solid && overlaps(&moved, &collider.rect) )
+    .map( |collider| {
+      let other = collider.rect.sides();
+      let distance = match (axis, amount > N::zero()) {
+        (Axis::X, true)  => other.left   - sides.right,
+        (Axis::X, false) => other.right  - sides.left,
+        (Axis::Y, true)  => other.top    - sides.bottom,
+        (Axis::Y, false) => other.bottom - sides.top
+      };
+      // Already overlapping colliders block without any movement
+      if distance * amount < N::zero() { N::zero() } else { distance }
+    })
+    .fold(None, |nearest: Option<N>, distance| match nearest {
+      Some(nearest) if nearest.abs() <= distance.abs() => Some(nearest),
+      _                                                => Some(distance)
+    })
+}
+
+/// Returns the nearest floor surface at most `distance` below the bottom of `rect`.
+fn floor_below<N: Num>(colliders: Obstacles<N>, rect: &Rect<N>, distance: N, drop_through: bool) -> Option<N> {
+  let epsilon = N::from_f32(EPSILON);
+  let sides = rect.sides();
+  colliders.iter()
+    .filter( |collider| !(drop_through && collider.kind == ColliderKind::OneWay) )
+    .filter_map( |collider| collider.floor_in(sides.left, sides.right) )
+    .filter( |floor| *floor >= sides.bottom - epsilon && *floor <= sides.bottom + distance )
+    .fold(None, |nearest: Option<N>, floor| Some(nearest.map_or(floor, |nearest| num::min(nearest, floor))))
+}
+
+/// Returns how far `rect` may fall by `amount` before it lands on a `OneWay` platform or a `Slope`.
+fn landing<N: Num>(colliders: Obstacles<N>, rect: &Rect<N>, amount: N, drop_through: bool) -> Option<N> {
+  let epsilon = N::from_f32(EPSILON);
+  let sides = rect.sides();
+  colliders.iter()
+    .filter( |collider| match collider.kind {
+      ColliderKind::Solid       => false,
+      ColliderKind::OneWay      => !drop_through,
+      ColliderKind::Slope { .. } => true
+    })
+    .filter_map( |collider| collider.floor_in(sides.left, sides.right) )
+    .filter( |floor| sides.bottom <= *floor + epsilon && sides.bottom + amount > *floor )
+    .map( |floor| num::max(floor - sides.bottom, N::zero()) )
+    .fold(None, |nearest: Option<N>, distance| Some(nearest.map_or(distance, |nearest| num::min(nearest, distance))))
+}
+
+/// Returns how far `rect` can move by `distance` along the `axis`, at most `distance.abs()`,
+/// while staying at least `SKIP_MARGIN` away from every collider.
+fn clear_distance<N: Num>(colliders: Obstacles<N>, rect: &Rect<N>, axis: &Axis, distance: N) -> N {
+  let margin = N::from_f32(SKIP_MARGIN);
+  let sides = rect.sides();
+  let (mut left, mut right, mut top, mut bottom) = (sides.left, sides.right, sides.top, sides.bottom);
+  match (axis, distance > N::zero()) {
+    (Axis::X, true)  => right  += distance,
+    (Axis::X, false) => left   += distance,
+    (Axis::Y, true)  => bottom += distance,
+    (Axis::Y, false) => top    += distance
+  }
+  let path = Rect::new_simple(
+    left - margin,
+    top  - margin,
+    right  - left + margin + margin,
+    bottom - top  + margin + margin
+  );
+  colliders.iter()
+    .filter( |collider| overlaps(&path, &collider.rect) )
+    .map( |collider| {
+      let other = collider.rect.sides();
+      let gap = match (axis, distance > N::zero()) {
+        (Axis::X, true)  => other.left  - sides.right,
+        (Axis::X, false) => sides.left  - other.right,
+        (Axis::Y, true)  => other.top   - sides.bottom,
+        (Axis::Y, false) => sides.top   - other.bottom
+      };
+      num::max(gap - margin, N::zero())
+    })
+    .fold(distance.abs(), num::min)
+}
+
+/// The steps of one pixel and the remainder a distance is moved in; see `steps`.
+pub(crate) struct Steps<N> {
+  step:  N,
+  whole: u64,
+  rem:   N
+}
+
+impl<N: Num> Iterator for Steps<N> {
+  type Item = N;
+
+  fn next(&mut self) -> Option<N> {
+    if self.whole > 0 {
+      self.whole -= 1;
+      Some(self.step)
+    } else if self.rem != N::zero() {
+      let rem = self.rem;
+      self.rem = N::zero();
+      Some(rem)
+    } else {
+      None
+    }
+  }
+}
+
+impl<N: Num> Steps<N> {
+  /// Returns the distance of the steps that are left.
+  fn remaining(&self) -> N {
+    self.step * N::from_f64(self.whole as f64) + self.rem
+  }
+
+  /// Skips up to `count` steps of one pixel, but not the remainder, and returns the distance skipped.
+  fn skip_whole(&mut self, count: u64) -> N {
+    let count = count.min(self.whole);
+    self.whole -= count;
+    self.step * N::from_f64(count as f64)
+  }
+}
+
+/// Splits a distance into steps of one pixel, followed by the remainder.
+/// The steps are yielded one at a time, so callers can stop early without going through a long distance.
+/// Infinite and NaN distances have no steps, so they don't move at all.
+pub(crate) fn steps<N: Num>(distance: N) -> Steps<N> {
+  if !distance.to_f64().is_finite() {
+    return Steps { step: N::zero(), whole: 0, rem: N::zero() };
+  }
+  let whole = distance.trunc();
+  Steps {
+    step:  distance.signum(),
+    whole: whole.abs().to_f64() as u64,
+    rem:   distance - whole
+  }
+}
+
+/// Moves the `rect` by `delta`, one pixel at a time along each axis, while `can_move_to` allows it,
+/// and returns its new position; see `Movement::move_while`.
+pub fn move_rect_while<N: Num, C: Fn(&Rect<N>) -> bool>(rect: &Rect<N>, delta: &Point<N>, can_move_to: C) -> Point<N> {
+  let mut position = rect.point().clone();
+  Axis::for_each( |axis| {
+    let vel = match axis {
+      Axis::X => delta.x,
+      Axis::Y => delta.y
+    };
+    // Move by one pixel at a time, then by the remainder
+    let mut steps = steps(vel);
+    while let Some(amount) = steps.next() {
+      let new_position = Point::combine(vec![ &position, &axis.point(amount) ]);
+      let new_rect = Rect::new(new_position.clone(), rect.size().clone(), rect.origin().clone());
+      if can_move_to(&new_rect) {
+        position = new_position;
+      } else {
+        // Blocked; still try to move by the remainder
+        steps.skip_whole(u64::MAX);
+      }
+    }
+  });
+  position
+}
+
+/// Moves the `rect` by `delta` among the colliders and returns its new position; see `Movement::get_move_among`.
+pub fn move_rect_among<N: Num>(rect: &Rect<N>, delta: &Point<N>, colliders: &[Collider<N>], drop_through: bool) -> MoveResult<N> {
+  move_rect(rect, delta, Obstacles { colliders, layers: None }, drop_through)
+}
+
+/// Same as `move_rect_among`, but only collides with the colliders on layers that collide with
+/// the passed `layers`, according to the `LayerMatrix`; see `Movement::get_move_among_layers`.
+pub fn move_rect_among_layers<N: Num>(rect: &Rect<N>, delta: &Point<N>, colliders: &[Collider<N>], drop_through: bool, layers: Layers, matrix: &LayerMatrix) -> MoveResult<N> {
+  move_rect(rect, delta, Obstacles { colliders, layers: Some((layers, matrix)) }, drop_through)
+}
+
+fn move_rect<N: Num>(rect: &Rect<N>, delta: &Point<N>, colliders: Obstacles<N>, drop_through: bool) -> MoveResult<N> {
+  let epsilon   = N::from_f32(EPSILON);
+  let max_slope = N::from_f32(MAX_SLOPE);
+  let rect_at = |position: &Point<N>| Rect::new(position.clone(), rect.size().clone(), rect.origin().clone());
+  let mut position  = rect.point().clone();
+  let mut blocked_x = false;
+  let mut blocked_y = false;
+
+  let mut steps_x = steps(delta.x);
+  loop {
+    // Skip stretches with no collider nearby at once, instead of one pixel at a time
+    let clear = clear_distance(colliders, &rect_at(&position), &Axis::X, steps_x.remaining());
+    position.x += steps_x.skip_whole(clear.to_f64() as u64);
+    let amount = match steps_x.next() {
+      Some(amount) => amount,
+      None         => break
+    };
+    let rect = rect_at(&position);
+    let was_grounded = floor_below(colliders, &rect, epsilon, drop_through).is_some();
+    let mut next = position.clone() + Point::new(amount, N::zero());
+    // Walk up slopes; slopes that are too steep block like walls
+    let next_rect = rect_at(&next);
+    let sides = next_rect.sides();
+    let mut climb = N::zero();
+    let mut too_steep = false;
+    for collider in colliders.iter() {
+      if let ColliderKind::Slope { .. } = collider.kind {
+        if !overlaps(&next_rect, &collider.rect) { continue; }
+        if let Some(floor) = collider.floor_in(sides.left, sides.right) {
+          let penetration = sides.bottom - floor;
+          if penetration <= epsilon { continue; }
+          if penetration <= amount.abs() * max_slope + epsilon {
+            climb = num::max(climb, penetration);
+          } else {
+            too_steep = true;
+          }
+        }
+      }
+    }
+    if too_steep {
+      blocked_x = true;
+      break;
+    }
+    next.y -= climb;
+    if climb > N::zero() {
+      if colliders.iter().any( |collider| collider.kind == ColliderKind::Solid && overlaps(&rect_at(&next), &collider.rect) ) {
+        blocked_x = true;
+        break;
+      }
+    } else if let Some(distance) = solid_contact(colliders, &rect, &Axis::X, amount) {
+      position.x += distance;
+      blocked_x = true;
+      break;
+    }
+    // Walk down slopes, instead of stepping off them into the air
+    if was_grounded && climb == N::zero() && delta.y >= N::zero() {
+      let next_rect = rect_at(&next);
+      if floor_below(colliders, &next_rect, epsilon, drop_through).is_none() {
+        if let Some(floor) = floor_below(colliders, &next_rect, amount.abs() * max_slope + epsilon, drop_through) {
+          next.y += floor - next_rect.sides().bottom;
+        }
+      }
+    }
+    position = next;
+  }
+
+  let mut steps_y = steps(delta.y);
+  loop {
+    let clear = clear_distance(colliders, &rect_at(&position), &Axis::Y, steps_y.remaining());
+    position.y += steps_y.skip_whole(clear.to_f64() as u64);
+    let amount = match steps_y.next() {
+      Some(amount) => amount,
+      None         => break
+    };
+    let rect = rect_at(&position);
+    let solid = solid_contact(colliders, &rect, &Axis::Y, amount);
+    let floor = if amount > N::zero() { landing(colliders, &rect, amount, drop_through) } else { None };
+    let stop = match (solid, floor) {
+      (Some(solid), Some(floor)) => Some(num::min(solid, floor)),
+      (solid, floor)             => solid.or(floor)
+    };
+    if let Some(distance) = stop {
+      position.y += distance;
+      blocked_y = true;
+      break;
+    }
+    position.y += amount;
+  }
+
+  let grounded = floor_below(colliders, &rect_at(&position), epsilon, drop_through).is_some();
+  MoveResult {
+    position,
+    grounded,
+    blocked_x,
+    blocked_y
+  }
+}
+
+/// Moves an Entity pixel by pixel with collision checking.
+/// Like `Entity` and `Velocity`, this trait uses `NumType` (`f32`) geometry; the `move_rect_*` functions
+/// it is built on are generic over `Num`, so they also move `i32` and `Fixed` geometry deterministically.
 pub trait Movement: Entity + Velocity {
   /// Moves one pixel in one direction.
   fn step(&mut self, step: Step) {
@@ -85,40 +426,60 @@ pub trait Movement: Entity + Velocity {
   /// which cannot be done because `self` has already been borrowed mutably by this method.
   /// For this situation there use the method `get_move_while`, which does not directly update the Entity's position,
   /// but rather it _returns the new position_ as a `Point`. Therefor it does not need a mutable reference to self.
+  /// Alternatively, store your entities in a `World` and use `World::with_mut_as`, which takes the
+  /// Entity out of the `World` while it is being moved, so the closure can look up all other entities.
   fn move_while<C: Fn(&Rect) -> bool>(&mut self, can_move_to: C) {
-    let position = &self.get_move_while(can_move_to);
+    let position = self.get_move_while(can_move_to);
+    self.point_mut().set(&position);
+  }
+
+  /// Same as `move_while`, but moves by the passed `delta` instead of the Entity's velocity.
+  fn move_by_while<C: Fn(&Rect) -> bool>(&mut self, delta: &Point, can_move_to: C) {
+    let position = self.get_move_by(delta, can_move_to);
     self.point_mut().set(&position);
   }
 
   /// This method almost does exactly the same as `move_while`, except it doesn't update the Entity's position,
   /// but it _returns the new position_ instead. This means it does not need to use a mutable reference to `self`.
   fn get_move_while<C: Fn(&Rect) -> bool>(&self, can_move_to: C) -> Point {
-    let mut position = self.point().clone();
-    Axis::for_each( |axis| {
-      let vel = match axis {
-        Axis::X => self.usable_velocity().x,
-        Axis::Y => self.usable_velocity().y
-      };
-      let abs  = vel.abs();
-      let sign = if vel as i32 != 0 {
-        vel.signum()
-      } else { 0.0 };
-      let rem  = vel % 1.0;
-      // Move by one absolute value at a time
-      for _i in 0_i32 ..= abs as i32 {
-        let new_position = Point::combine(vec![ &position, &axis.point(sign) ]);
-        let new_rect = Rect::new(new_position.clone(), self.size().clone(), self.origin().clone());
-        if can_move_to(&new_rect) {
-          position = new_position;
-        } else { break; }
-      }
-      // Move by the floating point remainder
-      let new_position = Point::combine(vec![ &position, &axis.point(rem) ]);
-      let new_rect = Rect::new(new_position.clone(), self.size().clone(), self.origin().clone());
-      if can_move_to(&new_rect) {
-        position = new_position;
-      }
-    });
-    return position;
+    self.get_move_by(&self.usable_velocity(), can_move_to)
+  }
+
+  /// Same as `get_move_while`, but moves by the passed `delta` instead of the Entity's velocity.
+  /// This is used when the velocity is not the distance to move per frame,
+  /// for example when it is in pixels per second and has to be multiplied with the deltatime first.
+  fn get_move_by<C: Fn(&Rect) -> bool>(&self, delta: &Point, can_move_to: C) -> Point {
+    move_rect_while(&Rect::new(self.point().clone(), self.size().clone(), self.origin().clone()), delta, can_move_to)
+  }
+
+  /// Moves the Entity by `delta` among the passed colliders, see `get_move_among`.
+  fn move_among(&mut self, delta: &Point, colliders: &[Collider], drop_through: bool) -> MoveResult {
+    let result = self.get_move_among(delta, colliders, drop_through);
+    self.point_mut().set(&result.position);
+    result
+  }
+
+  /// Like `get_move_by`, but collides with a list of `Collider`s instead of asking a closure:
+  /// `Solid` colliders block from every side, `OneWay` platforms only block when falling onto them
+  /// from above and while `drop_through` is `false`, and `Slope`s up to 45° are walked up and down
+  /// without leaving the ground. The Entity moves into exact contact with whatever blocks it.
+  /// Like `get_move_by`, it moves horizontally first, then vertically.
+  fn get_move_among(&self, delta: &Point, colliders: &[Collider], drop_through: bool) -> MoveResult {
+    move_rect_among(&Rect::new(self.point().clone(), self.size().clone(), self.origin().clone()), delta, colliders, drop_through)
+  }
+
+  /// Same as `move_among`, but only collides with the colliders on layers that collide with
+  /// the Entity's `layers`, according to the `LayerMatrix`.
+  fn move_among_layers(&mut self, delta: &Point, colliders: &[Collider], drop_through: bool, matrix: &LayerMatrix) -> MoveResult {
+    let result = self.get_move_among_layers(delta, colliders, drop_through, matrix);
+    self.point_mut().set(&result.position);
+    result
+  }
+
+  /// Same as `get_move_among`, but only collides with the colliders on layers that collide with
+  /// the Entity's `layers`, according to the `LayerMatrix`.
+  fn get_move_among_layers(&self, delta: &Point, colliders: &[Collider], drop_through: bool, matrix: &LayerMatrix) -> MoveResult {
+    let rect = Rect::new(self.point().clone(), self.size().clone(), self.origin().clone());
+    move_rect_among_layers(&rect, delta, colliders, drop_through, self.layers(), matrix)
   }
 }
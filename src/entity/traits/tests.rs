@@ -0,0 +1,425 @@
+use ::geo::prelude::*;
+use ::testing::{
+  Body,
+  deltatime,
+  assert_near
+};
+use ::layers::{
+  Layers,
+  LayerMatrix
+};
+use super::super::Entity;
+use super::velocity::{
+  Velocity,
+  Integrator
+};
+use super::movement::{
+  Movement,
+  move_rect_while,
+  move_rect_among
+};
+use super::collider::{
+  Collider,
+  ColliderKind
+};
+use super::kinematic::Kinematic;
+
+fn moving_body(x: NumType, y: NumType, vel_x: NumType, vel_y: NumType) -> Body {
+  Body::new(x, y).with_velocity(vel_x, vel_y).with_max_velocity(10.0, 10.0)
+}
+
+#[test]
+fn move_by_exact_velocity() {
+  let mut body = moving_body(0.0, 0.0, 2.5, -3.0);
+  body.move_while( |_| true );
+  assert_eq!(body.point(), &Point::new(2.5, -3.0));
+}
+
+#[test]
+fn move_by_small_velocity() {
+  let mut body = moving_body(0.0, 0.0, 0.5, -0.25);
+  body.move_while( |_| true );
+  assert_eq!(body.point(), &Point::new(0.5, -0.25));
+}
+
+#[test]
+fn stop_in_front_of_wall() {
+  let wall = Rect::new_simple(14.0, 0.0, 10.0, 10.0);
+  let mut body = moving_body(0.0, 0.0, 6.5, 0.0);
+  body.move_while( |rect| !rect.intersects(&wall) );
+  assert_eq!(body.point(), &Point::new(4.0, 0.0));
+}
+
+#[test]
+fn move_by_non_finite_delta() {
+  let mut body = moving_body(0.0, 0.0, 0.0, 0.0);
+  body.move_by_while(&Point::new(NumType::INFINITY, NumType::NEG_INFINITY), |_| true );
+  assert_eq!(body.point(), &Point::new(0.0, 0.0));
+  body.move_by_while(&Point::new(NumType::NAN, 2.5), |_| true );
+  assert_eq!(body.point(), &Point::new(0.0, 2.5));
+}
+
+#[test]
+fn move_by_remainder_after_wall() {
+  let wall = Rect::new_simple(14.0, 0.0, 10.0, 10.0);
+  let mut body = moving_body(0.0, 0.0, 0.0, 0.0);
+  body.move_by_while(&Point::new(6.5, 0.0), |rect| rect.sides().right <= wall.sides().left - 0.5 );
+  assert_eq!(body.point(), &Point::new(3.5, 0.0));
+}
+
+struct Heavy {
+  body: Body
+}
+
+impl Mask for Heavy {
+  fn point(&self) -> &Point {
+    self.body.point()
+  }
+  fn point_mut(&mut self) -> &mut Point {
+    self.body.point_mut()
+  }
+  fn size(&self) -> &Size {
+    self.body.size()
+  }
+  fn size_mut(&mut self) -> &mut Size {
+    self.body.size_mut()
+  }
+  fn origin(&self) -> &Origin {
+    self.body.origin()
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    self.body.origin_mut()
+  }
+}
+
+impl Entity for Heavy {}
+
+impl Velocity for Heavy {
+  fn velocity(&self) -> &Point {
+    self.body.velocity()
+  }
+  fn velocity_mut(&mut self) -> &mut Point {
+    self.body.velocity_mut()
+  }
+  fn max_velocity(&self) -> Point {
+    self.body.max_velocity()
+  }
+  fn mass(&self) -> NumType {
+    4.0
+  }
+  fn drag(&self) -> NumType {
+    2.0
+  }
+  fn gravity_scale(&self) -> NumType {
+    0.5
+  }
+}
+
+#[test]
+fn impulses_and_forces_are_scaled_by_mass() {
+  let mut heavy = Heavy { body: moving_body(0.0, 0.0, 0.0, 0.0) };
+  heavy.apply_impulse(&Point::new(40.0, -8.0));
+  assert_eq!(heavy.velocity(), &Point::new(10.0, -2.0));
+  heavy.apply_force(&Point::new(40.0, 0.0), &deltatime(500));
+  assert_eq!(heavy.velocity(), &Point::new(15.0, -2.0));
+}
+
+#[test]
+fn drag_does_not_depend_on_step_size() {
+  let mut one_step  = Heavy { body: moving_body(0.0, 0.0, 100.0, 0.0) };
+  let mut ten_steps = Heavy { body: moving_body(0.0, 0.0, 100.0, 0.0) };
+  one_step.apply_drag(&deltatime(500));
+  for _ in 0 .. 10 {
+    ten_steps.apply_drag(&deltatime(50));
+  }
+  assert_near(one_step.velocity().x, 100.0 * (-1.0 as NumType).exp());
+  assert_near(ten_steps.velocity().x, one_step.velocity().x);
+}
+
+#[test]
+fn gravity_is_scaled() {
+  let mut heavy = Heavy { body: moving_body(0.0, 0.0, 0.0, 0.0) };
+  heavy.apply_gravity(&Point::new(0.0, 100.0), &deltatime(100));
+  assert_near(heavy.velocity().y, 5.0);
+}
+
+#[test]
+fn integrate_free_fall() {
+  let gravity = Point::new(0.0, 10.0);
+  let dt = deltatime(100);
+  let mut euler  = moving_body(0.0, 0.0, 0.0, 0.0);
+  let mut verlet = moving_body(0.0, 0.0, 0.0, 0.0);
+  for _ in 0 .. 10 {
+    let delta = euler.integrate(&gravity, &dt, Integrator::SemiImplicitEuler);
+    euler.move_by_while(&delta, |_| true);
+    let delta = verlet.integrate(&gravity, &dt, Integrator::Verlet);
+    verlet.move_by_while(&delta, |_| true);
+  }
+  // After one second, the exact fall distance is `g / 2 = 5`;
+  // semi-implicit Euler overshoots by `g * dt / 2`.
+  assert_near(verlet.point().y, 5.0);
+  assert_near(euler.point().y, 5.5);
+  assert_near(verlet.velocity().y, 10.0);
+  assert_near(euler.velocity().y, 10.0);
+}
+
+fn bottom(body: &Body) -> NumType {
+  body.sides().bottom
+}
+
+#[test]
+fn walk_up_and_down_a_slope() {
+  // Ground at y 32, a 45° slope from x 16 to 32, and a raised floor at y 16 after it
+  let colliders = vec![
+    Collider::solid(Rect::new_simple(-64.0, 32.0, 192.0, 16.0)),
+    Collider::new(Rect::new_simple(16.0, 16.0, 16.0, 16.0), ColliderKind::slope_up()),
+    Collider::solid(Rect::new_simple(32.0, 16.0, 32.0, 16.0))
+  ];
+  let expected_floor = |x: NumType| {
+    let right = (x + 8.0).min(32.0);
+    if right > 16.0 { 32.0 - (right - 16.0) } else { 32.0 }
+  };
+  let mut body = Body::new(0.0, 24.0).with_size(8.0, 8.0);
+  for _ in 0 .. 40 {
+    let result = body.move_among(&Point::new(1.0, 1.0), &colliders, false);
+    assert!(result.grounded);
+    assert!(!result.blocked_x);
+    assert_eq!(bottom(&body), expected_floor(body.point().x));
+  }
+  assert_eq!(body.point(), &Point::new(40.0, 8.0));
+  for _ in 0 .. 40 {
+    let result = body.move_among(&Point::new(-1.0, 1.0), &colliders, false);
+    assert!(result.grounded);
+    assert_eq!(bottom(&body), expected_floor(body.point().x));
+  }
+  assert_eq!(body.point(), &Point::new(0.0, 24.0));
+}
+
+#[test]
+fn walk_over_shallow_slope_seam() {
+  let (lower, upper) = ColliderKind::shallow_slope_up();
+  let colliders = vec![
+    Collider::solid(Rect::new_simple(-64.0, 32.0, 192.0, 16.0)),
+    Collider::new(Rect::new_simple(16.0, 16.0, 16.0, 16.0), lower),
+    Collider::new(Rect::new_simple(32.0, 16.0, 16.0, 16.0), upper),
+    Collider::solid(Rect::new_simple(48.0, 16.0, 32.0, 16.0))
+  ];
+  let mut body = Body::new(0.0, 24.0).with_size(8.0, 8.0);
+  let mut last_bottom = bottom(&body);
+  for _ in 0 .. 48 {
+    let result = body.move_among(&Point::new(1.0, 1.0), &colliders, false);
+    assert!(result.grounded);
+    let rise = last_bottom - bottom(&body);
+    assert!((0.0 ..= 0.5 + 0.001).contains(&rise), "rose by {}", rise);
+    last_bottom = bottom(&body);
+  }
+  assert_eq!(bottom(&body), 16.0);
+}
+
+#[test]
+fn steep_slope_side_blocks() {
+  let colliders = vec![
+    Collider::solid(Rect::new_simple(-64.0, 32.0, 192.0, 16.0)),
+    Collider::new(Rect::new_simple(16.0, 16.0, 16.0, 16.0), ColliderKind::slope_down())
+  ];
+  let mut body = Body::new(4.0, 24.0).with_size(8.0, 8.0);
+  let result = body.move_among(&Point::new(10.0, 0.0), &colliders, false);
+  assert!(result.blocked_x);
+  assert_eq!(body.point(), &Point::new(8.0, 24.0));
+}
+
+#[test]
+fn huge_and_non_finite_deltas() {
+  let colliders = vec![Collider::solid(Rect::new_simple(32.0, 0.0, 16.0, 64.0))];
+  let mut body = Body::new(0.0, 24.0).with_size(8.0, 8.0);
+  let result = body.move_among(&Point::new(1.0e12, 0.0), &colliders, false);
+  assert!(result.blocked_x);
+  assert_eq!(body.point(), &Point::new(24.0, 24.0));
+  for delta in [NumType::INFINITY, NumType::NEG_INFINITY, NumType::NAN].iter() {
+    let result = body.move_among(&Point::new(-*delta, *delta), &colliders, false);
+    assert!(!result.blocked_x && !result.blocked_y);
+    assert_eq!(body.point(), &Point::new(24.0, 24.0));
+  }
+}
+
+#[test]
+fn huge_unblocked_delta() {
+  let colliders = vec![
+    Collider::solid(Rect::new_simple(-64.0, 32.0, 128.0, 16.0)),
+    Collider::solid(Rect::new_simple(-1.0e6, -1.0e6, 8.0, 8.0))
+  ];
+  let mut body = Body::new(0.0, 24.0).with_size(8.0, 8.0);
+  let result = body.move_among(&Point::new(1.0e12, 0.0), &colliders, false);
+  assert!(!result.blocked_x && !result.grounded);
+  assert_eq!(body.point(), &Point::new(1.0e12, 24.0));
+  let result = body.move_among(&Point::new(0.5, -1.0e12), &colliders, false);
+  assert!(!result.blocked_y);
+  assert_eq!(body.point(), &Point::new(1.0e12, -1.0e12));
+}
+
+#[test]
+fn move_integer_and_fixed_geometry() {
+  let colliders: Vec<Collider<i32>> = vec![
+    Collider::solid(Rect::new_simple(-64, 32, 192, 16)),
+    Collider::new(Rect::new_simple(16, 16, 16, 16), ColliderKind::slope_up()),
+    Collider::solid(Rect::new_simple(32, 16, 32, 16)),
+    Collider::solid(Rect::new_simple(64, 0, 16, 32))
+  ];
+  let result = move_rect_among(&Rect::new_simple(0, 24, 8, 8), &Point::new(100, 4), &colliders, false);
+  assert!(result.blocked_x && result.blocked_y && result.grounded);
+  assert_eq!(result.position, Point::new(56, 8));
+
+  let wall = Rect::new_simple(Fixed::from_int(14), Fixed::zero(), Fixed::from_int(10), Fixed::from_int(10));
+  let body = Rect::new_simple(Fixed::zero(), Fixed::zero(), Fixed::from_int(10), Fixed::from_int(10));
+  let position = move_rect_while(&body, &Point::new(Fixed::from_f32(6.5), Fixed::from_f32(-1.25)), |rect| !rect.intersects(&wall) );
+  assert_eq!(position, Point::new(Fixed::from_int(4), Fixed::from_f32(-1.25)));
+}
+
+#[test]
+fn one_way_platforms() {
+  let colliders = vec![
+    Collider::one_way(Rect::new_simple(0.0, 32.0, 32.0, 4.0))
+  ];
+  // Jump up through the platform from below
+  let mut body = Body::new(8.0, 40.0).with_size(8.0, 8.0);
+  let result = body.move_among(&Point::new(0.0, -20.0), &colliders, false);
+  assert!(!result.blocked_y);
+  assert_eq!(body.point().y, 20.0);
+  // Land on it from above
+  let result = body.move_among(&Point::new(0.0, 9.5), &colliders, false);
+  assert!(result.blocked_y);
+  assert!(result.grounded);
+  assert_eq!(bottom(&body), 32.0);
+  // Standing on it doesn't block walking
+  let result = body.move_among(&Point::new(3.0, 1.0), &colliders, false);
+  assert!(result.grounded);
+  assert_eq!(body.point(), &Point::new(11.0, 24.0));
+  // Drop through it
+  let result = body.move_among(&Point::new(0.0, 2.0), &colliders, true);
+  assert!(!result.grounded);
+  assert_eq!(bottom(&body), 34.0);
+  // Once inside it, it doesn't catch the body anymore
+  let result = body.move_among(&Point::new(0.0, 2.0), &colliders, false);
+  assert!(!result.blocked_y);
+  assert_eq!(bottom(&body), 36.0);
+}
+
+fn platform_at(x: NumType, y: NumType) -> Body {
+  let mut platform = moving_body(x, y, 0.0, 0.0);
+  platform.size = Size::new(32.0, 8.0);
+  platform
+}
+
+#[test]
+fn platform_carries_riders() {
+  let mut platform = platform_at(0.0, 32.0);
+  let mut rider    = Body::new(8.0, 24.0).with_size(8.0, 8.0);
+  let mut other    = Body::new(60.0, 24.0).with_size(8.0, 8.0);
+  for _ in 0 .. 4 {
+    let mut actors: Vec<&mut dyn Mask> = vec![&mut rider, &mut other];
+    platform.move_kinematic(&Point::new(2.5, -1.5), &mut actors, &[], |_| panic!("nothing is crushed"));
+  }
+  assert_eq!(platform.point(), &Point::new(10.0, 26.0));
+  assert_eq!(rider.point(), &Point::new(18.0, 18.0));
+  assert!(platform.is_riding(&rider));
+  assert_eq!(other.point(), &Point::new(60.0, 24.0));
+  // Moving down keeps the rider on it
+  let mut actors: Vec<&mut dyn Mask> = vec![&mut rider];
+  platform.move_kinematic(&Point::new(0.0, 6.0), &mut actors, &[], |_| ());
+  assert_eq!(rider.point(), &Point::new(18.0, 24.0));
+}
+
+#[test]
+fn platform_carries_riders_which_drifted_into_it() {
+  let mut platform = platform_at(0.0, 32.3);
+  let mut rider    = Body::new(8.0, 24.3).with_size(8.0, 8.0);
+  for _ in 0 .. 10 {
+    let mut actors: Vec<&mut dyn Mask> = vec![&mut rider];
+    platform.move_kinematic(&Point::new(0.1, 0.1), &mut actors, &[], |_| panic!("nothing is crushed"));
+  }
+  assert_near(platform.point().x, 1.0);
+  assert_near(rider.point().x, 9.0);
+  assert_near(rider.point().y, 25.3);
+  assert!(platform.is_riding(&rider));
+}
+
+#[test]
+fn platform_pushes_actors_without_tunnelling() {
+  let mut platform = platform_at(0.0, 20.0);
+  let mut actor    = Body::new(40.0, 20.0).with_size(8.0, 8.0);
+  let mut actors: Vec<&mut dyn Mask> = vec![&mut actor];
+  platform.move_kinematic(&Point::new(20.0, 0.0), &mut actors, &[], |_| panic!("nothing is crushed"));
+  assert_eq!(actor.point(), &Point::new(52.0, 20.0));
+}
+
+#[test]
+fn platform_crushes_actors_against_walls() {
+  let walls = vec![Collider::solid(Rect::new_simple(0.0, 0.0, 64.0, 8.0))];
+  let mut platform = platform_at(0.0, 40.0);
+  let mut standing = Body::new(4.0, 32.0).with_size(8.0, 8.0);
+  let mut beside   = Body::new(48.0, 32.0).with_size(8.0, 8.0);
+  let mut crushed = Vec::new();
+  {
+    let mut actors: Vec<&mut dyn Mask> = vec![&mut beside, &mut standing];
+    platform.move_kinematic(&Point::new(0.0, -30.0), &mut actors, &walls, |index| crushed.push(index));
+  }
+  assert_eq!(crushed, vec![1]);
+  assert_eq!(standing.point(), &Point::new(4.0, 8.0));
+  assert_eq!(beside.point(), &Point::new(48.0, 32.0));
+}
+
+#[test]
+fn one_way_platform_does_not_push() {
+  struct Ledge(Body);
+  impl Mask for Ledge {
+    fn point(&self) -> &Point { self.0.point() }
+    fn point_mut(&mut self) -> &mut Point { self.0.point_mut() }
+    fn size(&self) -> &Size { self.0.size() }
+    fn size_mut(&mut self) -> &mut Size { self.0.size_mut() }
+    fn origin(&self) -> &Origin { self.0.origin() }
+    fn origin_mut(&mut self) -> &mut Origin { self.0.origin_mut() }
+  }
+  impl Entity for Ledge {}
+  impl Kinematic for Ledge {
+    fn collider_kind(&self) -> ColliderKind { ColliderKind::OneWay }
+  }
+
+  let mut ledge = Ledge(platform_at(0.0, 40.0));
+  let mut below = Body::new(8.0, 30.0).with_size(8.0, 8.0);
+  let mut actors: Vec<&mut dyn Mask> = vec![&mut below];
+  ledge.move_kinematic(&Point::new(0.0, -8.0), &mut actors, &[], |_| ());
+  assert_eq!(below.point(), &Point::new(8.0, 30.0));
+}
+
+#[test]
+fn move_among_layers() {
+  struct Ghost(Body);
+  impl Mask for Ghost {
+    fn point(&self) -> &Point { self.0.point() }
+    fn point_mut(&mut self) -> &mut Point { self.0.point_mut() }
+    fn size(&self) -> &Size { self.0.size() }
+    fn size_mut(&mut self) -> &mut Size { self.0.size_mut() }
+    fn origin(&self) -> &Origin { self.0.origin() }
+    fn origin_mut(&mut self) -> &mut Origin { self.0.origin_mut() }
+  }
+  impl Entity for Ghost {
+    fn layers(&self) -> Layers { Layers::layer(2) }
+  }
+  impl Velocity for Ghost {
+    fn velocity(&self) -> &Point { self.0.velocity() }
+    fn velocity_mut(&mut self) -> &mut Point { self.0.velocity_mut() }
+    fn max_velocity(&self) -> Point { self.0.max_velocity() }
+  }
+  impl Movement for Ghost {}
+
+  let mut matrix = LayerMatrix::new();
+  matrix.set_collides(Layers::layer(2), Layers::DEFAULT, false);
+  let colliders = vec![
+    Collider::solid(Rect::new_simple(20.0, 0.0, 8.0, 8.0)),
+    Collider::solid(Rect::new_simple(40.0, 0.0, 8.0, 8.0)).with_layers(Layers::layer(2))
+  ];
+  let mut ghost = Ghost(Body::new(0.0, 0.0).with_size(8.0, 8.0));
+  let result = ghost.move_among_layers(&Point::new(50.0, 0.0), &colliders, false, &matrix);
+  assert!(result.blocked_x);
+  assert_eq!(ghost.point().x, 32.0);
+}
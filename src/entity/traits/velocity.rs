@@ -1,12 +1,41 @@
 use ::geo::point::Point;
 use ::geo::NumType;
+use ::deltatime::Deltatime;
 use super::super::Entity;
 
+/// The integration method used by `Velocity::integrate`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Integrator {
+  /// Updates the velocity first and then moves with the new velocity.
+  /// Stable and cheap; the default choice for games.
+  SemiImplicitEuler,
+  /// Velocity Verlet; moves with the average of the old and new velocity.
+  /// Exact for constant accelerations like gravity, so jump heights don't depend on the framerate.
+  Verlet
+}
+
 pub trait Velocity: Entity {
   fn velocity(&self)         -> &Point;
   fn velocity_mut(&mut self) -> &mut Point;
   fn max_velocity(&self)     -> Point;
 
+  /// The mass used by `apply_force` and `apply_impulse`.
+  /// A mass of `0` or less makes the Entity immovable by forces and impulses.
+  fn mass(&self) -> NumType {
+    1.0
+  }
+
+  /// The exponential drag rate per second used by `apply_drag`;
+  /// every second, the velocity is multiplied by `e^-drag`.
+  fn drag(&self) -> NumType {
+    0.0
+  }
+
+  /// Multiplier for the gravity passed to `apply_gravity` and `integrate`.
+  fn gravity_scale(&self) -> NumType {
+    1.0
+  }
+
   fn usable_velocity(&self) -> Point {
     self.velocity().clone()
   }
@@ -73,4 +102,58 @@ pub trait Velocity: Entity {
   fn clear_velocity(&mut self) {
     self.velocity_mut().set(&Point::new(0.0, 0.0));
   }
+
+  /// Applies a force over the deltatime, for example wind or a conveyor belt.
+  /// The velocity changes by `force / mass * secs`; it is not limited by `max_velocity`.
+  fn apply_force(&mut self, force: &Point, dt: &Deltatime) {
+    let mass = self.mass();
+    if mass <= 0.0 { return; }
+    let incr = force.mult_axes_by(dt.secs() / mass);
+    self.velocity_mut().add(&incr);
+  }
+
+  /// Applies an instantaneous impulse, for example an explosion or knockback.
+  /// The velocity changes by `impulse / mass`; it is not limited by `max_velocity`.
+  fn apply_impulse(&mut self, impulse: &Point) {
+    let mass = self.mass();
+    if mass <= 0.0 { return; }
+    let incr = impulse.mult_axes_by(1.0 / mass);
+    self.velocity_mut().add(&incr);
+  }
+
+  /// Applies gravity over the deltatime, scaled by `gravity_scale`; it does not depend on the mass.
+  fn apply_gravity(&mut self, gravity: &Point, dt: &Deltatime) {
+    let incr = gravity.mult_axes_by(self.gravity_scale() * dt.secs());
+    self.velocity_mut().add(&incr);
+  }
+
+  /// Slows the velocity down exponentially over the deltatime, using the `drag` rate.
+  /// Unlike `decrease_velocity`, the result does not depend on how often this is called.
+  fn apply_drag(&mut self, dt: &Deltatime) {
+    let factor = (-self.drag() * dt.secs()).exp();
+    let new_velocity = self.velocity().mult_axes_by(factor);
+    self.velocity_mut().set(&new_velocity);
+  }
+
+  /// Advances the velocity by the gravity (see `apply_gravity`) and drag over the deltatime,
+  /// and returns the distance to move in this step.
+  /// Pass the returned distance to `Movement::move_by_while` to move with collision checking.
+  /// Apply forces and impulses before calling this.
+  fn integrate(&mut self, gravity: &Point, dt: &Deltatime, integrator: Integrator) -> Point {
+    let secs = dt.secs();
+    let acceleration = gravity.mult_axes_by(self.gravity_scale());
+    match integrator {
+      Integrator::SemiImplicitEuler => {
+        self.apply_gravity(gravity, dt);
+        self.apply_drag(dt);
+        self.velocity().mult_axes_by(secs)
+      }
+      Integrator::Verlet => {
+        let delta = self.velocity().mult_axes_by(secs) + acceleration.mult_axes_by(0.5 * secs * secs);
+        self.apply_gravity(gravity, dt);
+        self.apply_drag(dt);
+        delta
+      }
+    }
+  }
 }
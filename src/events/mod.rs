@@ -0,0 +1,164 @@
+use std::any::{ Any, TypeId };
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+
+trait AnyChannel {
+  fn update(&mut self);
+  fn clear(&mut self);
+  fn append_pending(&mut self, other: &mut dyn AnyChannel);
+  fn as_any(&self)         -> &dyn Any;
+  fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+type Handler<T> = Box<dyn FnMut(&T)>;
+
+struct Channel<T> {
+  pending:  RefCell<Vec<T>>,
+  current:  Vec<T>,
+  handlers: Vec<Handler<T>>
+}
+
+impl<T> Channel<T> {
+  fn new() -> Self {
+    Self {
+      pending:  RefCell::new(Vec::new()),
+      current:  Vec::new(),
+      handlers: Vec::new()
+    }
+  }
+}
+
+impl<T: 'static> AnyChannel for Channel<T> {
+  fn update(&mut self) {
+    self.current = mem::take(self.pending.get_mut());
+    for handler in self.handlers.iter_mut() {
+      for event in self.current.iter() {
+        handler(event);
+      }
+    }
+  }
+
+  fn clear(&mut self) {
+    self.pending.get_mut().clear();
+    self.current.clear();
+  }
+
+  fn append_pending(&mut self, other: &mut dyn AnyChannel) {
+    let other = other.as_any_mut().downcast_mut::<Channel<T>>()
+      .expect("EventBus channel has the wrong type");
+    // The other channel's events were published first, so they stay in front.
+    let mut pending = mem::take(other.pending.get_mut());
+    pending.append(self.pending.get_mut());
+    *self.pending.get_mut() = pending;
+  }
+
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+  fn as_any_mut(&mut self) -> &mut dyn Any {
+    self
+  }
+}
+
+/// A typed event bus; any `'static` type can be used as an event.
+/// Events are double-buffered per frame, similar to the `InputManager`'s `keys_down`:
+/// events published during a frame are collected, and become readable when `update` is called
+/// at the end of the frame. They can then be read during the whole next frame,
+/// until the following `update` replaces them.
+/// Publishing only needs a shared reference, so events can be published from anywhere
+/// the bus can be reached, for example through `World::events` while an entity is updated.
+pub struct EventBus {
+  channels:     HashMap<TypeId, Box<dyn AnyChannel>>,
+  new_channels: RefCell<HashMap<TypeId, Box<dyn AnyChannel>>>
+}
+
+impl EventBus {
+  pub fn new() -> Self {
+    Self {
+      channels:     HashMap::new(),
+      new_channels: RefCell::new(HashMap::new())
+    }
+  }
+
+  fn channel<T: 'static>(&self) -> Option<&Channel<T>> {
+    self.channels.get(&TypeId::of::<T>())
+      .and_then( |channel| channel.as_any().downcast_ref::<Channel<T>>() )
+  }
+
+  fn channel_mut<T: 'static>(&mut self) -> &mut Channel<T> {
+    self.channels.entry(TypeId::of::<T>())
+      .or_insert_with( || Box::new(Channel::<T>::new()) )
+      .as_any_mut()
+      .downcast_mut::<Channel<T>>()
+      .expect("EventBus channel has the wrong type")
+  }
+
+  /// Publishes an event; it becomes readable after the next `update`.
+  pub fn publish<T: 'static>(&self, event: T) {
+    if let Some(channel) = self.channel::<T>() {
+      channel.pending.borrow_mut().push(event);
+      return;
+    }
+    let mut new_channels = self.new_channels.borrow_mut();
+    new_channels.entry(TypeId::of::<T>())
+      .or_insert_with( || Box::new(Channel::<T>::new()) )
+      .as_any()
+      .downcast_ref::<Channel<T>>()
+      .expect("EventBus channel has the wrong type")
+      .pending.borrow_mut().push(event);
+  }
+
+  /// Adds a handler which is called for every event of type `T`, when the events become readable in `update`.
+  pub fn subscribe<T: 'static, F: FnMut(&T) + 'static>(&mut self, handler: F) {
+    self.channel_mut::<T>().handlers.push(Box::new(handler));
+  }
+
+  /// Returns the events of type `T` published during the previous frame.
+  pub fn read<T: 'static>(&self) -> &[T] {
+    match self.channel::<T>() {
+      Some(channel) => &channel.current,
+      None          => &[]
+    }
+  }
+
+  /// Returns `true` if any events of type `T` were published during the previous frame.
+  pub fn has<T: 'static>(&self) -> bool {
+    !self.read::<T>().is_empty()
+  }
+
+  /// This method should be called once every frame, after all events of the frame were published.
+  /// It replaces the readable events with the events published since the last `update`,
+  /// and calls the subscribed handlers with them.
+  pub fn update(&mut self) {
+    let new_channels = mem::take(self.new_channels.get_mut());
+    for (type_id, mut new_channel) in new_channels {
+      // A handler may have been subscribed since the event type was first published,
+      // which already created the channel; keep it and its handlers and only move the events over.
+      match self.channels.get_mut(&type_id) {
+        Some(channel) => channel.append_pending(new_channel.as_mut()),
+        None          => { self.channels.insert(type_id, new_channel); }
+      }
+    }
+    for channel in self.channels.values_mut() {
+      channel.update();
+    }
+  }
+
+  /// Removes all readable and pending events; subscribed handlers are kept.
+  pub fn clear(&mut self) {
+    self.new_channels.get_mut().clear();
+    for channel in self.channels.values_mut() {
+      channel.clear();
+    }
+  }
+}
+
+impl Default for EventBus {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
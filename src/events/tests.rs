@@ -0,0 +1,78 @@
+use std::rc::Rc;
+use std::cell::Cell;
+use super::EventBus;
+
+#[derive(Debug, PartialEq)]
+struct CoinCollected {
+  value: u32
+}
+
+#[derive(Debug, PartialEq)]
+struct PlayerDied;
+
+#[test]
+fn events_are_readable_after_update() {
+  let mut bus = EventBus::new();
+  bus.publish(CoinCollected { value: 10 });
+  assert!(bus.read::<CoinCollected>().is_empty());
+  bus.update();
+  assert_eq!(bus.read::<CoinCollected>(), &[CoinCollected { value: 10 }]);
+  assert!(!bus.has::<PlayerDied>());
+}
+
+#[test]
+fn events_are_readable_for_one_frame() {
+  let mut bus = EventBus::new();
+  bus.publish(PlayerDied);
+  bus.update();
+  bus.publish(CoinCollected { value: 1 });
+  assert!(bus.has::<PlayerDied>());
+  bus.update();
+  assert!(!bus.has::<PlayerDied>());
+  assert!(bus.has::<CoinCollected>());
+  bus.update();
+  assert!(!bus.has::<CoinCollected>());
+}
+
+#[test]
+fn handlers_are_called_on_update() {
+  let mut bus = EventBus::new();
+  let total = Rc::new(Cell::new(0));
+  let handler_total = total.clone();
+  bus.subscribe( move |event: &CoinCollected| handler_total.set(handler_total.get() + event.value) );
+  bus.publish(CoinCollected { value: 5 });
+  bus.publish(CoinCollected { value: 7 });
+  assert_eq!(total.get(), 0);
+  bus.update();
+  assert_eq!(total.get(), 12);
+  bus.update();
+  assert_eq!(total.get(), 12);
+}
+
+#[test]
+fn handlers_subscribed_after_first_publish_are_kept() {
+  let mut bus = EventBus::new();
+  let total = Rc::new(Cell::new(0));
+  let handler_total = total.clone();
+  bus.publish(CoinCollected { value: 3 });
+  bus.subscribe( move |event: &CoinCollected| handler_total.set(handler_total.get() + event.value) );
+  bus.publish(CoinCollected { value: 2 });
+  bus.update();
+  assert_eq!(total.get(), 5);
+  assert_eq!(bus.read::<CoinCollected>(), &[CoinCollected { value: 3 }, CoinCollected { value: 2 }]);
+  bus.publish(CoinCollected { value: 4 });
+  bus.update();
+  assert_eq!(total.get(), 9);
+}
+
+#[test]
+fn clear_removes_events() {
+  let mut bus = EventBus::new();
+  bus.publish(PlayerDied);
+  bus.update();
+  bus.publish(PlayerDied);
+  bus.clear();
+  assert!(!bus.has::<PlayerDied>());
+  bus.update();
+  assert!(!bus.has::<PlayerDied>());
+}
@@ -1,6 +1,8 @@
 use super::super::NumType;
+use super::super::num::Num;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Origin {
   TopLeft,
   TopRight,
@@ -13,7 +15,8 @@ pub enum Origin {
   Center
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Side {
   Top,
   Bottom,
@@ -22,16 +25,18 @@ pub enum Side {
 }
 
 #[derive(PartialEq)]
-pub struct SideCollection {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SideCollection<N = NumType> {
+  #[cfg_attr(feature = "serde", serde(skip))]
   index:      usize,
-  pub top:    NumType,
-  pub bottom: NumType,
-  pub left:   NumType,
-  pub right:  NumType
+  pub top:    N,
+  pub bottom: N,
+  pub left:   N,
+  pub right:  N
 }
 
-impl SideCollection {
-  pub fn new(top: NumType, bottom: NumType, left: NumType, right: NumType) -> Self {
+impl<N: Num> SideCollection<N> {
+  pub fn new(top: N, bottom: N, left: N, right: N) -> Self {
     Self {
       index: 0,
       top,
@@ -51,10 +56,10 @@ impl SideCollection {
   }
 }
 
-impl Iterator for SideCollection {
-  type Item = (Side, NumType);
+impl<N: Num> Iterator for SideCollection<N> {
+  type Item = (Side, N);
 
-  fn next(&mut self) -> Option<(Side, NumType)> {
+  fn next(&mut self) -> Option<(Side, N)> {
     self.index += 1;
     match self.index {
       1 => Some((Side::Top,    self.top)),
@@ -66,14 +71,14 @@ impl Iterator for SideCollection {
   }
 }
 
-impl std::iter::FromIterator<NumType> for SideCollection {
-  fn from_iter<T: IntoIterator<Item=NumType>>(iter: T) -> Self {
+impl<N: Num> std::iter::FromIterator<N> for SideCollection<N> {
+  fn from_iter<T: IntoIterator<Item=N>>(iter: T) -> Self {
     let mut iter = iter.into_iter();
     Self::new(
-      iter.next().unwrap_or(0.0),
-      iter.next().unwrap_or(0.0),
-      iter.next().unwrap_or(0.0),
-      iter.next().unwrap_or(0.0),
+      iter.next().unwrap_or(N::zero()),
+      iter.next().unwrap_or(N::zero()),
+      iter.next().unwrap_or(N::zero()),
+      iter.next().unwrap_or(N::zero()),
     )
   }
 }
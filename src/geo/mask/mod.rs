@@ -1,6 +1,7 @@
 pub mod misc;
 
 use super::NumType;
+use super::num::Num;
 use super::{
   point::Point,
   size::Size
@@ -8,14 +9,49 @@ use super::{
 
 use self::misc::{ *, Side::* };
 
-pub trait Mask {
-  fn point(&self)         -> &Point;
-  fn point_mut(&mut self) -> &mut Point;
-  fn size(&self)          -> &Size;
-  fn origin(&self)        -> &Origin;
-
-  fn sides_intersect(sides_one: SideCollection, sides_two: SideCollection) -> bool {
-    return (
+pub trait Mask<N: Num = NumType> {
+  fn point(&self)          -> &Point<N>;
+  fn point_mut(&mut self)  -> &mut Point<N>;
+  fn size(&self)           -> &Size<N>;
+  fn size_mut(&mut self)   -> &mut Size<N>;
+  fn origin(&self)         -> &Origin;
+  fn origin_mut(&mut self) -> &mut Origin;
+
+  /// Set a new `Size`; the `point` is not touched, so the Mask grows or shrinks around its `origin`.
+  fn set_size(&mut self, size: Size<N>) {
+    *self.size_mut() = size;
+  }
+
+  /// Set a new `Origin`; the `point` is moved to the new origin's position,
+  /// so the Mask stays at the same place in world space.
+  fn set_origin(&mut self, origin: Origin) {
+    let point = self.origin_point(&origin);
+    *self.origin_mut() = origin;
+    self.point_mut().set(&point);
+  }
+
+  /// Set a new `Size`, while keeping the position of the passed `anchor` in place in world space.
+  /// For example, resizing with `Origin::BottomCenter` as the anchor will shrink or grow
+  /// the Mask from the top, while its bottom edge stays where it was.
+  /// # Example
+  ///   ```
+  ///   use noframe::geo::prelude::*;
+  ///
+  ///   let mut rect = Rect::new_simple(0.0, 0.0, 10.0, 20.0);
+  ///   rect.resize_from(Size::new(10.0, 10.0), &Origin::BottomCenter);
+  ///
+  ///   assert_eq!(Point::new(0.0, 10.0), rect.top_left());
+  ///   assert_eq!(Point::new(5.0, 20.0), rect.bottom_center());
+  ///   ```
+  fn resize_from(&mut self, size: Size<N>, anchor: &Origin) {
+    let anchor_before = self.origin_point(anchor);
+    self.set_size(size);
+    let anchor_after = self.origin_point(anchor);
+    self.point_mut().add(&(anchor_before - anchor_after));
+  }
+
+  fn sides_intersect(sides_one: SideCollection<N>, sides_two: SideCollection<N>) -> bool where Self: Sized {
+    (
       (
         sides_one.left >= sides_two.left &&
         sides_one.left <  sides_two.right
@@ -31,30 +67,30 @@ pub trait Mask {
         sides_one.top    <= sides_two.top &&
         sides_one.bottom >  sides_two.top
       )
-    );
+    )
   }
 
-  fn intersects<M: Mask>(&self, other: &M) -> bool {
+  fn intersects<M: Mask<N> + ?Sized>(&self, other: &M) -> bool where Self: Sized {
     self.is_same(other) || Self::sides_intersect(self.sides(), other.sides())
   }
 
-  fn intersects_round<M: Mask>(&self, other: &M) -> bool {
+  fn intersects_round<M: Mask<N> + ?Sized>(&self, other: &M) -> bool where Self: Sized {
     self.is_same(other) || Self::sides_intersect(self.sides().round(), other.sides().round())
   }
 
-  fn intersects_point(&self, point: &Point) -> bool {
+  fn intersects_point(&self, point: &Point<N>) -> bool {
     let sides = self.sides();
     point.x > sides.left && point.x < sides.right &&
       point.y > sides.top && point.y < sides.bottom
   }
 
-  fn is_same<M: Mask>(&self, other: &M) -> bool {
+  fn is_same<M: Mask<N> + ?Sized>(&self, other: &M) -> bool where Self: Sized {
     self.sides() == other.sides()
   }
 
-  fn top_left(&self) -> Point {
-    let point: &Point = self.point();
-    let size:  &Size  = self.size();
+  fn top_left(&self) -> Point<N> {
+    let point: &Point<N> = self.point();
+    let size:  &Size<N>  = self.size();
 
     match self.origin() {
       Origin::TopLeft => Point::new(
@@ -66,7 +102,7 @@ pub trait Mask {
         point.y
       ),
       Origin::TopCenter => Point::new(
-        point.x - size.w / 2.0,
+        point.x - size.w.half(),
         point.y
       ),
       Origin::BottomLeft => Point::new(
@@ -78,67 +114,84 @@ pub trait Mask {
         point.y - size.h
       ),
       Origin::BottomCenter => Point::new(
-        point.x - size.w / 2.0,
+        point.x - size.w.half(),
         point.y - size.h
       ),
       Origin::CenterLeft => Point::new(
         point.x,
-        point.y - size.h / 2.0
+        point.y - size.h.half()
       ),
       Origin::CenterRight => Point::new(
         point.x - size.w,
-        point.y - size.h / 2.0
+        point.y - size.h.half()
       ),
       Origin::Center => Point::new(
-        point.x - size.w / 2.0,
-        point.y - size.h / 2.0
+        point.x - size.w.half(),
+        point.y - size.h.half()
       )
     }
   }
 
-  fn top_right(&self) -> Point {
-    self.top_left() + Point::new(self.size().w, 0.0)
+  fn top_right(&self) -> Point<N> {
+    self.top_left() + Point::new(self.size().w, N::zero())
   }
 
-  fn top_center(&self) -> Point {
-    self.top_left() + Point::new(self.size().w * 0.5, 0.0)
+  fn top_center(&self) -> Point<N> {
+    self.top_left() + Point::new(self.size().w.half(), N::zero())
   }
 
-  fn bottom_left(&self) -> Point {
-    self.top_left() + Point::new(0.0, self.size().h)
+  fn bottom_left(&self) -> Point<N> {
+    self.top_left() + Point::new(N::zero(), self.size().h)
   }
 
-  fn bottom_right(&self) -> Point {
+  fn bottom_right(&self) -> Point<N> {
     self.top_left() + Point::new(self.size().w, self.size().h)
   }
 
-  fn bottom_center(&self) -> Point {
-    self.top_left() + Point::new(self.size().w * 0.5, self.size().h)
+  fn bottom_center(&self) -> Point<N> {
+    self.top_left() + Point::new(self.size().w.half(), self.size().h)
   }
 
-  fn center_left(&self) -> Point {
-    self.top_left() + Point::new(0.0, self.size().h * 0.5)
+  fn center_left(&self) -> Point<N> {
+    self.top_left() + Point::new(N::zero(), self.size().h.half())
   }
 
-  fn center_right(&self) -> Point {
-    self.top_left() + Point::new(self.size().w, self.size().h * 0.5)
+  fn center_right(&self) -> Point<N> {
+    self.top_left() + Point::new(self.size().w, self.size().h.half())
   }
 
-  fn center(&self) -> Point {
+  /// Returns the `point` moved by half the `size`; this is only the middle of Masks with `Origin::TopLeft`.
+  /// Use `origin_point(&Origin::Center)` for the middle of any Mask.
+  fn center(&self) -> Point<N> {
     Point::combine(vec![self.point(), &self.size().center()])
   }
 
-  fn side(&self, side: Side) -> NumType {
-    let top_left: Point = self.top_left();
-    return match side {
+  /// Returns the position of the passed `Origin` on this Mask, in world space.
+  fn origin_point(&self, origin: &Origin) -> Point<N> {
+    match origin {
+      Origin::TopLeft      => self.top_left(),
+      Origin::TopRight     => self.top_right(),
+      Origin::TopCenter    => self.top_center(),
+      Origin::BottomLeft   => self.bottom_left(),
+      Origin::BottomRight  => self.bottom_right(),
+      Origin::BottomCenter => self.bottom_center(),
+      Origin::CenterLeft   => self.center_left(),
+      Origin::CenterRight  => self.center_right(),
+      Origin::Center       => self.top_left() + self.size().center()
+    }
+  }
+
+  fn side(&self, side: Side) -> N {
+    let top_left: Point<N> = self.top_left();
+    match side {
       Top    => top_left.y,
       Bottom => top_left.y + self.size().h,
       Left   => top_left.x,
       Right  => top_left.x + self.size().w
-    };
+    }
   }
 
-  fn sides(&self) -> SideCollection {
+  fn sides(&self) -> SideCollection<N> {
     SideCollection::new(
       self.side(Top),
       self.side(Bottom),
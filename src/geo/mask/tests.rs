@@ -1,36 +1,9 @@
+use ::testing::Body;
 use super::super::NumType;
 use super::super::prelude::*;
 
-#[derive(Debug)]
-struct Body {
-  point:  Point,
-  size:   Size,
-  origin: Origin
-}
-
-impl Body {
-  pub fn new(x: NumType, y: NumType, w: NumType, h: NumType) -> Self {
-    Self {
-      point:  Point::new(x, y),
-      size:   Size::new(w, h),
-      origin: Origin::TopLeft
-    }
-  }
-}
-
-impl Mask for Body {
-  fn point(&self) -> &Point {
-    &self.point
-  }
-  fn point_mut(&mut self) -> &mut Point {
-    &mut self.point
-  }
-  fn size(&self) -> &Size {
-    &self.size
-  }
-  fn origin(&self) -> &Origin {
-    &self.origin
-  }
+fn sized_body(x: NumType, y: NumType, w: NumType, h: NumType) -> Body {
+  Body::new(x, y).with_size(w, h)
 }
 
 fn get_not_intersecting_bodies() -> (Body, Body) {
@@ -40,8 +13,8 @@ fn get_not_intersecting_bodies() -> (Body, Body) {
   //     |TWO|
   //     -----
   (
-    Body::new(10.0, 10.0, 10.0, 10.0),
-    Body::new(20.0, 20.0, 10.0, 10.0)
+    sized_body(10.0, 10.0, 10.0, 10.0),
+    sized_body(20.0, 20.0, 10.0, 10.0)
   )
 }
 
@@ -51,8 +24,8 @@ fn get_intersecting_bodies() -> (Body, Body) {
   // --|TWO|
   //   -----
   (
-    Body::new(10.0, 10.0, 10.0, 10.0),
-    Body::new(15.0, 15.0, 10.0, 10.0)
+    sized_body(10.0, 10.0, 10.0, 10.0),
+    sized_body(15.0, 15.0, 10.0, 10.0)
   )
 }
 
@@ -67,3 +40,44 @@ fn do_collide() {
   let bodies = get_intersecting_bodies();
   assert!(bodies.0.intersects(&bodies.1), "SHOULD collide:\n{:#?}\n", &bodies);
 }
+
+#[test]
+fn resize_keeps_anchor_in_place() {
+  let mut body = sized_body(10.0, 10.0, 10.0, 20.0);
+  body.resize_from(Size::new(10.0, 10.0), &Origin::BottomCenter);
+  assert_eq!(body.bottom_center(), Point::new(15.0, 30.0));
+  assert_eq!(body.top_left(),      Point::new(10.0, 20.0));
+  assert_eq!(body.size(),          &Size::new(10.0, 10.0));
+}
+
+#[test]
+fn resize_from_center() {
+  let mut body = sized_body(0.0, 0.0, 10.0, 10.0);
+  body.resize_from(Size::new(20.0, 30.0), &Origin::Center);
+  assert_eq!(body.center(),   Point::new(5.0, 5.0));
+  assert_eq!(body.top_left(), Point::new(-5.0, -10.0));
+}
+
+#[test]
+fn set_size_keeps_point() {
+  let mut body = sized_body(10.0, 10.0, 10.0, 10.0);
+  body.set_size(Size::new(5.0, 5.0));
+  assert_eq!(body.point(), &Point::new(10.0, 10.0));
+  assert_eq!(body.bottom_right(), Point::new(15.0, 15.0));
+}
+
+#[test]
+fn set_origin_keeps_mask_in_place() {
+  let mut body = sized_body(10.0, 10.0, 10.0, 20.0);
+  body.set_origin(Origin::BottomCenter);
+  assert_eq!(body.point(),    &Point::new(15.0, 30.0));
+  assert_eq!(body.top_left(), Point::new(10.0, 10.0));
+}
+
+#[test]
+fn set_origin_to_center_keeps_mask_in_place() {
+  let mut body = sized_body(10.0, 10.0, 10.0, 20.0);
+  body.set_origin(Origin::Center);
+  assert_eq!(body.point(),    &Point::new(15.0, 20.0));
+  assert_eq!(body.top_left(), Point::new(10.0, 10.0));
+}
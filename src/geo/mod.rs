@@ -1,5 +1,6 @@
 pub type NumType = f32;
 
+pub mod num;
 pub mod point;
 pub mod size;
 pub mod mask;
@@ -0,0 +1,209 @@
+use std::{ ops, fmt };
+
+use super::{ Num, isqrt };
+
+const FRAC_BITS: u32 = 16;
+const ONE:       i32 = 1 << FRAC_BITS;
+const FRAC_MASK: i32 = ONE - 1;
+
+/// Narrows a raw value to `i32`, saturating at the smallest and largest `Fixed`.
+fn saturate(raw: i64) -> i32 {
+  raw.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+}
+
+/// Divides raw values and saturates the quotient; dividing by zero saturates towards the sign of `numerator`,
+/// like `f32` division gives an infinity, and `0 / 0` is `0`.
+fn divide(numerator: i64, denominator: i64) -> i32 {
+  if denominator == 0 {
+    saturate(numerator.signum() * i64::MAX)
+  } else {
+    saturate(numerator / denominator)
+  }
+}
+
+/// Returns the raw value with another `FRAC_BITS` fractional bits, so dividing it by a raw value leaves a raw value.
+fn widen(raw: i32) -> i64 {
+  i64::from(raw) << FRAC_BITS
+}
+
+/// Returns the magnitude and sign of a raw value, widened so `i32::MIN` has a magnitude too.
+fn split_sign(raw: i32) -> (i64, i64) {
+  (i64::from(raw.unsigned_abs()), i64::from(raw.signum()))
+}
+
+/// A signed 16.16 fixed-point number.
+/// All arithmetic is done with integers, so results are identical on every platform.
+/// All arithmetic saturates at the smallest and largest values (about ±32768) instead of wrapping,
+/// in debug and release builds alike; dividing by zero saturates as well, and the remainder of a division by zero is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i32);
+
+impl Fixed {
+  /// Returns a new `Fixed` from its raw 16.16 representation.
+  pub fn from_raw(raw: i32) -> Self {
+    Fixed(raw)
+  }
+
+  /// Returns the raw 16.16 representation.
+  pub fn raw(&self) -> i32 {
+    self.0
+  }
+
+  /// Returns a new `Fixed` with the passed whole number value, saturating outside of ±32767.
+  /// # Example
+  ///   ```
+  ///   use noframe::geo::num::{ Num, Fixed };
+  ///
+  ///   let fixed = Fixed::from_int(3) / Fixed::from_int(2);
+  ///
+  ///   assert_eq!(1.5, fixed.to_f32());
+  ///   ```
+  pub fn from_int(value: i32) -> Self {
+    Fixed(saturate(i64::from(value) << FRAC_BITS))
+  }
+}
+
+impl Num for Fixed {
+  fn zero() -> Self { Fixed(0) }
+  fn one()  -> Self { Fixed(ONE) }
+
+  fn from_f32(value: f32) -> Self {
+    Fixed((value * ONE as f32).round() as i32)
+  }
+
+  fn to_f32(self) -> f32 {
+    self.0 as f32 / ONE as f32
+  }
+
+  fn from_f64(value: f64) -> Self {
+    Fixed((value * f64::from(ONE)).round() as i32)
+  }
+
+  fn to_f64(self) -> f64 {
+    f64::from(self.0) / f64::from(ONE)
+  }
+
+  fn abs(self) -> Self {
+    Fixed(self.0.saturating_abs())
+  }
+
+  fn signum(self) -> Self {
+    Fixed(self.0.signum() * ONE)
+  }
+
+  fn round(self) -> Self {
+    let (magnitude, sign) = split_sign(self.0);
+    let rounded = (magnitude + i64::from(ONE / 2)) & !i64::from(FRAC_MASK);
+    // Rounding up from the largest values saturates to the largest whole number
+    Fixed(saturate(rounded * sign) & !FRAC_MASK)
+  }
+
+  fn trunc(self) -> Self {
+    let (magnitude, sign) = split_sign(self.0);
+    Fixed(saturate((magnitude & !i64::from(FRAC_MASK)) * sign))
+  }
+
+  fn sqrt(self) -> Self {
+    Fixed(isqrt((::std::cmp::max(self.0, 0) as u64) << FRAC_BITS) as i32)
+  }
+
+  fn hypot(x: Self, y: Self) -> Self {
+    // The squares of the raw values have 32 fractional bits, so the root has 16 again
+    let (x, y) = (u64::from(x.0.unsigned_abs()), u64::from(y.0.unsigned_abs()));
+    Fixed(saturate(isqrt(x * x + y * y) as i64))
+  }
+
+  fn mul_div(self, mul: Self, div: Self) -> Self {
+    Fixed(divide(i64::from(self.0) * i64::from(mul.0), i64::from(div.0)))
+  }
+}
+
+impl ops::Add for Fixed {
+  type Output = Fixed;
+  fn add(self, other: Fixed) -> Fixed {
+    Fixed(self.0.saturating_add(other.0))
+  }
+}
+
+impl ops::AddAssign for Fixed {
+  fn add_assign(&mut self, other: Fixed) {
+    *self = *self + other;
+  }
+}
+
+impl ops::Sub for Fixed {
+  type Output = Fixed;
+  fn sub(self, other: Fixed) -> Fixed {
+    Fixed(self.0.saturating_sub(other.0))
+  }
+}
+
+impl ops::SubAssign for Fixed {
+  fn sub_assign(&mut self, other: Fixed) {
+    *self = *self - other;
+  }
+}
+
+impl ops::Mul for Fixed {
+  type Output = Fixed;
+  fn mul(self, other: Fixed) -> Fixed {
+    Fixed(saturate((i64::from(self.0) * i64::from(other.0)) >> FRAC_BITS))
+  }
+}
+
+impl ops::MulAssign for Fixed {
+  fn mul_assign(&mut self, other: Fixed) {
+    *self = *self * other;
+  }
+}
+
+impl ops::Div for Fixed {
+  type Output = Fixed;
+  fn div(self, other: Fixed) -> Fixed {
+    Fixed(divide(widen(self.0), i64::from(other.0)))
+  }
+}
+
+impl ops::DivAssign for Fixed {
+  fn div_assign(&mut self, other: Fixed) {
+    *self = *self / other;
+  }
+}
+
+impl ops::Rem for Fixed {
+  type Output = Fixed;
+  fn rem(self, other: Fixed) -> Fixed {
+    // Only fails for a zero divisor, and for `i32::MIN % -1`, whose remainder is zero anyway
+    Fixed(self.0.checked_rem(other.0).unwrap_or(0))
+  }
+}
+
+impl ops::Neg for Fixed {
+  type Output = Fixed;
+  fn neg(self) -> Fixed {
+    Fixed(self.0.saturating_neg())
+  }
+}
+
+impl fmt::Display for Fixed {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.0 as f64 / ONE as f64)
+  }
+}
+
+/// `Fixed` is serialized as a plain number, so data files stay readable.
+/// Every 16.16 value is exactly representable as an `f64`, so this round-trips without loss.
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for Fixed {
+  fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(self.0 as f64 / ONE as f64)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> ::serde::Deserialize<'de> for Fixed {
+  fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let value: f64 = ::serde::Deserialize::deserialize(deserializer)?;
+    Ok(Fixed::from_f64(value))
+  }
+}
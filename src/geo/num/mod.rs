@@ -0,0 +1,152 @@
+pub mod fixed;
+
+use std::{ ops, fmt };
+
+pub use self::fixed::Fixed;
+
+/// The scalar type used by the `geo` types (`Point`, `Size`, `Rect`, `Mask`, ...).
+/// It is implemented for `f32` (the default `NumType`), `f64`, `i32` and the fixed-point `Fixed` type.
+/// Integer and fixed-point scalars make geometry and `Mask` intersection checks deterministic across platforms,
+/// which is needed for lockstep netplay and replays.
+/// The step and collision maths of `Movement` are generic as well: `Collider`s and the `movement::move_rect_*`
+/// functions work with any `Num`. The `Entity`, `Velocity`, `Movement` and `Kinematic` traits, the `Camera`
+/// and the `World` still use `NumType` (`f32`); move entities with other scalars with the `move_rect_*` functions.
+pub trait Num:
+  Copy + PartialEq + PartialOrd + fmt::Debug + fmt::Display +
+  ops::Add<Output = Self> + ops::Sub<Output = Self> +
+  ops::Mul<Output = Self> + ops::Div<Output = Self> +
+  ops::Rem<Output = Self> + ops::Neg<Output = Self> +
+  ops::AddAssign + ops::SubAssign + ops::MulAssign + ops::DivAssign
+{
+  /// Returns `0`.
+  fn zero() -> Self;
+
+  /// Returns `1`.
+  fn one() -> Self;
+
+  /// Converts an `f32` to this type; integer types round to the nearest value.
+  fn from_f32(value: f32) -> Self;
+
+  /// Converts this value to an `f32`, for example for drawing with ggez.
+  fn to_f32(self) -> f32;
+
+  /// Converts an `f64` to this type; integer types round to the nearest value.
+  fn from_f64(value: f64) -> Self {
+    Self::from_f32(value as f32)
+  }
+
+  /// Converts this value to an `f64`; unlike `to_f32`, this is exact for all provided types.
+  fn to_f64(self) -> f64 {
+    f64::from(self.to_f32())
+  }
+
+  /// Returns the absolute value.
+  fn abs(self) -> Self;
+
+  /// Returns `1` for positive values, `-1` for negative values and `0` for zero.
+  /// __Note__ that this differs from `f32::signum`, which returns `1.0` for `0.0`.
+  fn signum(self) -> Self;
+
+  /// Rounds to the nearest whole number, rounding half-way cases away from zero.
+  fn round(self) -> Self;
+
+  /// Returns the whole number part, rounding towards zero.
+  fn trunc(self) -> Self;
+
+  /// Returns the square root; integer types round down.
+  /// Negative values return `0`.
+  /// The default goes through `f32`; the provided implementations are exact.
+  fn sqrt(self) -> Self {
+    let value = self.to_f32();
+    Self::from_f32(if value > 0.0 { value.sqrt() } else { 0.0 })
+  }
+
+  /// Returns the length of the vector `(x, y)`, the square root of `x * x + y * y`.
+  /// `Fixed` calculates this with more bits, so the squares don't overflow.
+  fn hypot(x: Self, y: Self) -> Self {
+    (x * x + y * y).sqrt()
+  }
+
+  /// Returns `self * mul / div`; `Fixed` keeps the intermediate product with more bits, so it doesn't overflow.
+  fn mul_div(self, mul: Self, div: Self) -> Self {
+    self * mul / div
+  }
+
+  /// Returns half of the value; integer types round towards zero.
+  fn half(self) -> Self {
+    self / (Self::one() + Self::one())
+  }
+}
+
+impl Num for f32 {
+  fn zero() -> Self { 0.0 }
+  fn one()  -> Self { 1.0 }
+  fn from_f32(value: f32) -> Self { value }
+  fn to_f32(self) -> f32 { self }
+  fn abs(self) -> Self { f32::abs(self) }
+  fn signum(self) -> Self {
+    if self == 0.0 { 0.0 } else { f32::signum(self) }
+  }
+  fn round(self) -> Self { f32::round(self) }
+  fn trunc(self) -> Self { f32::trunc(self) }
+  fn sqrt(self)  -> Self { f32::sqrt(self.max(0.0)) }
+}
+
+impl Num for f64 {
+  fn zero() -> Self { 0.0 }
+  fn one()  -> Self { 1.0 }
+  fn from_f32(value: f32) -> Self { value as f64 }
+  fn to_f32(self) -> f32 { self as f32 }
+  fn from_f64(value: f64) -> Self { value }
+  fn to_f64(self) -> f64 { self }
+  fn abs(self) -> Self { f64::abs(self) }
+  fn signum(self) -> Self {
+    if self == 0.0 { 0.0 } else { f64::signum(self) }
+  }
+  fn round(self) -> Self { f64::round(self) }
+  fn trunc(self) -> Self { f64::trunc(self) }
+  fn sqrt(self)  -> Self { f64::sqrt(self.max(0.0)) }
+}
+
+impl Num for i32 {
+  fn zero() -> Self { 0 }
+  fn one()  -> Self { 1 }
+  fn from_f32(value: f32) -> Self { value.round() as i32 }
+  fn to_f32(self) -> f32 { self as f32 }
+  fn from_f64(value: f64) -> Self { value.round() as i32 }
+  fn to_f64(self) -> f64 { f64::from(self) }
+  fn abs(self) -> Self { i32::abs(self) }
+  fn signum(self) -> Self { i32::signum(self) }
+  fn round(self) -> Self { self }
+  fn trunc(self) -> Self { self }
+  fn sqrt(self)  -> Self { isqrt(::std::cmp::max(self, 0) as u64) as i32 }
+}
+
+/// Returns the smaller of two values; works for floats, unlike `std::cmp::min`.
+pub fn min<N: PartialOrd>(one: N, two: N) -> N {
+  if two < one { two } else { one }
+}
+
+/// Returns the larger of two values; works for floats, unlike `std::cmp::max`.
+pub fn max<N: PartialOrd>(one: N, two: N) -> N {
+  if two > one { two } else { one }
+}
+
+/// Integer square root, rounded down.
+pub fn isqrt(value: u64) -> u64 {
+  if value < 2 {
+    return value;
+  }
+  // Newton's method, starting above the root so it converges downwards.
+  let mut root = value / 2 + 1;
+  loop {
+    let next = (root + value / root) / 2;
+    if next >= root {
+      return root;
+    }
+    root = next;
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+use super::{ Num, Fixed };
+use super::super::point::Point;
+use super::super::size::Size;
+use super::super::rect::Rect;
+use super::super::mask::{ Mask, misc::Origin };
+
+#[test]
+fn signum_of_zero_is_zero() {
+  assert_eq!(Num::signum(0.0_f32), 0.0);
+  assert_eq!(Num::signum(-3.0_f64), -1.0);
+  assert_eq!(Num::signum(0_i32), 0);
+  assert_eq!(Fixed::zero().signum(), Fixed::zero());
+}
+
+#[test]
+fn fixed_arithmetic() {
+  let a = Fixed::from_f32(2.5);
+  let b = Fixed::from_int(2);
+  assert_eq!((a + b).to_f32(), 4.5);
+  assert_eq!((a - b).to_f32(), 0.5);
+  assert_eq!((a * b).to_f32(), 5.0);
+  assert_eq!((a / b).to_f32(), 1.25);
+  assert_eq!((a % b).to_f32(), 0.5);
+  assert_eq!((-a).to_f32(), -2.5);
+}
+
+#[test]
+fn fixed_saturates_instead_of_wrapping() {
+  let big = Fixed::from_int(20000);
+  assert_eq!(big * big, Fixed::from_raw(i32::MAX));
+  assert_eq!(big * -big, Fixed::from_raw(i32::MIN));
+  assert_eq!(big / Fixed::from_f32(0.25), Fixed::from_raw(i32::MAX));
+  assert_eq!(big + big, Fixed::from_raw(i32::MAX));
+  assert_eq!(-big - big, Fixed::from_raw(i32::MIN));
+  let mut sum = big;
+  sum += big;
+  sum -= Fixed::from_int(1);
+  assert_eq!(sum, Fixed::from_raw(i32::MAX) - Fixed::from_int(1));
+  assert_eq!(Fixed::from_int(40000), Fixed::from_raw(i32::MAX));
+  assert_eq!(Fixed::from_int(-40000), Fixed::from_raw(i32::MIN));
+}
+
+#[test]
+fn fixed_lengths_at_world_coordinates() {
+  let point = Point::new(Fixed::from_int(3000), Fixed::from_int(-4000));
+  assert_eq!(point.length(), Fixed::from_int(5000));
+  assert_eq!(point.with_length(Fixed::from_int(500)), Point::new(Fixed::from_int(300), Fixed::from_int(-400)));
+  assert_eq!(point.limited(Fixed::from_int(10000)), point);
+  let extreme = Point::new(Fixed::from_raw(i32::MIN), Fixed::from_raw(i32::MIN));
+  assert_eq!(extreme.length(), Fixed::from_raw(i32::MAX));
+}
+
+#[test]
+fn fixed_rounding() {
+  assert_eq!(Fixed::from_f32( 2.5).round(), Fixed::from_int( 3));
+  assert_eq!(Fixed::from_f32(-2.5).round(), Fixed::from_int(-3));
+  assert_eq!(Fixed::from_f32( 2.4).round(), Fixed::from_int( 2));
+  assert_eq!(Fixed::from_f32(-2.7).trunc(), Fixed::from_int(-2));
+}
+
+#[test]
+fn fixed_at_the_bounds() {
+  let (min, max) = (Fixed::from_raw(i32::MIN), Fixed::from_raw(i32::MAX));
+  assert_eq!(min.abs(), max);
+  assert_eq!(-min, max);
+  assert_eq!(max.round(), Fixed::from_int(32767));
+  assert_eq!(min.round(), Fixed::from_int(-32768));
+  assert_eq!(max.trunc(), Fixed::from_int(32767));
+  assert_eq!(min.trunc(), Fixed::from_int(-32768));
+  assert_eq!((min + Fixed::from_raw(1)).abs(), max);
+}
+
+#[test]
+fn fixed_division_by_zero_saturates() {
+  let zero = Fixed::zero();
+  assert_eq!(Fixed::one() / zero, Fixed::from_raw(i32::MAX));
+  assert_eq!(-Fixed::one() / zero, Fixed::from_raw(i32::MIN));
+  assert_eq!(zero / zero, zero);
+  assert_eq!(Fixed::one().mul_div(Fixed::one(), zero), Fixed::from_raw(i32::MAX));
+  assert_eq!(Fixed::one() % zero, zero);
+  assert_eq!(Fixed::from_raw(i32::MIN) % Fixed::from_raw(-1), zero);
+}
+
+#[test]
+fn integer_points() {
+  let mut point: Point<i32> = Point::new(3, 4);
+  point.add(&Point::new(-5, 10));
+  assert_eq!(point.as_tup(), (-2, 14));
+  assert_eq!(point.inverted(), Point::new(2, -14));
+}
+
+#[test]
+fn integer_rect_mask() {
+  let rect: Rect<i32> = Rect::new(Point::new(10, 10), Size::new(4, 6), Origin::BottomCenter);
+  assert_eq!(rect.top_left(), Point::new(8, 4));
+  assert!(rect.intersects(&Rect::new_simple(11, 9, 2, 2)));
+  assert!(!rect.intersects(&Rect::new_simple(12, 4, 2, 2)));
+}
+
+#[test]
+fn fixed_rect_mask() {
+  let one = Fixed::one();
+  let rect: Rect<Fixed> = Rect::new(
+    Point::new(Fixed::from_int(10), Fixed::from_int(10)),
+    Size::new(Fixed::from_int(5), Fixed::from_int(5)),
+    Origin::Center
+  );
+  assert_eq!(rect.top_left(), Point::new(Fixed::from_f32(7.5), Fixed::from_f32(7.5)));
+  assert_eq!(rect.origin_point(&Origin::Center), Point::new(Fixed::from_int(10), Fixed::from_int(10)));
+  assert!(rect.intersects_point(&Point::new(Fixed::from_int(8), one * Fixed::from_int(12))));
+}
+
+#[test]
+fn square_roots() {
+  assert_eq!(Num::sqrt(16.0_f32), 4.0);
+  assert_eq!(Num::sqrt(17_i32), 4);
+  assert_eq!(Num::sqrt(-4_i32), 0);
+  assert_eq!(Fixed::from_int(9).sqrt(), Fixed::from_int(3));
+  assert_eq!(Fixed::from_f32(2.25).sqrt(), Fixed::from_f32(1.5));
+}
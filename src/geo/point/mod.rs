@@ -1,13 +1,15 @@
 use std::{ ops, fmt };
 use super::NumType;
+use super::num::Num;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct Point {
-  pub x: NumType,
-  pub y: NumType
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Point<N = NumType> {
+  pub x: N,
+  pub y: N
 }
 
-impl Point {
+impl<N: Num> Point<N> {
   /// Returns a new `Point` with the passed `x` and `y` values.
   /// # Example
   ///   ```
@@ -17,7 +19,7 @@ impl Point {
   ///
   ///   assert_eq!((32.0, 64.0), (point.x, point.y))
   ///   ```
-  pub fn new(x: NumType, y: NumType) -> Self {
+  pub fn new(x: N, y: N) -> Self {
     Self { x, y } }
 
   /// Returns a new `Point` with the accumulated `x` and `y` values
@@ -33,27 +35,27 @@ impl Point {
   ///
   ///   assert_eq!(Point::new(42.0, 10.0), Point::combine(points));
   ///   ```
-  pub fn combine(points: Vec<&Point>) -> Point {
-    let mut point_acc: Point = Point::new(0.0, 0.0);
+  pub fn combine(points: Vec<&Point<N>>) -> Point<N> {
+    let mut point_acc: Point<N> = Point::new(N::zero(), N::zero());
     for point in points {
       point_acc.add(point);
     }
-    return point_acc;
+    point_acc
   }
 
   /// Set the `x` and `y` values to the values of the passed `Point` reference.
-  pub fn set(&mut self, point: &Point) {
+  pub fn set(&mut self, point: &Point<N>) {
     self.x = point.x;
     self.y = point.y;
   }
 
   /// Set the `x` value.
-  pub fn set_x(&mut self, value: NumType) {
+  pub fn set_x(&mut self, value: N) {
     self.x = value;
   }
 
   /// Set the `y` value.
-  pub fn set_y(&mut self, value: NumType) {
+  pub fn set_y(&mut self, value: N) {
     self.y = value;
   }
 
@@ -68,7 +70,7 @@ impl Point {
   ///
   ///   assert_eq!(Point::new(15.0, 10.0), point);
   ///   ```
-  pub fn add(&mut self, point: &Point) {
+  pub fn add(&mut self, point: &Point<N>) {
     self.x += point.x;
     self.y += point.y;
   }
@@ -82,7 +84,7 @@ impl Point {
   ///
   ///   assert_eq!((10.0, 20.0), point.as_tup());
   ///   ```
-  pub fn as_tup(&self) -> (NumType, NumType) {
+  pub fn as_tup(&self) -> (N, N) {
     (self.x, self.y)
   }
 
@@ -95,8 +97,8 @@ impl Point {
   ///
   ///   assert_eq!(Point::new(-10.0, -20.0), point.inverted());
   ///   ```
-  pub fn inverted(&self) -> Point {
-    Point::new(self.x * -1.0, self.y * -1.0)
+  pub fn inverted(&self) -> Point<N> {
+    Point::new(-self.x, -self.y)
   }
 
   /// Multiplies the `x` and `y` values by `-1`.
@@ -110,55 +112,97 @@ impl Point {
   ///   assert_eq!(Point::new(-10.0, -20.0), point);
   ///   ```
   pub fn invert(&mut self) {
-    self.x *= -1.0;
-    self.y *= -1.0;
+    self.x = -self.x;
+    self.y = -self.y;
   }
 
-  pub fn mult_axes_by(&self, mult: NumType) -> Point {
+  pub fn mult_axes_by(&self, mult: N) -> Point<N> {
     Point::new(
       self.x * mult,
       self.y * mult
     )
   }
 
+  /// Returns the length of the vector from `(0, 0)` to this `Point`.
+  /// # Example
+  ///   ```
+  ///   use noframe::geo::point::Point;
+  ///
+  ///   assert_eq!(5.0, Point::new(3.0, -4.0).length());
+  ///   ```
+  pub fn length(&self) -> N {
+    N::hypot(self.x, self.y)
+  }
+
+  /// Returns a new `Point` in the same direction, with a length of `1`.
+  /// A `Point` with a length of `0` stays at `(0, 0)`.
+  pub fn normalized(&self) -> Point<N> {
+    self.with_length(N::one())
+  }
+
+  /// Returns a new `Point` in the same direction, with the passed length.
+  pub fn with_length(&self, length: N) -> Point<N> {
+    let current = self.length();
+    if current == N::zero() {
+      return self.clone();
+    }
+    Point::new(self.x.mul_div(length, current), self.y.mul_div(length, current))
+  }
+
+  /// Returns a new `Point` in the same direction, which is at most `max` long.
+  /// # Example
+  ///   ```
+  ///   use noframe::geo::point::Point;
+  ///
+  ///   assert_eq!(Point::new(3.0, 4.0),  Point::new(6.0, 8.0).limited(5.0));
+  ///   assert_eq!(Point::new(1.0, 1.0),  Point::new(1.0, 1.0).limited(5.0));
+  ///   ```
+  pub fn limited(&self, max: N) -> Point<N> {
+    if self.length() > max {
+      self.with_length(max)
+    } else {
+      self.clone()
+    }
+  }
+
   /// Round the Point's `x` and `y` values.
   pub fn round(&mut self) {
-    self.x = self.x.round();
-    self.y = self.y.round();
+    self.x = Num::round(self.x);
+    self.y = Num::round(self.y);
   }
 }
 
-impl ops::Add for Point {
-  type Output = Point;
-  fn add(self, other: Point) -> Point {
+impl<N: Num> ops::Add for Point<N> {
+  type Output = Point<N>;
+  fn add(self, other: Point<N>) -> Point<N> {
     Point::combine(vec![&self, &other])
   }
 }
 
-impl ops::AddAssign for Point {
-  fn add_assign(&mut self, other: Point) {
+impl<N: Num> ops::AddAssign for Point<N> {
+  fn add_assign(&mut self, other: Point<N>) {
     self.x += other.x;
     self.y += other.y;
   }
 }
 
-impl ops::Sub for Point {
-  type Output = Point;
-  fn sub(self, other: Point) -> Point {
+impl<N: Num> ops::Sub for Point<N> {
+  type Output = Point<N>;
+  fn sub(self, other: Point<N>) -> Point<N> {
     Point::combine(vec![&self, &other.inverted()])
   }
 }
 
-impl ops::SubAssign for Point {
-  fn sub_assign(&mut self, other: Point) {
+impl<N: Num> ops::SubAssign for Point<N> {
+  fn sub_assign(&mut self, other: Point<N>) {
     self.x -= other.x;
     self.y -= other.y;
   }
 }
 
-impl ops::Mul for Point {
-  type Output = Point;
-  fn mul(self, other: Point) -> Point {
+impl<N: Num> ops::Mul for Point<N> {
+  type Output = Point<N>;
+  fn mul(self, other: Point<N>) -> Point<N> {
     Point::new(
       self.x * other.x,
       self.y * other.y
@@ -166,16 +210,16 @@ impl ops::Mul for Point {
   }
 }
 
-impl ops::MulAssign for Point {
-  fn mul_assign(&mut self, other: Point) {
+impl<N: Num> ops::MulAssign for Point<N> {
+  fn mul_assign(&mut self, other: Point<N>) {
     self.x *= other.x;
     self.y *= other.y;
   }
 }
 
-impl ops::Div for Point {
-  type Output = Point;
-  fn div(self, other: Point) -> Point {
+impl<N: Num> ops::Div for Point<N> {
+  type Output = Point<N>;
+  fn div(self, other: Point<N>) -> Point<N> {
     Point::new(
       self.x / other.x,
       self.y / other.y
@@ -183,14 +227,14 @@ impl ops::Div for Point {
   }
 }
 
-impl ops::DivAssign for Point {
-  fn div_assign(&mut self, other: Point) {
+impl<N: Num> ops::DivAssign for Point<N> {
+  fn div_assign(&mut self, other: Point<N>) {
     self.x /= other.x;
     self.y /= other.y;
   }
 }
 
-impl fmt::Display for Point {
+impl<N: Num> fmt::Display for Point<N> {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(f, "x: {}, y: {}", self.x, self.y)
   }
@@ -198,8 +242,8 @@ impl fmt::Display for Point {
 
 /// Implement the `From` trait for ggez's `Point2` struct,
 /// so that ggez's `Point2` struct can easily be created from our `Point` struct.
-impl<'a> From<&'a Point> for ::ggez::graphics::Point2 {
-  fn from(point: &Point) -> Self {
+impl From<&Point<NumType>> for ::ggez::graphics::Point2 {
+  fn from(point: &Point<NumType>) -> Self {
     Self::new(point.x, point.y)
   }
 }
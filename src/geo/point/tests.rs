@@ -18,14 +18,14 @@ fn add_position() {
 
 #[test]
 fn combine_multiple_points() {
-  let points = vec![
+  let points = [
     Point::new( 5.0,   10.0),
     Point::new( 15.0,  7.0),
     Point::new( 42.0,  24.0),
     Point::new(-21.0, -22.0)
   ];
   let point = Point::combine(
-    points.iter().map( |p| p ).collect()  // Create a `Vec<&Point>` from a `Vec<Point>`
+    points.iter().collect()  // Create a `Vec<&Point>` from an array of `Point`s
   );
   assert_eq!(point.as_tup(), (41.0, 19.0));
 }
@@ -44,3 +44,10 @@ fn create_ggez_point2_from_point() {
   let point2 = ::ggez::graphics::Point2::from(&point);
   assert_eq!(point.as_tup(), (point2.x, point2.y));
 }
+
+#[test]
+fn normalize_point() {
+  let point: Point = Point::new(10.0, 10.0).normalized();
+  assert!((point.length() - 1.0).abs() < 0.0001);
+  assert_eq!(Point::new(0.0, 0.0).normalized(), Point::new(0.0, 0.0));
+}
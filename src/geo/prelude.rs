@@ -1,5 +1,9 @@
 pub use super::{
   NumType,
+  num::{
+    Num,
+    Fixed
+  },
   point::Point,
   size::Size,
   mask::{
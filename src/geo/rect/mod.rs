@@ -1,5 +1,6 @@
 use super::{
   NumType,
+  num::Num,
   point::Point,
   size::Size,
   mask::{
@@ -8,15 +9,16 @@ use super::{
   }
 };
 
-#[derive(Debug)]
-pub struct Rect {
-  point:  Point,
-  size:   Size,
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rect<N = NumType> {
+  point:  Point<N>,
+  size:   Size<N>,
   origin: Origin
 }
 
-impl Rect {
-  pub fn new(point: Point, size: Size, origin: Origin) -> Self {
+impl<N: Num> Rect<N> {
+  pub fn new(point: Point<N>, size: Size<N>, origin: Origin) -> Self {
     Self {
       point,
       size,
@@ -24,7 +26,7 @@ impl Rect {
     }
   }
 
-  pub fn new_simple(x: NumType, y: NumType, w: NumType, h: NumType) -> Self {
+  pub fn new_simple(x: N, y: N, w: N, h: N) -> Self {
     Self {
       point:  Point::new(x, y),
       size:   Size::new(w, h),
@@ -33,17 +35,26 @@ impl Rect {
   }
 }
 
-impl Mask for Rect {
-  fn point(&self) -> &Point {
+impl<N: Num> Mask<N> for Rect<N> {
+  fn point(&self) -> &Point<N> {
     &self.point
   }
-  fn point_mut(&mut self) -> &mut Point {
+  fn point_mut(&mut self) -> &mut Point<N> {
     &mut self.point
   }
-  fn size(&self) -> &Size {
+  fn size(&self) -> &Size<N> {
     &self.size
   }
+  fn size_mut(&mut self) -> &mut Size<N> {
+    &mut self.size
+  }
   fn origin(&self) -> &Origin {
     &self.origin
   }
+  fn origin_mut(&mut self) -> &mut Origin {
+    &mut self.origin
+  }
 }
+
+#[cfg(test)]
+mod tests;
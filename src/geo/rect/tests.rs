@@ -0,0 +1,27 @@
+use super::super::prelude::*;
+
+#[test]
+fn new_simple_has_top_left_origin() {
+  let rect = Rect::new_simple(10.0, 20.0, 30.0, 40.0);
+  assert_eq!(rect.origin(), &Origin::TopLeft);
+  assert_eq!(rect.bottom_right(), Point::new(40.0, 60.0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+  let rect = Rect::new(Point::new(10.0, 20.0), Size::new(8.0, 16.0), Origin::BottomCenter);
+  let json = ::serde_json::to_string(&rect).unwrap();
+  assert_eq!(json, r#"{"point":{"x":10.0,"y":20.0},"size":{"w":8.0,"h":16.0},"origin":"BottomCenter"}"#);
+  assert_eq!(::serde_json::from_str::<Rect>(&json).unwrap(), rect);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip_fixed() {
+  let rect: Rect<Fixed> = Rect::new_simple(
+    Fixed::from_f32(1.5), Fixed::from_int(-2), Fixed::from_raw(1), Fixed::from_int(4)
+  );
+  let json = ::serde_json::to_string(&rect).unwrap();
+  assert_eq!(::serde_json::from_str::<Rect<Fixed>>(&json).unwrap(), rect);
+}
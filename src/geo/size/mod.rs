@@ -1,30 +1,32 @@
 use super::NumType;
+use super::num::Num;
 use super::point::Point;
 
-#[derive(Debug, Clone)]
-pub struct Size {
-  pub w: NumType,
-  pub h: NumType
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Size<N = NumType> {
+  pub w: N,
+  pub h: N
 }
 
-impl Size {
-  pub fn new(w: NumType, h: NumType) -> Self {
+impl<N: Num> Size<N> {
+  pub fn new(w: N, h: N) -> Self {
     Self { w, h }
   }
 
-  pub fn center(&self) -> Point {
-    Point::new(self.w / 2.0, self.h / 2.0)
+  pub fn center(&self) -> Point<N> {
+    Point::new(self.w.half(), self.h.half())
   }
 }
 
-impl From<[NumType; 2]> for Size {
-  fn from(arr: [NumType; 2]) -> Self {
+impl<N: Num> From<[N; 2]> for Size<N> {
+  fn from(arr: [N; 2]) -> Self {
     Self::new(arr[0], arr[1])
   }
 }
 
-impl From<(NumType, NumType)> for Size {
-  fn from(tup: (NumType, NumType)) -> Self {
+impl<N: Num> From<(N, N)> for Size<N> {
+  fn from(tup: (N, N)) -> Self {
     Self::new(tup.0, tup.1)
   }
 }
@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use ::geo::{
+  NumType,
+  point::Point,
+  rect::Rect,
+  mask::Mask
+};
+use ::world::EntityId;
+
+/// The direction an entity is facing. Boxes are defined facing right, and mirrored when facing left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Facing {
+  Left,
+  #[default]
+  Right
+}
+
+impl Facing {
+  pub fn sign(&self) -> NumType {
+    match self {
+      Facing::Left  => -1.0,
+      Facing::Right =>  1.0
+    }
+  }
+}
+
+/// A box that deals damage to the hurtboxes it overlaps.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hitbox {
+  /// The area of the box, relative to the owner's origin point, facing right.
+  pub rect:      Rect,
+  pub damage:    u32,
+  /// The knockback velocity applied to the target, facing right.
+  pub knockback: Point,
+  /// For how many frames the target is stunned.
+  pub hitstun:   u32
+}
+
+/// The hitboxes and hurtboxes of an entity for one animation frame.
+/// Hitboxes are checked in order, so when several hit the same target, the first one counts.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HitboxSet {
+  pub hitboxes:  Vec<Hitbox>,
+  /// The areas where the entity can be hit, relative to its origin point, facing right.
+  pub hurtboxes: Vec<Rect>
+}
+
+/// Returns the world space `Rect` of a box relative to the `position`, mirrored if facing left.
+pub fn place(rect: &Rect, position: &Point, facing: Facing) -> Rect {
+  let sides = rect.sides();
+  let left = match facing {
+    Facing::Left  => -sides.right,
+    Facing::Right =>  sides.left
+  };
+  Rect::new_simple(position.x + left, position.y + sides.top, rect.size().w, rect.size().h)
+}
+
+/// An entity taking part in a hit check.
+#[derive(Debug, Clone)]
+pub struct Combatant<'a, K = EntityId> {
+  pub key:      K,
+  /// The owner's origin point, which the boxes are relative to.
+  pub position: Point,
+  pub facing:   Facing,
+  pub boxes:    &'a HitboxSet
+}
+
+impl<'a, K> Combatant<'a, K> {
+  /// Returns a `Combatant` with its boxes relative to the origin point of the `owner`.
+  pub fn new<M: Mask + ?Sized>(key: K, owner: &M, facing: Facing, boxes: &'a HitboxSet) -> Self {
+    Self {
+      key,
+      position: owner.point().clone(),
+      facing,
+      boxes
+    }
+  }
+
+  pub fn hitboxes(&self) -> Vec<Rect> {
+    self.boxes.hitboxes.iter().map( |hitbox| place(&hitbox.rect, &self.position, self.facing) ).collect()
+  }
+
+  pub fn hurtboxes(&self) -> Vec<Rect> {
+    self.boxes.hurtboxes.iter().map( |rect| place(rect, &self.position, self.facing) ).collect()
+  }
+}
+
+/// Reports that an attacker's hitbox overlapped a target's hurtbox.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitEvent<K = EntityId> {
+  pub attacker:  K,
+  pub target:    K,
+  /// The index of the hitbox in the attacker's `HitboxSet`.
+  pub hitbox:    usize,
+  pub damage:    u32,
+  /// The knockback velocity, already mirrored to the attacker's facing.
+  pub knockback: Point,
+  pub hitstun:   u32
+}
+
+/// Checks hitboxes against hurtboxes, and remembers who was hit by which attack,
+/// so an attack hits every target at most once, no matter for how many frames its hitboxes overlap.
+#[derive(Debug, Clone)]
+pub struct HitTracker<K = EntityId> {
+  hits: BTreeSet<(K, K)>
+}
+
+impl<K: Ord + Clone> HitTracker<K> {
+  pub fn new() -> Self {
+    Self {
+      hits: BTreeSet::new()
+    }
+  }
+
+  /// Starts a new attack of the `attacker`, so it can hit its previous targets again.
+  pub fn begin_attack(&mut self, attacker: &K) {
+    self.hits.retain( |(other, _)| other != attacker );
+  }
+
+  /// Returns `true` if the `attacker`'s current attack has already hit the `target`.
+  pub fn has_hit(&self, attacker: &K, target: &K) -> bool {
+    self.hits.contains(&(attacker.clone(), target.clone()))
+  }
+
+  /// Checks the hitboxes of all combatants against the hurtboxes of all others for this frame,
+  /// and returns a `HitEvent` for every new hit, sorted by attacker key, then by target key.
+  pub fn check(&mut self, combatants: &[Combatant<K>]) -> Vec<HitEvent<K>> {
+    let mut sorted: Vec<&Combatant<K>> = combatants.iter().collect();
+    sorted.sort_by( |one, two| one.key.cmp(&two.key) );
+    let mut events = Vec::new();
+    for attacker in sorted.iter() {
+      let hitboxes = attacker.hitboxes();
+      if hitboxes.is_empty() { continue; }
+      for target in sorted.iter() {
+        if target.key == attacker.key || self.has_hit(&attacker.key, &target.key) { continue; }
+        let hurtboxes = target.hurtboxes();
+        let hit = hitboxes.iter().position( |hitbox| {
+          hurtboxes.iter().any( |hurtbox| hitbox.intersects(hurtbox) )
+        });
+        if let Some(index) = hit {
+          let hitbox = &attacker.boxes.hitboxes[index];
+          self.hits.insert((attacker.key.clone(), target.key.clone()));
+          events.push(HitEvent {
+            attacker:  attacker.key.clone(),
+            target:    target.key.clone(),
+            hitbox:    index,
+            damage:    hitbox.damage,
+            knockback: Point::new(hitbox.knockback.x * attacker.facing.sign(), hitbox.knockback.y),
+            hitstun:   hitbox.hitstun
+          });
+        }
+      }
+    }
+    events
+  }
+
+  /// Forgets all hits of all attacks.
+  pub fn clear(&mut self) {
+    self.hits.clear();
+  }
+}
+
+impl<K: Ord + Clone> Default for HitTracker<K> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
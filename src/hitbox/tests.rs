@@ -0,0 +1,69 @@
+use ::geo::prelude::*;
+use super::{
+  Facing,
+  Hitbox,
+  HitboxSet,
+  HitTracker,
+  Combatant,
+  place
+};
+
+fn punch() -> HitboxSet {
+  HitboxSet {
+    hitboxes: vec![
+      Hitbox { rect: Rect::new_simple(4.0, -12.0, 10.0, 4.0), damage: 10, knockback: Point::new(200.0, -50.0), hitstun: 12 },
+      Hitbox { rect: Rect::new_simple(0.0, -12.0, 4.0, 4.0),  damage: 2,  knockback: Point::new(0.0, 0.0),     hitstun: 2 }
+    ],
+    hurtboxes: vec![Rect::new_simple(-4.0, -16.0, 8.0, 16.0)]
+  }
+}
+
+fn idle() -> HitboxSet {
+  HitboxSet {
+    hitboxes:  Vec::new(),
+    hurtboxes: vec![Rect::new_simple(-4.0, -16.0, 8.0, 16.0)]
+  }
+}
+
+#[test]
+fn boxes_are_mirrored_when_facing_left() {
+  let rect = Rect::new_simple(4.0, -12.0, 10.0, 4.0);
+  let position = Point::new(100.0, 50.0);
+  assert_eq!(place(&rect, &position, Facing::Right), Rect::new_simple(104.0, 38.0, 10.0, 4.0));
+  assert_eq!(place(&rect, &position, Facing::Left),  Rect::new_simple(86.0, 38.0, 10.0, 4.0));
+}
+
+#[test]
+fn attack_hits_each_target_once() {
+  let (punch, idle) = (punch(), idle());
+  let attacker_mask = Rect::new(Point::new(0.0, 0.0), Size::new(8.0, 16.0), Origin::BottomCenter);
+  let target_mask   = Rect::new(Point::new(-12.0, 0.0), Size::new(8.0, 16.0), Origin::BottomCenter);
+  let mut tracker = HitTracker::new();
+
+  let combatants = [
+    Combatant::new(2, &target_mask, Facing::Right, &idle),
+    Combatant::new(1, &attacker_mask, Facing::Left, &punch)
+  ];
+  let events = tracker.check(&combatants);
+  assert_eq!(events.len(), 1);
+  assert_eq!((events[0].attacker, events[0].target, events[0].hitbox), (1, 2, 0));
+  assert_eq!(events[0].knockback, Point::new(-200.0, -50.0));
+  assert!(tracker.has_hit(&1, &2));
+
+  assert!(tracker.check(&combatants).is_empty());
+  tracker.begin_attack(&1);
+  assert_eq!(tracker.check(&combatants).len(), 1);
+}
+
+#[test]
+fn facing_away_misses() {
+  let (punch, idle) = (punch(), idle());
+  let attacker_mask = Rect::new(Point::new(0.0, 0.0), Size::new(8.0, 16.0), Origin::BottomCenter);
+  let target_mask   = Rect::new(Point::new(-12.0, 0.0), Size::new(8.0, 16.0), Origin::BottomCenter);
+  let mut tracker = HitTracker::new();
+  let combatants = [
+    Combatant::new(1, &attacker_mask, Facing::Right, &punch),
+    Combatant::new(2, &target_mask, Facing::Right, &idle)
+  ];
+  assert!(tracker.check(&combatants).is_empty());
+}
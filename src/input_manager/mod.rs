@@ -4,22 +4,35 @@ use ::ggez::event::{
   Mod
 };
 
+use ::geo::{
+  NumType,
+  point::Point
+};
+
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[derive(Debug, PartialEq)]
 pub struct InputManager {
-  keys_down:    Vec<Keycode>,
-  keys_up:      Vec<Keycode>,
-  keys_pressed: Vec<Keycode>,
-  mouse_down:   Vec<MouseButton>,
-  mouse_up:     Vec<MouseButton>
+  keys_down:      Vec<Keycode>,
+  keys_up:        Vec<Keycode>,
+  keys_pressed:   Vec<Keycode>,
+  mouse_down:     Vec<MouseButton>,
+  mouse_up:       Vec<MouseButton>,
+  mouse_pressed:  Vec<MouseButton>,
+  mouse_position: Point
 }
 
 impl InputManager {
   pub fn new() -> Self {
     Self {
-      keys_down:    Vec::new(),
-      keys_up:      Vec::new(),
-      keys_pressed: Vec::new(),
-      mouse_down:   Vec::new(),
-      mouse_up:     Vec::new()
+      keys_down:      Vec::new(),
+      keys_up:        Vec::new(),
+      keys_pressed:   Vec::new(),
+      mouse_down:     Vec::new(),
+      mouse_up:       Vec::new(),
+      mouse_pressed:  Vec::new(),
+      mouse_position: Point::new(0.0, 0.0)
     }
   }
 
@@ -43,16 +56,25 @@ impl InputManager {
     &self.mouse_up
   }
 
+  pub fn mouse_pressed(&self) -> &Vec<MouseButton> {
+    &self.mouse_pressed
+  }
+
+  /// Returns the last known position of the mouse cursor, in window coordinates.
+  pub fn mouse_position(&self) -> &Point {
+    &self.mouse_position
+  }
+
   pub fn key_down(&mut self, keycode: Keycode, _keymod: Mod, repeat: bool) {
     if repeat { return; }
-    if !self.keys_pressed.iter().any( |&key| keycode == key ) {
+    if !self.keys_pressed.contains(&keycode) {
       self.keys_pressed.push(keycode);
     }
     self.keys_down.push(keycode);
   }
 
   pub fn key_up(&mut self, keycode: Keycode, _keymod: Mod, repeat: bool) {
-    if repeat { return (); }
+    if repeat { return; }
     let index: Option<usize> = self.keys_pressed.iter().position( |&key| keycode == key );
     if let Some(i) = index {
       self.keys_pressed.remove(i);
@@ -61,13 +83,24 @@ impl InputManager {
   }
 
   pub fn add_mouse_down(&mut self, mouse_button: MouseButton, x: i32, y: i32) {
+    self.mouse_motion(x, y);
+    if !self.mouse_pressed.contains(&mouse_button) {
+      self.mouse_pressed.push(mouse_button);
+    }
     self.mouse_down.push(mouse_button);
   }
 
   pub fn add_mouse_up(&mut self, mouse_button: MouseButton, x: i32, y: i32) {
+    self.mouse_motion(x, y);
+    self.mouse_pressed.retain( |&button| button != mouse_button );
     self.mouse_up.push(mouse_button);
   }
 
+  /// Should be called from ggez's `EventHandler::mouse_motion_event`.
+  pub fn mouse_motion(&mut self, x: i32, y: i32) {
+    self.mouse_position = Point::new(x as NumType, y as NumType);
+  }
+
   pub fn update(&mut self) {
     self.keys_down.clear();
     self.keys_up.clear();
@@ -75,3 +108,12 @@ impl InputManager {
     self.mouse_up.clear();
   }
 }
+
+impl Default for InputManager {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
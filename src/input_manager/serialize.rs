@@ -0,0 +1,106 @@
+//! `Serialize` / `Deserialize` implementations for the `InputManager`.
+//! ggez's `Keycode` and `MouseButton` types don't implement serde's traits,
+//! so keys are stored by their SDL names (like `"Space"`) and mouse buttons by their variant names.
+
+use ::ggez::event::{
+  Keycode,
+  MouseButton
+};
+use ::serde::{
+  Serialize,
+  Serializer,
+  Deserialize,
+  Deserializer,
+  de::Error
+};
+
+use ::geo::{
+  NumType,
+  point::Point
+};
+use super::InputManager;
+
+#[derive(Serialize, Deserialize)]
+struct InputManagerData {
+  keys_down:      Vec<String>,
+  keys_up:        Vec<String>,
+  keys_pressed:   Vec<String>,
+  mouse_down:     Vec<String>,
+  mouse_up:       Vec<String>,
+  #[serde(default)]
+  mouse_pressed:  Vec<String>,
+  #[serde(default)]
+  mouse_position: (NumType, NumType)
+}
+
+fn key_names(keys: &[Keycode]) -> Vec<String> {
+  keys.iter().map( |key| key.name() ).collect()
+}
+
+fn keys_from_names<E: Error>(names: Vec<String>) -> Result<Vec<Keycode>, E> {
+  names.into_iter().map( |name| {
+    Keycode::from_name(&name).ok_or_else( || E::custom(format!("unknown key `{}`", name)) )
+  }).collect()
+}
+
+pub fn mouse_button_name(button: &MouseButton) -> &'static str {
+  match button {
+    MouseButton::Left    => "Left",
+    MouseButton::Middle  => "Middle",
+    MouseButton::Right   => "Right",
+    MouseButton::X1      => "X1",
+    MouseButton::X2      => "X2",
+    MouseButton::Unknown => "Unknown"
+  }
+}
+
+pub fn mouse_button_from_name(name: &str) -> Option<MouseButton> {
+  match name {
+    "Left"    => Some(MouseButton::Left),
+    "Middle"  => Some(MouseButton::Middle),
+    "Right"   => Some(MouseButton::Right),
+    "X1"      => Some(MouseButton::X1),
+    "X2"      => Some(MouseButton::X2),
+    "Unknown" => Some(MouseButton::Unknown),
+    _         => None
+  }
+}
+
+fn mouse_button_names(buttons: &[MouseButton]) -> Vec<String> {
+  buttons.iter().map( |button| mouse_button_name(button).to_string() ).collect()
+}
+
+fn mouse_buttons_from_names<E: Error>(names: Vec<String>) -> Result<Vec<MouseButton>, E> {
+  names.into_iter().map( |name| {
+    mouse_button_from_name(&name).ok_or_else( || E::custom(format!("unknown mouse button `{}`", name)) )
+  }).collect()
+}
+
+impl Serialize for InputManager {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    InputManagerData {
+      keys_down:      key_names(&self.keys_down),
+      keys_up:        key_names(&self.keys_up),
+      keys_pressed:   key_names(&self.keys_pressed),
+      mouse_down:     mouse_button_names(&self.mouse_down),
+      mouse_up:       mouse_button_names(&self.mouse_up),
+      mouse_pressed:  mouse_button_names(&self.mouse_pressed),
+      mouse_position: self.mouse_position.as_tup()
+    }.serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for InputManager {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let data = InputManagerData::deserialize(deserializer)?;
+    Ok(InputManager {
+      keys_down:      keys_from_names(data.keys_down)?,
+      keys_up:        keys_from_names(data.keys_up)?,
+      keys_pressed:   keys_from_names(data.keys_pressed)?,
+      mouse_down:     mouse_buttons_from_names(data.mouse_down)?,
+      mouse_up:       mouse_buttons_from_names(data.mouse_up)?,
+      mouse_pressed:  mouse_buttons_from_names(data.mouse_pressed)?,
+      mouse_position: Point::new(data.mouse_position.0, data.mouse_position.1)
+    })
+  }
+}
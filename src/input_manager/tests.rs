@@ -0,0 +1,51 @@
+use ::ggez::event::{
+  Keycode,
+  MouseButton,
+  Mod
+};
+use ::geo::point::Point;
+use super::InputManager;
+
+fn get_input_manager() -> InputManager {
+  let mut input = InputManager::new();
+  input.key_down(Keycode::Space, Mod::empty(), false);
+  input.key_down(Keycode::A,     Mod::empty(), false);
+  input.key_up(Keycode::A,       Mod::empty(), false);
+  input.add_mouse_down(MouseButton::Left, 0, 0);
+  input
+}
+
+#[test]
+fn keys_pressed_until_released() {
+  let mut input = get_input_manager();
+  assert_eq!(input.keys_pressed(), &vec![Keycode::Space]);
+  assert_eq!(input.keys_down(),    &vec![Keycode::Space, Keycode::A]);
+  input.update();
+  assert!(input.keys_down().is_empty());
+  assert_eq!(input.keys_pressed(), &vec![Keycode::Space]);
+}
+
+#[test]
+fn mouse_buttons_pressed_until_released() {
+  let mut input = get_input_manager();
+  input.mouse_motion(12, 34);
+  assert_eq!(input.mouse_position(), &Point::new(12.0, 34.0));
+  input.update();
+  assert_eq!(input.mouse_pressed(), &vec![MouseButton::Left]);
+  input.add_mouse_up(MouseButton::Left, 20, 40);
+  assert!(input.mouse_pressed().is_empty());
+  assert_eq!(input.mouse_up(), &vec![MouseButton::Left]);
+  assert_eq!(input.mouse_position(), &Point::new(20.0, 40.0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+  let input = get_input_manager();
+  let json = ::serde_json::to_string(&input).unwrap();
+  assert_eq!(
+    json,
+    r#"{"keys_down":["Space","A"],"keys_up":["A"],"keys_pressed":["Space"],"mouse_down":["Left"],"mouse_up":[],"mouse_pressed":["Left"],"mouse_position":[0.0,0.0]}"#
+  );
+  assert_eq!(::serde_json::from_str::<InputManager>(&json).unwrap(), input);
+}
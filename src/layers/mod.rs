@@ -0,0 +1,176 @@
+use std::ops;
+
+/// The number of available collision layers.
+pub const LAYER_COUNT: usize = 32;
+
+/// A set of collision layers, stored as bitflags.
+/// Every entity and `Collider` belongs to one or more layers;
+/// a `LayerMatrix` decides which layers collide and trigger with which.
+/// # Example
+///   ```
+///   use noframe::layers::Layers;
+///
+///   const PLAYER:  Layers = Layers::layer(1);
+///   const ENEMIES: Layers = Layers::layer(2);
+///   let characters = PLAYER | ENEMIES;
+///   assert!(characters.contains(PLAYER));
+///   assert!(!characters.contains(Layers::DEFAULT));
+///   ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Layers(u32);
+
+impl Layers {
+  pub const NONE:    Layers = Layers(0);
+  pub const ALL:     Layers = Layers(!0);
+  /// The layer entities and colliders are on, unless they say otherwise.
+  pub const DEFAULT: Layers = Layers(1);
+
+  /// Returns the set containing only the layer with the given index.
+  /// # Panics
+  /// If the index isn't below `LAYER_COUNT`; in a `const` this is a compile error.
+  pub const fn layer(index: u32) -> Self {
+    assert!((index as usize) < LAYER_COUNT, "layer index out of range");
+    Layers(1 << index)
+  }
+
+  pub const fn from_bits(bits: u32) -> Self {
+    Layers(bits)
+  }
+
+  pub fn bits(&self) -> u32 {
+    self.0
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0 == 0
+  }
+
+  /// Returns `true` if all layers of `other` are in this set.
+  pub fn contains(&self, other: Layers) -> bool {
+    self.0 & other.0 == other.0
+  }
+
+  /// Returns `true` if any layer of `other` is in this set.
+  pub fn intersects(&self, other: Layers) -> bool {
+    self.0 & other.0 != 0
+  }
+
+  pub fn insert(&mut self, other: Layers) {
+    self.0 |= other.0;
+  }
+
+  pub fn remove(&mut self, other: Layers) {
+    self.0 &= !other.0;
+  }
+
+  /// Iterates over the indices of the layers in this set.
+  pub fn indices(&self) -> impl Iterator<Item = usize> {
+    let bits = self.0;
+    (0 .. LAYER_COUNT).filter( move |index| bits & (1 << index) != 0 )
+  }
+}
+
+impl Default for Layers {
+  fn default() -> Self {
+    Layers::DEFAULT
+  }
+}
+
+impl ops::BitOr for Layers {
+  type Output = Layers;
+  fn bitor(self, other: Layers) -> Layers {
+    Layers(self.0 | other.0)
+  }
+}
+
+impl ops::BitOrAssign for Layers {
+  fn bitor_assign(&mut self, other: Layers) {
+    self.0 |= other.0;
+  }
+}
+
+impl ops::BitAnd for Layers {
+  type Output = Layers;
+  fn bitand(self, other: Layers) -> Layers {
+    Layers(self.0 & other.0)
+  }
+}
+
+impl ops::BitAndAssign for Layers {
+  fn bitand_assign(&mut self, other: Layers) {
+    self.0 &= other.0;
+  }
+}
+
+impl ops::Not for Layers {
+  type Output = Layers;
+  fn not(self) -> Layers {
+    Layers(!self.0)
+  }
+}
+
+/// Says which collision layers collide and which trigger with each other.
+/// Both relations are symmetric; by default every layer collides and triggers with every layer.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LayerMatrix {
+  collides: [Layers; LAYER_COUNT],
+  triggers: [Layers; LAYER_COUNT]
+}
+
+impl LayerMatrix {
+  pub fn new() -> Self {
+    Self {
+      collides: [Layers::ALL; LAYER_COUNT],
+      triggers: [Layers::ALL; LAYER_COUNT]
+    }
+  }
+
+  fn set(rows: &mut [Layers], one: Layers, two: Layers, value: bool) {
+    for index in one.indices() {
+      if value { rows[index].insert(two) } else { rows[index].remove(two) }
+    }
+    for index in two.indices() {
+      if value { rows[index].insert(one) } else { rows[index].remove(one) }
+    }
+  }
+
+  fn get(rows: &[Layers], one: Layers, two: Layers) -> bool {
+    one.indices().any( |index| rows[index].intersects(two) )
+  }
+
+  /// Sets whether all layers in `one` collide with all layers in `two`.
+  pub fn set_collides(&mut self, one: Layers, two: Layers, collides: bool) {
+    Self::set(&mut self.collides, one, two, collides);
+  }
+
+  /// Sets whether all layers in `one` trigger with all layers in `two`.
+  pub fn set_triggers(&mut self, one: Layers, two: Layers, triggers: bool) {
+    Self::set(&mut self.triggers, one, two, triggers);
+  }
+
+  /// Returns `true` if any layer in `one` collides with any layer in `two`.
+  pub fn collides(&self, one: Layers, two: Layers) -> bool {
+    Self::get(&self.collides, one, two)
+  }
+
+  /// Returns `true` if any layer in `one` triggers with any layer in `two`.
+  pub fn triggers(&self, one: Layers, two: Layers) -> bool {
+    Self::get(&self.triggers, one, two)
+  }
+
+  /// Returns all layers that collide with any layer in `layers`.
+  pub fn collision_layers(&self, layers: Layers) -> Layers {
+    layers.indices().fold(Layers::NONE, |all, index| all | self.collides[index])
+  }
+}
+
+impl Default for LayerMatrix {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,51 @@
+use super::{
+  Layers,
+  LayerMatrix
+};
+
+const PLAYER:  Layers = Layers::layer(1);
+const ENEMIES: Layers = Layers::layer(2);
+const BULLETS: Layers = Layers::layer(3);
+
+#[test]
+fn layer_sets() {
+  let mut layers = PLAYER | BULLETS;
+  assert!(layers.contains(PLAYER));
+  assert!(layers.intersects(ENEMIES | BULLETS));
+  assert!(!layers.intersects(ENEMIES));
+  layers.remove(PLAYER);
+  assert_eq!(layers, BULLETS);
+  assert_eq!(layers.indices().collect::<Vec<_>>(), vec![3]);
+  assert_eq!(!Layers::NONE, Layers::ALL);
+}
+
+#[test]
+fn matrix_is_symmetric() {
+  let mut matrix = LayerMatrix::new();
+  assert!(matrix.collides(ENEMIES, ENEMIES));
+  matrix.set_collides(ENEMIES, ENEMIES, false);
+  matrix.set_collides(PLAYER, BULLETS, false);
+  assert!(!matrix.collides(ENEMIES, ENEMIES));
+  assert!(!matrix.collides(BULLETS, PLAYER));
+  assert!(matrix.collides(BULLETS, ENEMIES));
+  assert!(matrix.collides(ENEMIES, PLAYER | ENEMIES));
+  assert!(matrix.triggers(PLAYER, BULLETS));
+  assert_eq!(matrix.collision_layers(PLAYER), !(BULLETS));
+}
+
+#[test]
+#[should_panic(expected = "layer index out of range")]
+fn layers_past_the_last_are_rejected() {
+  let index = super::LAYER_COUNT as u32;
+  Layers::layer(index);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn matrices_need_a_row_for_every_layer() {
+  let mut matrix = LayerMatrix::new();
+  matrix.set_collides(PLAYER, BULLETS, false);
+  let json = ::serde_json::to_string(&matrix).unwrap();
+  assert_eq!(::serde_json::from_str::<LayerMatrix>(&json).unwrap(), matrix);
+  assert!(::serde_json::from_str::<LayerMatrix>("{\"collides\":[1],\"triggers\":[1]}").is_err());
+}
@@ -1,6 +1,17 @@
 extern crate ggez;
+extern crate flate2;
+extern crate image;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(all(test, feature = "hot-reload"))]
+extern crate filetime;
 
 mod settings;
+#[cfg(test)]
+mod testing;
 
 pub mod color;
 pub mod geo;
@@ -8,3 +19,19 @@ pub mod entity;
 pub mod input_manager;
 pub mod camera;
 pub mod deltatime;
+pub mod world;
+pub mod events;
+pub mod triggers;
+pub mod layers;
+pub mod hitbox;
+pub mod timer;
+pub mod tween;
+pub mod rng;
+pub mod particles;
+pub mod audio;
+pub mod assets;
+pub mod pack;
+pub mod text;
+pub mod ui;
+#[cfg(feature = "ecs")]
+pub mod ecs;
@@ -0,0 +1,315 @@
+//! An archive format for shipping a game's resources as a single file.
+//!
+//! A pack starts with a header (`NFPK`, the format version and the offset of the index),
+//! followed by the data of all files, followed by the index.
+//! Every file in the index has its path (like `/images/player.png`), whether it is compressed with deflate,
+//! its offset and size in the pack, its original size and a CRC32 checksum of its original data.
+//! Packs are created with `PackWriter`, `pack_dir` or the `noframe-pack` tool, and read with `Pack`.
+
+use std::collections::BTreeMap;
+use std::fs::{
+  self,
+  File
+};
+use std::io::{
+  self,
+  BufReader,
+  BufWriter,
+  Read,
+  Seek,
+  SeekFrom,
+  Write
+};
+use std::path::Path;
+
+use ::flate2::{
+  Compression,
+  Crc,
+  read::DeflateDecoder,
+  write::DeflateEncoder
+};
+
+const MAGIC:       &[u8; 4] = b"NFPK";
+const VERSION:     u32      = 1;
+const HEADER_SIZE: u64      = 16;
+/// The size of an index entry without its path.
+const ENTRY_SIZE:  u64      = 4 + 1 + 8 + 8 + 8 + 4;
+
+/// A file in a `Pack`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackEntry {
+  /// The asset path, like `/images/player.png`.
+  pub path:          String,
+  pub compressed:    bool,
+  offset:            u64,
+  /// The size of the data in the pack.
+  pub size:          u64,
+  /// The size of the file before compression.
+  pub original_size: u64,
+  /// The CRC32 checksum of the original file.
+  pub crc:           u32
+}
+
+fn invalid(message: String) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn checksum(data: &[u8]) -> u32 {
+  let mut crc = Crc::new();
+  crc.update(data);
+  crc.sum()
+}
+
+fn write_u32<W: Write>(writer: &mut W, value: u32) -> io::Result<()> {
+  writer.write_all(&value.to_le_bytes())
+}
+
+fn write_u64<W: Write>(writer: &mut W, value: u64) -> io::Result<()> {
+  writer.write_all(&value.to_le_bytes())
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+  let mut bytes = [0; 4];
+  reader.read_exact(&mut bytes)?;
+  Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+  let mut bytes = [0; 8];
+  reader.read_exact(&mut bytes)?;
+  Ok(u64::from_le_bytes(bytes))
+}
+
+/// Returns the path as an asset path; with a leading `/` and `/` as separator.
+pub fn normalize(path: &str) -> String {
+  let parts = path.split(['/', '\\']).filter( |part| !part.is_empty() ).collect::<Vec<&str>>();
+  format!("/{}", parts.join("/"))
+}
+
+/// Writes a pack, file by file.
+/// # Example
+///   ```
+///   use std::io::Cursor;
+///   use noframe::pack::{ Pack, PackWriter };
+///
+///   let mut writer = PackWriter::new(Cursor::new(Vec::new())).unwrap();
+///   writer.add("/config/speed.txt", b"10", false).unwrap();
+///   let mut pack = Pack::new(writer.finish().unwrap()).unwrap();
+///   assert_eq!(pack.read("/config/speed.txt").unwrap(), b"10");
+///   ```
+pub struct PackWriter<W: Write + Seek> {
+  writer:   W,
+  entries:  Vec<PackEntry>,
+  position: u64
+}
+
+impl<W: Write + Seek> PackWriter<W> {
+  pub fn new(mut writer: W) -> io::Result<Self> {
+    writer.write_all(MAGIC)?;
+    write_u32(&mut writer, VERSION)?;
+    write_u64(&mut writer, 0)?;
+    Ok(Self {
+      writer,
+      entries:  Vec::new(),
+      position: HEADER_SIZE
+    })
+  }
+
+  /// Adds a file to the pack; with `compress`, it is stored compressed unless that doesn't make it smaller.
+  pub fn add(&mut self, path: &str, data: &[u8], compress: bool) -> io::Result<&PackEntry> {
+    let path = normalize(path);
+    if self.entries.iter().any( |entry| entry.path == path ) {
+      return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("\"{}\" is already in the pack", path)));
+    }
+    let compressed = if compress {
+      let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+      encoder.write_all(data)?;
+      Some(encoder.finish()?).filter( |compressed| compressed.len() < data.len() )
+    } else {
+      None
+    };
+    let stored = compressed.as_deref().unwrap_or(data);
+    self.writer.write_all(stored)?;
+    self.entries.push(PackEntry {
+      path,
+      compressed:    compressed.is_some(),
+      offset:        self.position,
+      size:          stored.len() as u64,
+      original_size: data.len() as u64,
+      crc:           checksum(data)
+    });
+    self.position += stored.len() as u64;
+    Ok(&self.entries[self.entries.len() - 1])
+  }
+
+  pub fn entries(&self) -> &[PackEntry] {
+    &self.entries
+  }
+
+  /// Writes the index and returns the writer.
+  pub fn finish(mut self) -> io::Result<W> {
+    let index_offset = self.position;
+    write_u32(&mut self.writer, self.entries.len() as u32)?;
+    for entry in &self.entries {
+      write_u32(&mut self.writer, entry.path.len() as u32)?;
+      self.writer.write_all(entry.path.as_bytes())?;
+      self.writer.write_all(&[entry.compressed as u8])?;
+      write_u64(&mut self.writer, entry.offset)?;
+      write_u64(&mut self.writer, entry.size)?;
+      write_u64(&mut self.writer, entry.original_size)?;
+      write_u32(&mut self.writer, entry.crc)?;
+    }
+    self.writer.seek(SeekFrom::Start(MAGIC.len() as u64 + 4))?;
+    write_u64(&mut self.writer, index_offset)?;
+    self.writer.seek(SeekFrom::End(0))?;
+    self.writer.flush()?;
+    Ok(self.writer)
+  }
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<(String, std::path::PathBuf)>) -> io::Result<()> {
+  for entry in fs::read_dir(dir)? {
+    let path = entry?.path();
+    // Symlinked directories are skipped, so a symlink loop can't make this recurse forever
+    if fs::symlink_metadata(&path)?.is_dir() {
+      collect_files(root, &path, files)?;
+    } else if path.is_file() {
+      let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().into_owned();
+      files.push((normalize(&relative), path));
+    }
+  }
+  Ok(())
+}
+
+/// Packs all files in the directory `dir` into a new pack file at `out`, sorted by path.
+/// A file at `dir/images/player.png` is stored as `/images/player.png`.
+/// Symlinks to files are packed like the files, symlinks to directories are skipped.
+pub fn pack_dir(dir: &Path, out: &Path, compress: bool) -> io::Result<Vec<PackEntry>> {
+  let mut files = Vec::new();
+  collect_files(dir, dir, &mut files)?;
+  // Don't pack a previous version of the pack, if it is in the directory
+  let previous = fs::canonicalize(out).ok();
+  files.retain( |(_, file)| previous.is_none() || fs::canonicalize(file).ok() != previous );
+  files.sort();
+  let mut writer = PackWriter::new(BufWriter::new(File::create(out)?))?;
+  for (path, file) in files {
+    writer.add(&path, &fs::read(file)?, compress)?;
+  }
+  let entries = writer.entries().to_vec();
+  writer.finish()?;
+  Ok(entries)
+}
+
+/// Reads files from a pack.
+pub struct Pack<R: Read + Seek> {
+  reader:  R,
+  entries: BTreeMap<String, PackEntry>
+}
+
+impl Pack<BufReader<File>> {
+  /// Opens the pack file at `path`.
+  pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    Self::new(BufReader::new(File::open(path)?))
+  }
+}
+
+impl<R: Read + Seek> Pack<R> {
+  /// Reads the header and index of the pack.
+  /// Offsets and sizes in the index are checked against the length of the pack,
+  /// so a broken index returns an `InvalidData` error.
+  pub fn new(mut reader: R) -> io::Result<Self> {
+    let length = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+      return Err(invalid("Not a pack file".to_string()));
+    }
+    let version = read_u32(&mut reader)?;
+    if version != VERSION {
+      return Err(invalid(format!("Unsupported pack version {}", version)));
+    }
+    let index_offset = read_u64(&mut reader)?;
+    if index_offset < HEADER_SIZE || index_offset.checked_add(4).map_or(true, |end| end > length ) {
+      return Err(invalid("The pack index is outside of the pack".to_string()));
+    }
+    reader.seek(SeekFrom::Start(index_offset))?;
+    let count = read_u32(&mut reader)?;
+    let mut remaining = length - index_offset - 4;
+    if u64::from(count) * ENTRY_SIZE > remaining {
+      return Err(invalid("The pack index is truncated".to_string()));
+    }
+    let mut entries = BTreeMap::new();
+    for _ in 0 .. count {
+      let path_size = u64::from(read_u32(&mut reader)?);
+      if path_size + ENTRY_SIZE > remaining {
+        return Err(invalid("The pack index is truncated".to_string()));
+      }
+      remaining -= path_size + ENTRY_SIZE;
+      let mut path = vec![0; path_size as usize];
+      reader.read_exact(&mut path)?;
+      let path = String::from_utf8(path).map_err( |_| invalid("Invalid path in pack index".to_string()) )?;
+      let mut compressed = [0];
+      reader.read_exact(&mut compressed)?;
+      let entry = PackEntry {
+        path,
+        compressed:    compressed[0] != 0,
+        offset:        read_u64(&mut reader)?,
+        size:          read_u64(&mut reader)?,
+        original_size: read_u64(&mut reader)?,
+        crc:           read_u32(&mut reader)?
+      };
+      if entry.offset < HEADER_SIZE || entry.offset.checked_add(entry.size).map_or(true, |end| end > index_offset ) {
+        return Err(invalid(format!("Entry \"{}\" is outside of the pack's data", entry.path)));
+      }
+      entries.insert(entry.path.clone(), entry);
+    }
+    Ok(Self { reader, entries })
+  }
+
+  /// Returns all files, sorted by path.
+  pub fn entries(&self) -> impl Iterator<Item = &PackEntry> {
+    self.entries.values()
+  }
+
+  pub fn get(&self, path: &str) -> Option<&PackEntry> {
+    self.entries.get(&normalize(path))
+  }
+
+  pub fn contains(&self, path: &str) -> bool {
+    self.get(path).is_some()
+  }
+
+  /// Reads, decompresses and checks the file at `path`.
+  pub fn read(&mut self, path: &str) -> io::Result<Vec<u8>> {
+    let entry = self.get(path).cloned().ok_or_else( || io::Error::new(
+      io::ErrorKind::NotFound, format!("\"{}\" is not in the pack", path)
+    ))?;
+    self.reader.seek(SeekFrom::Start(entry.offset))?;
+    let mut stored = vec![0; entry.size as usize];
+    self.reader.read_exact(&mut stored)?;
+    let data = if entry.compressed {
+      // Reading one byte more than expected is enough to notice a wrong size, without trusting it for allocation
+      let mut data = Vec::new();
+      DeflateDecoder::new(stored.as_slice()).take(entry.original_size.saturating_add(1)).read_to_end(&mut data)?;
+      data
+    } else {
+      stored
+    };
+    if data.len() as u64 != entry.original_size || checksum(&data) != entry.crc {
+      return Err(invalid(format!("\"{}\" is corrupted, its checksum doesn't match", entry.path)));
+    }
+    Ok(data)
+  }
+
+  /// Reads and checks every file; returns the paths of broken files with their errors.
+  pub fn verify(&mut self) -> Vec<(String, io::Error)> {
+    let paths = self.entries.keys().cloned().collect::<Vec<String>>();
+    paths.into_iter()
+      .filter_map( |path| self.read(&path).err().map( |error| (path, error) ) )
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests;
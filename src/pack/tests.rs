@@ -0,0 +1,139 @@
+use std::fs;
+use std::io::Cursor;
+
+use super::*;
+
+const LEVEL: &[u8] = b"#################\n#...............#\n#...............#\n#################\n";
+
+fn pack_bytes(compress: bool) -> Vec<u8> {
+  let mut writer = PackWriter::new(Cursor::new(Vec::new())).unwrap();
+  writer.add("levels/one.txt", LEVEL, compress).unwrap();
+  writer.add("/config/speed.txt", b"10", compress).unwrap();
+  writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn paths_are_normalized() {
+  assert_eq!(normalize("images/player.png"), "/images/player.png");
+  assert_eq!(normalize("\\images\\\\player.png"), "/images/player.png");
+  assert_eq!(normalize("/images/player.png/"), "/images/player.png");
+}
+
+#[test]
+fn files_round_trip() {
+  for &compress in &[false, true] {
+    let mut pack = Pack::new(Cursor::new(pack_bytes(compress))).unwrap();
+    assert_eq!(pack.read("/levels/one.txt").unwrap(), LEVEL);
+    assert_eq!(pack.read("config/speed.txt").unwrap(), b"10");
+    let paths = pack.entries().map( |entry| entry.path.as_str() ).collect::<Vec<&str>>();
+    assert_eq!(paths, vec!["/config/speed.txt", "/levels/one.txt"]);
+    assert!(pack.verify().is_empty());
+    assert_eq!(pack.read("/levels/two.txt").unwrap_err().kind(), io::ErrorKind::NotFound);
+  }
+}
+
+#[test]
+fn only_smaller_files_are_stored_compressed() {
+  let pack = Pack::new(Cursor::new(pack_bytes(true))).unwrap();
+  let level = pack.get("/levels/one.txt").unwrap();
+  assert!(level.compressed);
+  assert!(level.size < level.original_size);
+  assert!(!pack.get("/config/speed.txt").unwrap().compressed);
+}
+
+#[test]
+fn duplicate_paths_are_rejected() {
+  let mut writer = PackWriter::new(Cursor::new(Vec::new())).unwrap();
+  writer.add("/a.txt", b"a", false).unwrap();
+  assert!(writer.add("a.txt", b"b", false).is_err());
+}
+
+#[test]
+fn corruption_is_detected() {
+  let mut bytes = pack_bytes(false);
+  bytes[HEADER_SIZE as usize + 1] ^= 0xff;
+  let mut pack = Pack::new(Cursor::new(bytes)).unwrap();
+  let broken = pack.verify();
+  assert_eq!(broken.len(), 1);
+  assert_eq!(broken[0].0, "/levels/one.txt");
+  assert_eq!(broken[0].1.kind(), io::ErrorKind::InvalidData);
+  assert_eq!(pack.read("/config/speed.txt").unwrap(), b"10");
+
+  assert!(Pack::new(Cursor::new(b"PK\x03\x04 not a pack".to_vec())).is_err());
+}
+
+#[test]
+fn directories_are_packed() {
+  let root = ::std::env::temp_dir().join(format!("noframe-pack-{}", ::std::process::id()));
+  fs::create_dir_all(root.join("resources/images")).unwrap();
+  fs::write(root.join("resources/images/player.png"), b"not really a png").unwrap();
+  fs::write(root.join("resources/settings.txt"), b"volume = 1").unwrap();
+  let entries = pack_dir(&root.join("resources"), &root.join("resources.pack"), true).unwrap();
+  assert_eq!(entries.len(), 2);
+
+  let mut pack = Pack::open(root.join("resources.pack")).unwrap();
+  assert_eq!(pack.read("/images/player.png").unwrap(), b"not really a png");
+  assert_eq!(pack.read("/settings.txt").unwrap(), b"volume = 1");
+  fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn symlinked_directories_are_skipped() {
+  use std::os::unix::fs::symlink;
+
+  let root = ::std::env::temp_dir().join(format!("noframe-pack-links-{}", ::std::process::id()));
+  fs::create_dir_all(root.join("resources/images")).unwrap();
+  fs::write(root.join("resources/images/player.png"), b"not really a png").unwrap();
+  symlink(root.join("resources"), root.join("resources/images/loop")).unwrap();
+  symlink(root.join("resources/images/player.png"), root.join("resources/hero.png")).unwrap();
+  let entries = pack_dir(&root.join("resources"), &root.join("resources.pack"), false).unwrap();
+  let paths: Vec<_> = entries.iter().map( |entry| entry.path.as_str() ).collect();
+  assert_eq!(paths, vec!["/hero.png", "/images/player.png"]);
+  fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn broken_indexes_are_rejected() {
+  let bytes = pack_bytes(true);
+  let index_offset = read_u64(&mut &bytes[8 ..]).unwrap() as usize;
+  let path_size = read_u32(&mut &bytes[index_offset + 4 ..]).unwrap() as usize;
+  let rejected = |bytes: Vec<u8>| Pack::new(Cursor::new(bytes)).err().map( |error| error.kind() );
+
+  // Truncated in the middle of the index
+  assert_eq!(rejected(bytes[.. bytes.len() - 10].to_vec()), Some(io::ErrorKind::InvalidData));
+
+  // An index offset past the end of the pack
+  let mut garbled = bytes.clone();
+  garbled[8 .. 16].copy_from_slice(&u64::MAX.to_le_bytes());
+  assert_eq!(rejected(garbled), Some(io::ErrorKind::InvalidData));
+
+  // A huge entry count and a huge path length
+  let mut garbled = bytes.clone();
+  garbled[index_offset .. index_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+  assert_eq!(rejected(garbled), Some(io::ErrorKind::InvalidData));
+  let mut garbled = bytes.clone();
+  garbled[index_offset + 4 .. index_offset + 8].copy_from_slice(&u32::MAX.to_le_bytes());
+  assert_eq!(rejected(garbled), Some(io::ErrorKind::InvalidData));
+
+  // An entry whose offset and size overflow
+  let mut garbled = bytes.clone();
+  let offset = index_offset + 8 + path_size + 1;
+  garbled[offset .. offset + 16].copy_from_slice(&[0xff; 16]);
+  assert_eq!(rejected(garbled), Some(io::ErrorKind::InvalidData));
+}
+
+#[test]
+fn wrong_original_sizes_are_detected() {
+  let mut bytes = pack_bytes(true);
+  // The first entry of the index is the compressed level; claim it is huge
+  let index_offset = read_u64(&mut &bytes[8 ..]).unwrap() as usize;
+  let path_size = read_u32(&mut &bytes[index_offset + 4 ..]).unwrap() as usize;
+  let original_size = index_offset + 8 + path_size + 1 + 8 + 8;
+  bytes[original_size .. original_size + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+  let mut pack = Pack::new(Cursor::new(bytes)).unwrap();
+  assert_eq!(pack.get("/levels/one.txt").unwrap().original_size, u64::MAX);
+  assert_eq!(pack.read("/levels/one.txt").unwrap_err().kind(), io::ErrorKind::InvalidData);
+  assert_eq!(pack.read("/config/speed.txt").unwrap(), b"10");
+}
@@ -0,0 +1,362 @@
+use ::ggez::{
+  GameResult,
+  Context,
+  graphics::{
+    self,
+    DrawParam,
+    Image,
+    Point2,
+    spritebatch::SpriteBatch
+  }
+};
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::color::{
+  self,
+  Color
+};
+use ::assets::{
+  Assets,
+  Handle
+};
+use ::camera::Camera;
+use ::deltatime::Deltatime;
+use ::rng::Rng;
+use ::tween::Lerp;
+
+/// A range to pick random values from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Range {
+  pub min: NumType,
+  pub max: NumType
+}
+
+impl Range {
+  pub fn new(min: NumType, max: NumType) -> Self {
+    Self { min, max }
+  }
+
+  /// A range which always returns the same value.
+  pub fn exact(value: NumType) -> Self {
+    Self::new(value, value)
+  }
+
+  pub fn pick(&self, rng: &mut Rng) -> NumType {
+    rng.range(self.min, self.max)
+  }
+}
+
+/// Values changing over a particle's life; each stop is a progress from `0.0` (birth) to `1.0` (death)
+/// and the value at that point. Values between stops are interpolated linearly.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gradient<T> {
+  pub stops: Vec<(f32, T)>
+}
+
+impl<T: Lerp + Clone> Gradient<T> {
+  /// Returns a gradient that always has the same value.
+  pub fn constant(value: T) -> Self {
+    Self { stops: vec![(0.0, value)] }
+  }
+
+  /// Returns a gradient going from `from` at birth to `to` at death.
+  pub fn between(from: T, to: T) -> Self {
+    Self { stops: vec![(0.0, from), (1.0, to)] }
+  }
+
+  /// Returns the value at the progress `t`, or `None` if the gradient has no stops.
+  /// The stops must be sorted by progress.
+  pub fn at(&self, t: f32) -> Option<T> {
+    let after = self.stops.iter().position( |(stop, _)| *stop > t );
+    match after {
+      Some(0)     => Some(self.stops[0].1.clone()),
+      None        => self.stops.last().map( |(_, value)| value.clone() ),
+      Some(index) => {
+        let (start, ref from) = self.stops[index - 1];
+        let (end,   ref to)   = self.stops[index];
+        Some(from.lerp(to, (t - start) / (end - start)))
+      }
+    }
+  }
+}
+
+/// How an `Emitter` spawns its particles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EmitMode {
+  /// Spawns no particles by itself; use `Emitter::burst`.
+  Burst,
+  /// Spawns this many particles per second while the emitter is active.
+  Continuous(NumType)
+}
+
+/// The settings of an `Emitter`; can be loaded from a data file with the `serde` feature.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EmitterConfig {
+  pub mode:     EmitMode,
+  /// Lifetime of a particle in seconds.
+  pub lifetime: Range,
+  /// Initial speed in pixels per second.
+  pub speed:    Range,
+  /// Initial direction in degrees; `0` is right and `90` is down.
+  pub angle:    Range,
+  /// Acceleration in pixels per second squared.
+  pub gravity:  Point,
+  /// Exponential drag rate per second.
+  pub drag:     NumType,
+  /// The size of the particles over their life.
+  pub size:     Gradient<Size>,
+  /// The color of the particles over their life.
+  pub color:    Gradient<Color>
+}
+
+impl Default for EmitterConfig {
+  fn default() -> Self {
+    Self {
+      mode:     EmitMode::Burst,
+      lifetime: Range::new(0.5, 1.0),
+      speed:    Range::new(50.0, 100.0),
+      angle:    Range::new(0.0, 360.0),
+      gravity:  Point::new(0.0, 0.0),
+      drag:     0.0,
+      size:     Gradient::between(Size::new(4.0, 4.0), Size::new(0.0, 0.0)),
+      color:    Gradient::constant(color::WHITE)
+    }
+  }
+}
+
+/// A single particle, simulated by its `Emitter`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Particle {
+  /// The center of the particle.
+  pub position: Point,
+  pub velocity: Point,
+  /// Age in seconds.
+  pub age:      NumType,
+  /// Lifetime in seconds.
+  pub lifetime: NumType
+}
+
+impl Particle {
+  /// Returns how far the particle is through its life, from `0.0` to `1.0`.
+  pub fn progress(&self) -> f32 {
+    (self.age / self.lifetime).min(1.0)
+  }
+}
+
+/// Spawns and simulates particles on the CPU.
+/// Its `Mask` is the area particles spawn in; a size of `0` spawns all particles at its point.
+pub struct Emitter {
+  pub config: EmitterConfig,
+  point:      Point,
+  size:       Size,
+  origin:     Origin,
+  particles:  Vec<Particle>,
+  rng:        Rng,
+  emitting:   bool,
+  pending:    NumType
+}
+
+impl Emitter {
+  pub fn new(point: Point, config: EmitterConfig) -> Self {
+    Self::with_rng(point, config, Rng::from_time())
+  }
+
+  /// Returns a new `Emitter` with the given random number generator,
+  /// for example a seeded one for reproducible effects.
+  pub fn with_rng(point: Point, config: EmitterConfig, rng: Rng) -> Self {
+    Self {
+      config,
+      point,
+      size:      Size::new(0.0, 0.0),
+      origin:    Origin::Center,
+      particles: Vec::new(),
+      rng,
+      emitting:  true,
+      pending:   0.0
+    }
+  }
+
+  pub fn particles(&self) -> &[Particle] {
+    &self.particles
+  }
+
+  pub fn len(&self) -> usize {
+    self.particles.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.particles.is_empty()
+  }
+
+  /// Starts emitting particles continuously, if the mode is `Continuous`.
+  pub fn start(&mut self) {
+    self.emitting = true;
+  }
+
+  /// Stops emitting new particles; the existing ones live on.
+  pub fn stop(&mut self) {
+    self.emitting = false;
+    self.pending  = 0.0;
+  }
+
+  pub fn is_emitting(&self) -> bool {
+    self.emitting
+  }
+
+  /// Returns `true` if the emitter doesn't emit and all particles have died,
+  /// so a one-off effect can be removed.
+  pub fn is_finished(&self) -> bool {
+    self.particles.is_empty() && !(self.emitting && self.config.mode != EmitMode::Burst)
+  }
+
+  /// Spawns `count` particles at once.
+  pub fn burst(&mut self, count: usize) {
+    for _ in 0 .. count {
+      self.spawn();
+    }
+  }
+
+  fn spawn(&mut self) {
+    let top_left = self.top_left();
+    let position = Point::new(
+      top_left.x + self.size.w * self.rng.next_f32(),
+      top_left.y + self.size.h * self.rng.next_f32()
+    );
+    let angle = self.config.angle.pick(&mut self.rng).to_radians();
+    let speed = self.config.speed.pick(&mut self.rng);
+    let lifetime = self.config.lifetime.pick(&mut self.rng);
+    self.particles.push(Particle {
+      position,
+      velocity: Point::new(angle.cos() * speed, angle.sin() * speed),
+      age:      0.0,
+      lifetime
+    });
+  }
+
+  pub fn update(&mut self, dt: &Deltatime) {
+    self.update_secs(dt.secs());
+  }
+
+  /// Advances the simulation by `secs` seconds: ages, accelerates and moves the particles,
+  /// removes dead ones and spawns new ones in `Continuous` mode.
+  pub fn update_secs(&mut self, secs: NumType) {
+    let gravity = self.config.gravity.mult_axes_by(secs);
+    let drag    = (-self.config.drag * secs).exp();
+    for particle in self.particles.iter_mut() {
+      particle.age += secs;
+      particle.velocity.add(&gravity);
+      particle.velocity = particle.velocity.mult_axes_by(drag);
+      particle.position.add(&particle.velocity.mult_axes_by(secs));
+    }
+    self.particles.retain( |particle| particle.age < particle.lifetime );
+
+    if let EmitMode::Continuous(rate) = self.config.mode {
+      if self.emitting {
+        // After a hitch, spawn at most as many particles as can be alive at once, instead of the whole backlog
+        let most = (rate * self.config.lifetime.max).max(1.0);
+        self.pending = (self.pending + rate * secs).min(most);
+        let count = self.pending.floor();
+        self.pending -= count;
+        self.burst(count as usize);
+      }
+    }
+  }
+
+  /// Returns the area of a particle at its current size; particles without a `size` gradient have no area.
+  pub fn particle_rect(&self, particle: &Particle) -> Rect {
+    let size = self.config.size.at(particle.progress()).unwrap_or_else( || Size::new(0.0, 0.0) );
+    Rect::new(particle.position.clone(), size, Origin::Center)
+  }
+
+  /// Returns the particles which are inside of the `Camera`'s view.
+  pub fn visible<'a>(&'a self, camera: &'a Camera) -> impl Iterator<Item = &'a Particle> + 'a {
+    self.particles.iter().filter( move |particle| camera.intersects(&self.particle_rect(particle)) )
+  }
+
+  /// Draws all visible particles through the `Camera`, in one batch.
+  pub fn draw(&self, ctx: &mut Context, camera: &Camera, renderer: &mut ParticleRenderer) -> GameResult<()> {
+    let offset = camera.top_left().inverted();
+    for particle in self.visible(camera) {
+      let rect = self.particle_rect(particle);
+      let top_left = rect.top_left() + offset.clone();
+      renderer.batch.add(DrawParam {
+        dest:  Point2::new(top_left.x.round(), top_left.y.round()),
+        scale: Point2::new(rect.size().w / renderer.image_size.w, rect.size().h / renderer.image_size.h),
+        color: Some(self.config.color.at(particle.progress()).unwrap_or(color::WHITE).into()),
+        .. Default::default()
+      });
+    }
+    graphics::draw_ex(ctx, &renderer.batch, DrawParam::default())?;
+    renderer.batch.clear();
+    Ok(())
+  }
+}
+
+impl Mask for Emitter {
+  fn point(&self) -> &Point {
+    &self.point
+  }
+  fn point_mut(&mut self) -> &mut Point {
+    &mut self.point
+  }
+  fn size(&self) -> &Size {
+    &self.size
+  }
+  fn size_mut(&mut self) -> &mut Size {
+    &mut self.size
+  }
+  fn origin(&self) -> &Origin {
+    &self.origin
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    &mut self.origin
+  }
+}
+
+/// The `SpriteBatch` used to draw particles with `Emitter::draw`.
+/// The image is tinted with the particle colors and scaled to the particle sizes.
+pub struct ParticleRenderer {
+  batch:      SpriteBatch,
+  image_size: Size
+}
+
+impl ParticleRenderer {
+  pub fn new(image: Image) -> Self {
+    let image_size = Size::new(image.width() as NumType, image.height() as NumType);
+    Self {
+      batch: SpriteBatch::new(image),
+      image_size
+    }
+  }
+
+  /// Returns a renderer drawing an image loaded with `Assets`, or `None` if it isn't loaded.
+  pub fn from_asset(assets: &Assets, handle: &Handle<Image>) -> Option<Self> {
+    assets.get(handle).cloned().map(Self::new)
+  }
+
+  /// Returns a renderer drawing plain colored squares.
+  pub fn pixel(ctx: &mut Context) -> GameResult<Self> {
+    let image = Image::from_rgba8(ctx, 1, 1, &[255, 255, 255, 255])?;
+    Ok(Self {
+      batch:      SpriteBatch::new(image),
+      image_size: Size::new(1.0, 1.0)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use ::geo::prelude::*;
+use ::color;
+use ::camera::Camera;
+use ::deltatime::Deltatime;
+use ::testing::assert_near;
+use ::rng::Rng;
+use super::{
+  Emitter,
+  EmitterConfig,
+  EmitMode,
+  Gradient,
+  Range
+};
+
+fn config() -> EmitterConfig {
+  EmitterConfig {
+    lifetime: Range::exact(1.0),
+    speed:    Range::exact(100.0),
+    angle:    Range::exact(0.0),
+    .. EmitterConfig::default()
+  }
+}
+
+#[test]
+fn gradient_interpolates_between_stops() {
+  let gradient = Gradient { stops: vec![(0.0, 0.0), (0.5, 10.0), (1.0, 0.0)] };
+  assert_near(gradient.at(0.25).unwrap(), 5.0);
+  assert_near(gradient.at(0.5).unwrap(), 10.0);
+  assert_near(gradient.at(2.0).unwrap(), 0.0);
+  let fade = Gradient::between(color::WHITE, [1.0, 1.0, 1.0, 0.0]);
+  assert_eq!(fade.at(0.5), Some([1.0, 1.0, 1.0, 0.5]));
+}
+
+#[test]
+fn empty_gradients_have_no_value() {
+  let gradient: Gradient<NumType> = Gradient { stops: Vec::new() };
+  assert_eq!(gradient.at(0.5), None);
+  let config = EmitterConfig { size: Gradient { stops: Vec::new() }, .. config() };
+  let mut emitter = Emitter::with_rng(Point::new(10.0, 10.0), config, Rng::new(1));
+  emitter.burst(1);
+  let rect = emitter.particle_rect(&emitter.particles()[0]);
+  assert_eq!(rect.size(), &Size::new(0.0, 0.0));
+}
+
+#[test]
+fn burst_particles_move_and_die() {
+  let mut emitter = Emitter::with_rng(Point::new(10.0, 10.0), config(), Rng::new(1));
+  emitter.burst(5);
+  assert_eq!(emitter.len(), 5);
+  emitter.update(&Deltatime::fixed(Duration::from_millis(500)));
+  for particle in emitter.particles() {
+    assert_near(particle.position.x, 60.0);
+    assert_near(particle.position.y, 10.0);
+  }
+  assert_eq!(emitter.particle_rect(&emitter.particles()[0]).size(), &Size::new(2.0, 2.0));
+  emitter.update(&Deltatime::fixed(Duration::from_millis(500)));
+  assert!(emitter.is_empty());
+  assert!(emitter.is_finished());
+}
+
+#[test]
+fn gravity_and_drag() {
+  let config = EmitterConfig {
+    speed:   Range::exact(0.0),
+    gravity: Point::new(0.0, 100.0),
+    .. config()
+  };
+  let mut emitter = Emitter::with_rng(Point::new(0.0, 0.0), config, Rng::new(1));
+  emitter.burst(1);
+  for _ in 0 .. 10 {
+    emitter.update_secs(0.05);
+  }
+  assert_near(emitter.particles()[0].velocity.y, 50.0);
+
+  emitter.config.gravity = Point::new(0.0, 0.0);
+  emitter.config.drag    = 2.0;
+  emitter.update_secs(0.25);
+  assert_near(emitter.particles()[0].velocity.y, 50.0 * (-0.5 as NumType).exp());
+}
+
+#[test]
+fn continuous_emission_and_spawn_area() {
+  let config = EmitterConfig { mode: EmitMode::Continuous(40.0), .. config() };
+  let mut emitter = Emitter::with_rng(Point::new(0.0, 0.0), config, Rng::new(7));
+  emitter.set_size(Size::new(20.0, 10.0));
+  emitter.update_secs(0.1);
+  assert_eq!(emitter.len(), 4);
+  for particle in emitter.particles() {
+    assert!(particle.position.x >= -10.0 && particle.position.x < 10.0);
+    assert!(particle.position.y >= -5.0  && particle.position.y < 5.0);
+  }
+  emitter.stop();
+  emitter.update_secs(0.5);
+  assert_eq!(emitter.len(), 4);
+  assert!(!emitter.is_finished());
+}
+
+#[test]
+fn continuous_emission_caps_backlog() {
+  let steady = EmitterConfig { mode: EmitMode::Continuous(40.0), .. config() };
+  let mut emitter = Emitter::with_rng(Point::new(0.0, 0.0), steady, Rng::new(7));
+  emitter.update_secs(10.0);
+  assert_eq!(emitter.len(), 40);
+
+  let sparse = EmitterConfig { mode: EmitMode::Continuous(1.0), lifetime: Range::exact(0.5), .. config() };
+  let mut emitter = Emitter::with_rng(Point::new(0.0, 0.0), sparse, Rng::new(7));
+  emitter.update_secs(10.0);
+  assert_eq!(emitter.len(), 1);
+}
+
+#[test]
+fn same_seed_same_particles() {
+  let config = EmitterConfig::default();
+  let mut one = Emitter::with_rng(Point::new(0.0, 0.0), config.clone(), Rng::new(42));
+  let mut two = Emitter::with_rng(Point::new(0.0, 0.0), config, Rng::new(42));
+  one.burst(10);
+  two.burst(10);
+  assert_eq!(one.particles(), two.particles());
+}
+
+#[test]
+fn particles_are_culled_by_camera() {
+  let mut camera = Camera::new(Size::new(100.0, 100.0));
+  camera.move_to(&Point::new(0.0, 0.0));
+  let mut emitter = Emitter::with_rng(Point::new(0.0, 0.0), config(), Rng::new(3));
+  emitter.burst(1);
+  emitter.update_secs(0.2);
+  assert_eq!(emitter.visible(&camera).count(), 1);
+  emitter.update_secs(0.5);
+  assert_eq!(emitter.visible(&camera).count(), 0);
+}
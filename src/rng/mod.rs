@@ -0,0 +1,65 @@
+use std::time::{
+  SystemTime,
+  UNIX_EPOCH
+};
+
+use ::geo::NumType;
+
+/// A small, fast pseudo random number generator (xorshift64*).
+/// It is seeded explicitly, so the same seed always produces the same numbers on every platform,
+/// which keeps particle effects and randomized sounds reproducible in tests and replays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rng {
+  state: u64
+}
+
+impl Rng {
+  pub fn new(seed: u64) -> Self {
+    // splitmix64 spreads similar seeds over the whole state, and every seed to a different state
+    let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    state ^= state >> 31;
+    // The state must never be zero, which only one seed leads to
+    Self { state: if state == 0 { 0x9e37_79b9_7f4a_7c15 } else { state } }
+  }
+
+  /// Returns a new `Rng` seeded from the current time.
+  pub fn from_time() -> Self {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH)
+      .map( |duration| duration.as_nanos() as u64 )
+      .unwrap_or(0);
+    Self::new(nanos)
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.state ^= self.state >> 12;
+    self.state ^= self.state << 25;
+    self.state ^= self.state >> 27;
+    self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+  }
+
+  /// Returns a number from `0.0` (inclusive) to `1.0` (exclusive).
+  pub fn next_f32(&mut self) -> NumType {
+    (self.next_u64() >> 40) as NumType / (1u64 << 24) as NumType
+  }
+
+  /// Returns a number from `min` (inclusive) to `max` (exclusive); returns `min` if they are equal.
+  pub fn range(&mut self, min: NumType, max: NumType) -> NumType {
+    min + (max - min) * self.next_f32()
+  }
+
+  /// Returns an index from `0` to `len` (exclusive).
+  pub fn index(&mut self, len: usize) -> usize {
+    (self.next_u64() % len.max(1) as u64) as usize
+  }
+}
+
+impl Default for Rng {
+  fn default() -> Self {
+    Self::from_time()
+  }
+}
+
+#[cfg(test)]
+mod tests;
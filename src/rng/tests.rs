@@ -0,0 +1,24 @@
+use super::Rng;
+
+#[test]
+fn neighbouring_seeds_give_different_numbers() {
+  let numbers = |seed| {
+    let mut rng = Rng::new(seed);
+    (0 .. 4).map( |_| rng.next_u64() ).collect::<Vec<u64>>()
+  };
+  assert_eq!(numbers(1), numbers(1));
+  assert_ne!(numbers(0), numbers(1));
+  assert_ne!(numbers(2), numbers(3));
+}
+
+#[test]
+fn numbers_stay_in_range() {
+  let mut rng = Rng::new(42);
+  for _ in 0 .. 1000 {
+    let unit = rng.next_f32();
+    assert!((0.0 .. 1.0).contains(&unit));
+    let ranged = rng.range(-5.0, 5.0);
+    assert!((-5.0 .. 5.0).contains(&ranged));
+  }
+  assert_eq!(rng.range(3.0, 3.0), 3.0);
+}
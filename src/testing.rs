@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use ::geo::prelude::*;
+use ::entity::prelude::*;
+use ::deltatime::Deltatime;
+
+/// Returns a fixed `Deltatime` of `millis` milliseconds.
+pub fn deltatime(millis: u64) -> Deltatime {
+  Deltatime::fixed(Duration::from_millis(millis))
+}
+
+/// Asserts that `actual` is within `0.001` of `expected`.
+pub fn assert_near(actual: NumType, expected: NumType) {
+  assert_within(actual, expected, 0.001);
+}
+
+/// Asserts that `actual` is within `tolerance` of `expected`.
+pub fn assert_within(actual: NumType, expected: NumType, tolerance: NumType) {
+  assert!((actual - expected).abs() < tolerance, "expected {}, got {}", expected, actual);
+}
+
+/// A moving entity for unit tests: a 10 by 10 `Mask` with its origin at the top-left, at rest.
+/// Use the `with_` methods or the public fields to set it up for a test.
+#[derive(Debug, Clone)]
+pub struct Body {
+  pub point:        Point,
+  pub size:         Size,
+  pub origin:       Origin,
+  pub velocity:     Point,
+  pub max_velocity: Point
+}
+
+impl Body {
+  pub fn new(x: NumType, y: NumType) -> Self {
+    Self {
+      point:        Point::new(x, y),
+      size:         Size::new(10.0, 10.0),
+      origin:       Origin::TopLeft,
+      velocity:     Point::new(0.0, 0.0),
+      max_velocity: Point::new(1000.0, 1000.0)
+    }
+  }
+
+  pub fn with_size(mut self, w: NumType, h: NumType) -> Self {
+    self.size = Size::new(w, h);
+    self
+  }
+
+  pub fn with_origin(mut self, origin: Origin) -> Self {
+    self.origin = origin;
+    self
+  }
+
+  pub fn with_velocity(mut self, x: NumType, y: NumType) -> Self {
+    self.velocity = Point::new(x, y);
+    self
+  }
+
+  pub fn with_max_velocity(mut self, x: NumType, y: NumType) -> Self {
+    self.max_velocity = Point::new(x, y);
+    self
+  }
+}
+
+impl Mask for Body {
+  fn point(&self) -> &Point {
+    &self.point
+  }
+  fn point_mut(&mut self) -> &mut Point {
+    &mut self.point
+  }
+  fn size(&self) -> &Size {
+    &self.size
+  }
+  fn size_mut(&mut self) -> &mut Size {
+    &mut self.size
+  }
+  fn origin(&self) -> &Origin {
+    &self.origin
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    &mut self.origin
+  }
+}
+
+impl Entity for Body {}
+
+impl Velocity for Body {
+  fn velocity(&self) -> &Point {
+    &self.velocity
+  }
+  fn velocity_mut(&mut self) -> &mut Point {
+    &mut self.velocity
+  }
+  fn max_velocity(&self) -> Point {
+    self.max_velocity.clone()
+  }
+}
+
+impl Movement for Body {}
+
+impl Kinematic for Body {}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use ::ggez::{
+  GameResult,
+  GameError,
+  Context,
+  graphics::{
+    self,
+    DrawParam,
+    Image,
+    Point2,
+    spritebatch::SpriteBatch
+  }
+};
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size
+};
+use ::assets::{
+  Asset,
+  Loader,
+  Resources
+};
+use super::{
+  DrawText,
+  Metrics,
+  TextLayout
+};
+
+/// A character in a `BitmapFont`'s image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Glyph {
+  /// The top-left of the character in the image, in pixels.
+  pub source:  Point,
+  pub size:    Size,
+  /// Where the character is drawn, relative to the pen position at the top of the line.
+  pub offset:  Point,
+  pub advance: NumType
+}
+
+/// A pixel font, where every character is a part of one image.
+/// This only holds the font's metrics; draw it with a `BitmapRenderer`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitmapFont {
+  glyphs:      HashMap<char, Glyph>,
+  kerning:     HashMap<(char, char), NumType>,
+  line_height: NumType,
+  image_size:  Size,
+  scale:       NumType
+}
+
+fn parse_error(message: String) -> GameError {
+  GameError::ResourceLoadError(format!("Invalid BMFont file: {}", message))
+}
+
+impl BitmapFont {
+  /// Returns a monospaced font from an image of equally sized cells, left to right and top to bottom,
+  /// with the characters in `chars` in that order.
+  pub fn grid(image_size: Size, cell: Size, chars: &str) -> Self {
+    let columns = ((image_size.w / cell.w) as usize).max(1);
+    let glyphs = chars.chars().enumerate().map( |(index, c)| (c, Glyph {
+      source:  Point::new((index % columns) as NumType * cell.w, (index / columns) as NumType * cell.h),
+      size:    cell.clone(),
+      offset:  Point::new(0.0, 0.0),
+      advance: cell.w
+    })).collect();
+    Self {
+      glyphs,
+      kerning:     HashMap::new(),
+      line_height: cell.h,
+      image_size,
+      scale:       1.0
+    }
+  }
+
+  /// Parses a font description in the text format of AngelCode's BMFont, usually a `.fnt` file.
+  /// Only single-page fonts are supported.
+  pub fn parse_bmfont(text: &str) -> GameResult<Self> {
+    let mut font = Self {
+      glyphs:      HashMap::new(),
+      kerning:     HashMap::new(),
+      line_height: 0.0,
+      image_size:  Size::new(0.0, 0.0),
+      scale:       1.0
+    };
+    for (number, line) in text.lines().enumerate() {
+      let mut parts = line.split_whitespace();
+      let tag = match parts.next() {
+        Some(tag) => tag,
+        None      => continue
+      };
+      let values = parts.filter_map( |part| {
+        let mut pair = part.splitn(2, '=');
+        Some((pair.next()?, pair.next()?.trim_matches('"')))
+      }).collect::<HashMap<&str, &str>>();
+      let get = |key: &str| -> GameResult<NumType> {
+        values.get(key).and_then( |value| value.parse().ok() ).ok_or_else( || parse_error(
+          format!("line {} has no number \"{}\"", number + 1, key)
+        ))
+      };
+      let get_char = |key: &str| -> GameResult<char> {
+        get(key).and_then( |id| ::std::char::from_u32(id as u32).ok_or_else( || parse_error(
+          format!("line {} has an invalid character", number + 1)
+        )))
+      };
+      match tag {
+        "common" => {
+          font.line_height = get("lineHeight")?;
+          font.image_size  = Size::new(get("scaleW")?, get("scaleH")?);
+          if values.get("pages").map_or(false, |pages| *pages != "1" ) {
+            return Err(parse_error("only fonts with one page are supported".to_string()));
+          }
+        }
+        "char" => {
+          font.glyphs.insert(get_char("id")?, Glyph {
+            source:  Point::new(get("x")?, get("y")?),
+            size:    Size::new(get("width")?, get("height")?),
+            offset:  Point::new(get("xoffset")?, get("yoffset")?),
+            advance: get("xadvance")?
+          });
+        }
+        "kerning" => {
+          font.kerning.insert((get_char("first")?, get_char("second")?), get("amount")?);
+        }
+        _ => ()
+      }
+    }
+    if font.line_height <= 0.0 {
+      return Err(parse_error("no \"common\" line with a lineHeight".to_string()));
+    }
+    Ok(font)
+  }
+
+  /// Draws the font scaled by `scale`; use whole numbers to keep pixel art crisp.
+  pub fn with_scale(mut self, scale: NumType) -> Self {
+    self.scale = scale;
+    self
+  }
+
+  pub fn scale(&self) -> NumType {
+    self.scale
+  }
+
+  /// Returns the glyph of the character, or of `?` if the font doesn't have it.
+  pub fn glyph(&self, c: char) -> Option<&Glyph> {
+    self.glyphs.get(&c).or_else( || self.glyphs.get(&'?') )
+  }
+
+  pub fn image_size(&self) -> &Size {
+    &self.image_size
+  }
+}
+
+impl Metrics for BitmapFont {
+  fn advance(&self, c: char) -> NumType {
+    match self.glyph(c) {
+      Some(glyph) => glyph.advance * self.scale,
+      None        => self.line_height * 0.5 * self.scale
+    }
+  }
+
+  fn kerning(&self, left: char, right: char) -> NumType {
+    self.kerning.get(&(left, right)).map_or(0.0, |amount| amount * self.scale)
+  }
+
+  fn line_height(&self) -> NumType {
+    self.line_height * self.scale
+  }
+}
+
+fn parse_bytes(bytes: Vec<u8>, path: &Path) -> GameResult<BitmapFont> {
+  let text = String::from_utf8(bytes).map_err( |_| GameError::ResourceLoadError(
+    format!("\"{}\" is not valid UTF-8", path.display())
+  ))?;
+  BitmapFont::parse_bmfont(&text)
+}
+
+/// Loads a BMFont `.fnt` file.
+impl Asset for BitmapFont {
+  fn load(ctx: &mut Context, path: &Path) -> GameResult<Self> {
+    BitmapFont::parse_bmfont(&String::load(ctx, path)?)
+  }
+}
+
+impl Asset<Resources> for BitmapFont {
+  fn load(resources: &mut Resources, path: &Path) -> GameResult<Self> {
+    parse_bytes(resources.read(path)?, path)
+  }
+}
+
+impl<'a> Asset<Loader<'a>> for BitmapFont {
+  fn load(loader: &mut Loader<'a>, path: &Path) -> GameResult<Self> {
+    parse_bytes(loader.resources.read(path)?, path)
+  }
+}
+
+/// Draws text with a `BitmapFont` and its image, in one `SpriteBatch`.
+pub struct BitmapRenderer {
+  font:  BitmapFont,
+  batch: SpriteBatch
+}
+
+impl BitmapRenderer {
+  pub fn new(font: BitmapFont, image: Image) -> Self {
+    Self {
+      font,
+      batch: SpriteBatch::new(image)
+    }
+  }
+
+  pub fn font(&self) -> &BitmapFont {
+    &self.font
+  }
+}
+
+impl Metrics for BitmapRenderer {
+  fn advance(&self, c: char) -> NumType {
+    self.font.advance(c)
+  }
+
+  fn kerning(&self, left: char, right: char) -> NumType {
+    self.font.kerning(left, right)
+  }
+
+  fn line_height(&self) -> NumType {
+    self.font.line_height()
+  }
+}
+
+impl DrawText for BitmapRenderer {
+  fn draw_layout(&mut self, ctx: &mut Context, layout: &TextLayout) -> GameResult<()> {
+    let scale = self.font.scale;
+    let image = &self.font.image_size;
+    for line in &layout.lines {
+      let mut pen = line.position.x;
+      let mut previous = None;
+      for c in line.text.chars() {
+        if let Some(previous) = previous {
+          pen += self.font.kerning(previous, c);
+        }
+        previous = Some(c);
+        let glyph = match self.font.glyph(c) {
+          Some(glyph) => glyph,
+          None        => {
+            pen += self.font.advance(c);
+            continue;
+          }
+        };
+        if glyph.size.w > 0.0 && glyph.size.h > 0.0 {
+          self.batch.add(DrawParam {
+            src:   [
+              glyph.source.x / image.w,
+              glyph.source.y / image.h,
+              glyph.size.w   / image.w,
+              glyph.size.h   / image.h
+            ].into(),
+            dest:  Point2::new(
+              (pen + glyph.offset.x * scale).round(),
+              (line.position.y + glyph.offset.y * scale).round()
+            ),
+            scale: Point2::new(scale, scale),
+            color: Some(layout.color.into()),
+            .. Default::default()
+          });
+        }
+        pen += glyph.advance * scale;
+      }
+    }
+    graphics::draw_ex(ctx, &self.batch, DrawParam::default())?;
+    self.batch.clear();
+    Ok(())
+  }
+}
@@ -0,0 +1,267 @@
+//! Text layout and drawing.
+//! `layout` places text in a `Rect`, aligned by an `Origin`, word-wrapped and with line spacing;
+//! it only needs the `Metrics` of a font, so layouts can be calculated (and tested) without drawing.
+//! The resulting `TextLayout` is drawn with a `DrawText` font: a ggez `TrueTypeFont`, or a `BitmapRenderer` for pixel fonts.
+
+use ::ggez::{
+  GameResult,
+  Context,
+  graphics::{
+    self,
+    DrawParam,
+    Font,
+    Point2,
+    Text
+  }
+};
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::color::{
+  self,
+  Color
+};
+
+pub mod bitmap;
+
+pub use self::bitmap::{
+  BitmapFont,
+  BitmapRenderer,
+  Glyph
+};
+
+/// Measures text, for `layout`.
+pub trait Metrics {
+  /// Returns how far the pen moves after drawing the character.
+  fn advance(&self, c: char) -> NumType;
+
+  /// Returns the extra space between two specific characters; usually negative.
+  fn kerning(&self, _left: char, _right: char) -> NumType {
+    0.0
+  }
+
+  fn line_height(&self) -> NumType;
+
+  /// Returns the width of a single line of text.
+  fn width(&self, text: &str) -> NumType {
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+      if let Some(previous) = previous {
+        width += self.kerning(previous, c);
+      }
+      width += self.advance(c);
+      previous = Some(c);
+    }
+    width
+  }
+}
+
+/// A font which can draw a `TextLayout` made with its `Metrics`.
+pub trait DrawText: Metrics {
+  fn draw_layout(&mut self, ctx: &mut Context, layout: &TextLayout) -> GameResult<()>;
+}
+
+/// How text is placed by `layout`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TextStyle {
+  /// Where the text is aligned in the `Rect`; `TopLeft` is left-aligned text at the top,
+  /// `Center` is centered both horizontally and vertically, and so on.
+  pub align:        Origin,
+  /// Wraps lines at word boundaries to fit into the width of the `Rect`.
+  pub wrap:         bool,
+  /// The distance between lines, as a multiple of the font's line height.
+  pub line_spacing: NumType,
+  pub color:        Color
+}
+
+impl Default for TextStyle {
+  fn default() -> Self {
+    Self {
+      align:        Origin::TopLeft,
+      wrap:         true,
+      line_spacing: 1.0,
+      color:        color::WHITE
+    }
+  }
+}
+
+/// A line of a `TextLayout`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+  pub text:     String,
+  /// The top-left of the line.
+  pub position: Point,
+  pub width:    NumType
+}
+
+/// Text placed by `layout`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLayout {
+  pub lines: Vec<Line>,
+  pub color: Color,
+  /// The area the lines take up; it may be bigger than the `Rect` if the text doesn't fit.
+  pub bounds: Rect
+}
+
+/// Splits the text into lines; explicit line breaks are kept and lines are wrapped at `max_width`.
+/// Words longer than `max_width` are broken between characters.
+pub fn wrap<F: Metrics + ?Sized>(font: &F, text: &str, max_width: Option<NumType>) -> Vec<String> {
+  let mut lines = Vec::new();
+  for paragraph in text.split('\n') {
+    let max_width = match max_width {
+      Some(max_width) => max_width,
+      None => {
+        lines.push(paragraph.to_string());
+        continue;
+      }
+    };
+    let mut line = String::new();
+    for word in paragraph.split(' ').filter( |word| !word.is_empty() ) {
+      let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+      if font.width(&candidate) <= max_width {
+        line = candidate;
+        continue;
+      }
+      if !line.is_empty() {
+        lines.push(line);
+      }
+      line = String::new();
+      for c in word.chars() {
+        line.push(c);
+        if font.width(&line) > max_width && line.chars().count() > 1 {
+          line.pop();
+          lines.push(line);
+          line = c.to_string();
+        }
+      }
+    }
+    lines.push(line);
+  }
+  lines
+}
+
+/// Returns the horizontal and vertical alignment factors of the `Origin`;
+/// `0.0` is left or top, `0.5` is center and `1.0` is right or bottom.
+fn alignment(align: &Origin) -> (NumType, NumType) {
+  match align {
+    Origin::TopLeft      => (0.0, 0.0),
+    Origin::TopCenter    => (0.5, 0.0),
+    Origin::TopRight     => (1.0, 0.0),
+    Origin::CenterLeft   => (0.0, 0.5),
+    Origin::Center       => (0.5, 0.5),
+    Origin::CenterRight  => (1.0, 0.5),
+    Origin::BottomLeft   => (0.0, 1.0),
+    Origin::BottomCenter => (0.5, 1.0),
+    Origin::BottomRight  => (1.0, 1.0)
+  }
+}
+
+/// Places the text in the `Rect`, measured with the font's `Metrics`.
+/// # Example
+///   ```
+///   use noframe::geo::prelude::*;
+///   use noframe::text::{ self, BitmapFont, TextStyle };
+///
+///   // A monospaced font with 8x8 pixel characters
+///   let font = BitmapFont::grid(Size::new(64.0, 8.0), Size::new(8.0, 8.0), "ABCDEFG ");
+///   let rect = Rect::new(Point::new(0.0, 0.0), Size::new(40.0, 40.0), Origin::TopLeft);
+///   let style = TextStyle { align: Origin::Center, .. TextStyle::default() };
+///   let layout = text::layout(&font, "BAD CAFE", &rect, &style);
+///   assert_eq!(layout.lines.len(), 2);
+///   assert_eq!(layout.lines[0].position, Point::new(8.0, 12.0));
+///   assert_eq!(layout.lines[1].position, Point::new(4.0, 20.0));
+///   ```
+pub fn layout<F: Metrics + ?Sized>(font: &F, text: &str, rect: &Rect, style: &TextStyle) -> TextLayout {
+  let max_width = if style.wrap { Some(rect.size().w) } else { None };
+  let texts = wrap(font, text, max_width);
+  let line_height = font.line_height();
+  let advance = line_height * style.line_spacing;
+  let height = if texts.is_empty() { 0.0 } else { line_height + advance * (texts.len() - 1) as NumType };
+  let (horizontal, vertical) = alignment(&style.align);
+  let top_left = rect.top_left();
+  let top = top_left.y + (rect.size().h - height) * vertical;
+
+  let lines = texts.into_iter().enumerate().map( |(index, text)| {
+    let width = font.width(&text);
+    Line {
+      position: Point::new(
+        top_left.x + (rect.size().w - width) * horizontal,
+        top + advance * index as NumType
+      ),
+      text,
+      width
+    }
+  }).collect::<Vec<Line>>();
+
+  let left  = lines.iter().map( |line| line.position.x ).fold(top_left.x + rect.size().w * horizontal, NumType::min);
+  let right = lines.iter().map( |line| line.position.x + line.width ).fold(left, NumType::max);
+  TextLayout {
+    lines,
+    color:  style.color,
+    bounds: Rect::new(Point::new(left, top), Size::new(right - left, height), Origin::TopLeft)
+  }
+}
+
+/// Lays out and draws the text in one go.
+pub fn draw<F: DrawText + ?Sized>(ctx: &mut Context, font: &mut F, text: &str, rect: &Rect, style: &TextStyle) -> GameResult<TextLayout> {
+  let layout = layout(font, text, rect, style);
+  font.draw_layout(ctx, &layout)?;
+  Ok(layout)
+}
+
+/// A ggez TrueType `Font`.
+pub struct TrueTypeFont {
+  font: Font
+}
+
+impl TrueTypeFont {
+  pub fn new(font: Font) -> Self {
+    Self { font }
+  }
+
+  pub fn font(&self) -> &Font {
+    &self.font
+  }
+}
+
+impl Metrics for TrueTypeFont {
+  fn advance(&self, c: char) -> NumType {
+    self.font.get_width(&c.to_string()) as NumType
+  }
+
+  fn line_height(&self) -> NumType {
+    self.font.get_height() as NumType
+  }
+
+  fn width(&self, text: &str) -> NumType {
+    self.font.get_width(text) as NumType
+  }
+}
+
+impl DrawText for TrueTypeFont {
+  fn draw_layout(&mut self, ctx: &mut Context, layout: &TextLayout) -> GameResult<()> {
+    for line in layout.lines.iter().filter( |line| !line.text.is_empty() ) {
+      let text = Text::new(ctx, &line.text, &self.font)?;
+      graphics::draw_ex(ctx, &text, DrawParam {
+        dest:  Point2::new(line.position.x.round(), line.position.y.round()),
+        color: Some(layout.color.into()),
+        .. Default::default()
+      })?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,136 @@
+use super::*;
+
+const FNT: &str = "\
+info face=\"Tiny\" size=8 bold=0 italic=0
+common lineHeight=10 base=8 scaleW=32 scaleH=16 pages=1 packed=0
+page id=0 file=\"tiny.png\"
+chars count=3
+char id=65 x=0 y=0 width=6 height=8 xoffset=0 yoffset=1 xadvance=7 page=0 chnl=15
+char id=86 x=6 y=0 width=6 height=8 xoffset=0 yoffset=1 xadvance=7 page=0 chnl=15
+char id=32 x=0 y=0 width=0 height=0 xoffset=0 yoffset=0 xadvance=4 page=0 chnl=15
+kernings count=1
+kerning first=65 second=86 amount=-2
+";
+
+fn font() -> BitmapFont {
+  BitmapFont::grid(Size::new(64.0, 16.0), Size::new(8.0, 8.0), "ABCDEFGH ?")
+}
+
+fn rect() -> Rect {
+  Rect::new(Point::new(10.0, 20.0), Size::new(40.0, 40.0), Origin::TopLeft)
+}
+
+fn style(align: Origin) -> TextStyle {
+  TextStyle { align, .. TextStyle::default() }
+}
+
+#[test]
+fn words_are_wrapped() {
+  assert_eq!(wrap(&font(), "ABC DEF GH", Some(56.0)), vec!["ABC DEF", "GH"]);
+  assert_eq!(wrap(&font(), "ABC   DEF", Some(24.0)), vec!["ABC", "DEF"]);
+  assert_eq!(wrap(&font(), "ABC DEF GH", None), vec!["ABC DEF GH"]);
+}
+
+#[test]
+fn long_words_are_broken() {
+  assert_eq!(wrap(&font(), "ABCDEFGH", Some(24.0)), vec!["ABC", "DEF", "GH"]);
+  // A single character wider than the line still gets a line of its own
+  assert_eq!(wrap(&font(), "AB", Some(4.0)), vec!["A", "B"]);
+}
+
+#[test]
+fn line_breaks_are_kept() {
+  assert_eq!(wrap(&font(), "AB\n\nCD", Some(40.0)), vec!["AB", "", "CD"]);
+  assert_eq!(wrap(&font(), "AB\nCD", None), vec!["AB", "CD"]);
+}
+
+#[test]
+fn text_is_aligned() {
+  let expected = [
+    (Origin::TopLeft,      Point::new(10.0, 20.0)),
+    (Origin::TopCenter,    Point::new(22.0, 20.0)),
+    (Origin::TopRight,     Point::new(34.0, 20.0)),
+    (Origin::CenterLeft,   Point::new(10.0, 36.0)),
+    (Origin::Center,       Point::new(22.0, 36.0)),
+    (Origin::CenterRight,  Point::new(34.0, 36.0)),
+    (Origin::BottomLeft,   Point::new(10.0, 52.0)),
+    (Origin::BottomCenter, Point::new(22.0, 52.0)),
+    (Origin::BottomRight,  Point::new(34.0, 52.0))
+  ];
+  for (align, position) in expected.iter().cloned() {
+    let layout = layout(&font(), "AB", &rect(), &style(align.clone()));
+    assert_eq!(layout.lines.len(), 1);
+    assert_eq!(layout.lines[0].position, position, "{:?}", align);
+    assert_eq!(layout.bounds, Rect::new(position, Size::new(16.0, 8.0), Origin::TopLeft));
+  }
+}
+
+#[test]
+fn lines_are_aligned_separately() {
+  let layout = layout(&font(), "ABCD\nAB", &rect(), &style(Origin::TopRight));
+  assert_eq!(layout.lines[0].position, Point::new(18.0, 20.0));
+  assert_eq!(layout.lines[1].position, Point::new(34.0, 28.0));
+  assert_eq!(layout.bounds, Rect::new(Point::new(18.0, 20.0), Size::new(32.0, 16.0), Origin::TopLeft));
+}
+
+#[test]
+fn line_spacing_is_applied() {
+  let style = TextStyle { line_spacing: 1.5, align: Origin::BottomLeft, .. TextStyle::default() };
+  let layout = layout(&font(), "A\nB\nC", &rect(), &style);
+  let tops = layout.lines.iter().map( |line| line.position.y ).collect::<Vec<NumType>>();
+  // 8 + 12 + 12 pixels high, at the bottom of the rect
+  assert_eq!(tops, vec![28.0, 40.0, 52.0]);
+  assert_eq!(layout.bounds.size().h, 32.0);
+}
+
+#[test]
+fn text_overflows_without_wrapping() {
+  let style = TextStyle { wrap: false, align: Origin::TopCenter, .. TextStyle::default() };
+  let layout = layout(&font(), "ABCDEFGH", &rect(), &style);
+  assert_eq!(layout.lines.len(), 1);
+  assert_eq!(layout.bounds.top_left(), Point::new(-2.0, 20.0));
+  assert_eq!(layout.bounds.size().w, 64.0);
+}
+
+#[test]
+fn grid_fonts_are_monospaced() {
+  let font = font();
+  assert_eq!(font.width("ABC"), 24.0);
+  assert_eq!(font.glyph('H').unwrap().source, Point::new(56.0, 0.0));
+  assert_eq!(font.glyph(' ').unwrap().source, Point::new(0.0, 8.0));
+  // Unknown characters are drawn as `?`
+  assert_eq!(font.glyph('x').unwrap().source, Point::new(8.0, 8.0));
+}
+
+#[test]
+fn bmfont_files_are_parsed() {
+  let font = BitmapFont::parse_bmfont(FNT).unwrap();
+  assert_eq!(font.line_height(), 10.0);
+  assert_eq!(font.image_size(), &Size::new(32.0, 16.0));
+  let v = font.glyph('V').unwrap();
+  assert_eq!(v.source, Point::new(6.0, 0.0));
+  assert_eq!(v.offset, Point::new(0.0, 1.0));
+  assert_eq!(font.width("A V"), 18.0);
+  // Kerning pulls `V` towards `A`
+  assert_eq!(font.width("AV"), 12.0);
+  assert_eq!(font.width("VA"), 14.0);
+  // Without a `?`, unknown characters are half a line wide
+  assert!(font.glyph('x').is_none());
+  assert_eq!(font.advance('x'), 5.0);
+}
+
+#[test]
+fn invalid_bmfont_files_are_rejected() {
+  assert!(BitmapFont::parse_bmfont("info face=\"Tiny\"").is_err());
+  assert!(BitmapFont::parse_bmfont("common lineHeight=10 scaleW=32 scaleH=16 pages=2").is_err());
+  assert!(BitmapFont::parse_bmfont("common lineHeight=10 scaleW=32 scaleH=16\nchar id=65 x=0").is_err());
+}
+
+#[test]
+fn fonts_are_scaled() {
+  let font = BitmapFont::parse_bmfont(FNT).unwrap().with_scale(2.0);
+  assert_eq!(font.line_height(), 20.0);
+  assert_eq!(font.width("AV"), 24.0);
+  let layout = layout(&font, "AV", &rect(), &style(Origin::TopLeft));
+  assert_eq!(layout.bounds.size(), &Size::new(24.0, 20.0));
+}
@@ -0,0 +1,346 @@
+use std::time::Duration;
+
+use ::deltatime::Deltatime;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// A `Scheduler` runs the callback of a repeating timer at most this often per update.
+pub const MAX_CALLBACKS_PER_UPDATE: u32 = 100;
+
+/// A one-shot or repeating timer, advanced with `tick` (by a `Deltatime`) or `tick_by` (by a manual `Duration`).
+/// Durations are added up exactly, so timers ticked with the same durations always finish on the same tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timer {
+  duration:      Duration,
+  elapsed:       Duration,
+  repeating:     bool,
+  paused:        bool,
+  finished:      bool,
+  just_finished: u32
+}
+
+impl Timer {
+  fn new(duration: Duration, repeating: bool) -> Self {
+    Self {
+      duration,
+      elapsed:       Duration::new(0, 0),
+      repeating,
+      paused:        false,
+      finished:      false,
+      just_finished: 0
+    }
+  }
+
+  /// Returns a timer which finishes once, after the `duration`.
+  pub fn once(duration: Duration) -> Self {
+    Self::new(duration, false)
+  }
+
+  /// Returns a timer which finishes every time the `duration` has passed.
+  pub fn repeating(duration: Duration) -> Self {
+    Self::new(duration, true)
+  }
+
+  /// Advances the timer by the deltatime; see `tick_by`.
+  pub fn tick(&mut self, dt: &Deltatime) -> u32 {
+    self.tick_by(dt.get())
+  }
+
+  /// Advances the timer by the `delta` and returns how often it finished during this tick;
+  /// a repeating timer can finish several times, if the `delta` is longer than its duration.
+  /// Paused and finished one-shot timers don't advance.
+  pub fn tick_by(&mut self, delta: Duration) -> u32 {
+    self.just_finished = 0;
+    if self.paused || self.finished { return 0; }
+    self.elapsed = self.elapsed.saturating_add(delta);
+    if self.elapsed < self.duration { return 0; }
+    if !self.repeating {
+      self.elapsed  = self.duration;
+      self.finished = true;
+      self.just_finished = 1;
+    } else if self.duration == Duration::new(0, 0) {
+      self.elapsed = Duration::new(0, 0);
+      self.just_finished = 1;
+    } else {
+      // Divide once instead of subtracting one period at a time, so tiny durations don't loop for long
+      let duration = self.duration.as_nanos();
+      let elapsed  = self.elapsed.as_nanos();
+      let remainder = elapsed % duration;
+      self.elapsed = Duration::new((remainder / NANOS_PER_SEC) as u64, (remainder % NANOS_PER_SEC) as u32);
+      self.just_finished = (elapsed / duration).min(u128::from(u32::MAX)) as u32;
+    }
+    self.just_finished
+  }
+
+  /// Returns `true` if the timer finished during the last tick.
+  pub fn just_finished(&self) -> bool {
+    self.just_finished > 0
+  }
+
+  /// Returns how often the timer finished during the last tick.
+  pub fn times_finished(&self) -> u32 {
+    self.just_finished
+  }
+
+  /// Returns `true` if this one-shot timer has finished; repeating timers never finish.
+  pub fn is_finished(&self) -> bool {
+    self.finished
+  }
+
+  pub fn is_repeating(&self) -> bool {
+    self.repeating
+  }
+
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  /// Sets the duration, keeping the elapsed time; if more time has already elapsed,
+  /// the timer finishes on its next tick.
+  pub fn set_duration(&mut self, duration: Duration) {
+    self.duration = duration;
+    self.elapsed  = self.elapsed.min(duration);
+  }
+
+  pub fn elapsed(&self) -> Duration {
+    self.elapsed
+  }
+
+  /// Returns the time left until the timer finishes (next).
+  pub fn remaining(&self) -> Duration {
+    self.duration.saturating_sub(self.elapsed)
+  }
+
+  /// Returns how far the timer is, from `0.0` when it starts to `1.0` when it finishes.
+  pub fn progress(&self) -> f32 {
+    if self.duration == Duration::new(0, 0) { return 1.0; }
+    (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+  }
+
+  pub fn pause(&mut self) {
+    self.paused = true;
+  }
+
+  pub fn resume(&mut self) {
+    self.paused = false;
+  }
+
+  pub fn is_paused(&self) -> bool {
+    self.paused
+  }
+
+  /// Restarts the timer from `0`; this also restarts finished one-shot timers.
+  pub fn reset(&mut self) {
+    self.elapsed       = Duration::new(0, 0);
+    self.finished      = false;
+    self.just_finished = 0;
+  }
+}
+
+/// Limits how often something can happen, like shooting or dashing.
+/// # Example
+///   ```
+///   use std::time::Duration;
+///   use noframe::timer::Cooldown;
+///
+///   let mut dash = Cooldown::new(Duration::from_millis(500));
+///   assert!(dash.trigger());
+///   assert!(!dash.trigger());
+///   dash.tick_by(Duration::from_millis(500));
+///   assert!(dash.trigger());
+///   ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cooldown {
+  timer: Timer
+}
+
+impl Cooldown {
+  /// Returns a new `Cooldown`, which is ready immediately.
+  pub fn new(duration: Duration) -> Self {
+    let mut timer = Timer::once(duration);
+    timer.tick_by(duration);
+    Self { timer }
+  }
+
+  pub fn tick(&mut self, dt: &Deltatime) {
+    self.timer.tick(dt);
+  }
+
+  pub fn tick_by(&mut self, delta: Duration) {
+    self.timer.tick_by(delta);
+  }
+
+  /// Returns `true` if the cooldown has passed.
+  pub fn is_ready(&self) -> bool {
+    self.timer.is_finished()
+  }
+
+  /// If the cooldown is ready, starts it and returns `true`; otherwise returns `false`.
+  pub fn trigger(&mut self) -> bool {
+    if !self.is_ready() { return false; }
+    self.timer.reset();
+    true
+  }
+
+  /// Makes the cooldown ready immediately.
+  pub fn finish(&mut self) {
+    let remaining = self.timer.remaining();
+    self.timer.tick_by(remaining);
+  }
+
+  /// Returns the time left until the cooldown is ready.
+  pub fn remaining(&self) -> Duration {
+    self.timer.remaining()
+  }
+
+  /// Returns how far the cooldown is, from `0.0` right after triggering to `1.0` when it is ready.
+  pub fn progress(&self) -> f32 {
+    self.timer.progress()
+  }
+}
+
+/// A handle to a timer in a `Scheduler`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(u64);
+
+type Callback = Box<dyn FnMut()>;
+
+struct Scheduled {
+  id:       TimerId,
+  timer:    Timer,
+  callback: Option<Callback>
+}
+
+/// A pausable group of timers with a shared time scale.
+/// Timers can either be polled with `get` and `just_finished`, or run a callback when they finish.
+/// Pausing the `Scheduler` and its time scale affect all of its timers,
+/// so for example gameplay timers can be paused and slowed down separately from UI timers.
+pub struct Scheduler {
+  entries:    Vec<Scheduled>,
+  next_id:    u64,
+  time_scale: f32,
+  paused:     bool
+}
+
+impl Scheduler {
+  pub fn new() -> Self {
+    Self {
+      entries:    Vec::new(),
+      next_id:    0,
+      time_scale: 1.0,
+      paused:     false
+    }
+  }
+
+  fn insert(&mut self, timer: Timer, callback: Option<Callback>) -> TimerId {
+    let id = TimerId(self.next_id);
+    self.next_id += 1;
+    self.entries.push(Scheduled { id, timer, callback });
+    id
+  }
+
+  /// Adds a timer to poll with `get` and `just_finished`; it stays until it is cancelled.
+  pub fn add(&mut self, timer: Timer) -> TimerId {
+    self.insert(timer, None)
+  }
+
+  /// Calls the callback once after the `delay`; the timer is removed afterwards.
+  pub fn after<F: FnMut() + 'static>(&mut self, delay: Duration, callback: F) -> TimerId {
+    self.insert(Timer::once(delay), Some(Box::new(callback)))
+  }
+
+  /// Calls the callback every time the `interval` has passed, until it is cancelled;
+  /// but at most `MAX_CALLBACKS_PER_UPDATE` times per update, so very short intervals can't stall the game.
+  pub fn every<F: FnMut() + 'static>(&mut self, interval: Duration, callback: F) -> TimerId {
+    self.insert(Timer::repeating(interval), Some(Box::new(callback)))
+  }
+
+  /// Removes the timer; returns `false` if it didn't exist (anymore).
+  pub fn cancel(&mut self, id: TimerId) -> bool {
+    let len = self.entries.len();
+    self.entries.retain( |entry| entry.id != id );
+    self.entries.len() != len
+  }
+
+  pub fn get(&self, id: TimerId) -> Option<&Timer> {
+    self.entries.iter().find( |entry| entry.id == id ).map( |entry| &entry.timer )
+  }
+
+  pub fn get_mut(&mut self, id: TimerId) -> Option<&mut Timer> {
+    self.entries.iter_mut().find( |entry| entry.id == id ).map( |entry| &mut entry.timer )
+  }
+
+  /// Returns `true` if the timer finished during the last update.
+  pub fn just_finished(&self, id: TimerId) -> bool {
+    self.get(id).map_or(false, |timer| timer.just_finished() )
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Sets the speed of all timers; `0.5` runs them at half speed, `2.0` at double speed.
+  /// Negative and NaN scales stop the timers like `0.0`, and infinite scales are clamped to the largest `f32`.
+  pub fn set_time_scale(&mut self, time_scale: f32) {
+    self.time_scale = if time_scale.is_nan() { 0.0 } else { time_scale.clamp(0.0, f32::MAX) };
+  }
+
+  pub fn time_scale(&self) -> f32 {
+    self.time_scale
+  }
+
+  pub fn pause(&mut self) {
+    self.paused = true;
+  }
+
+  pub fn resume(&mut self) {
+    self.paused = false;
+  }
+
+  pub fn is_paused(&self) -> bool {
+    self.paused
+  }
+
+  /// Advances all timers by the deltatime; see `update_by`.
+  pub fn update(&mut self, dt: &Deltatime) {
+    self.update_by(dt.get());
+  }
+
+  /// Advances all timers by the `delta`, scaled by the time scale, and runs the callbacks of the
+  /// timers that finished. Callbacks run in the order the timers finished during the `delta`;
+  /// timers finishing at the same time run in the order they were added.
+  pub fn update_by(&mut self, delta: Duration) {
+    if self.paused { return; }
+    let secs = delta.as_secs_f64() * f64::from(self.time_scale);
+    // Scaled deltas too long for a `Duration` saturate
+    let delta = if secs < u64::MAX as f64 { Duration::from_secs_f64(secs) } else { Duration::MAX };
+    let mut fired = Vec::new();
+    for (index, entry) in self.entries.iter_mut().enumerate() {
+      let until_first = entry.timer.remaining();
+      let times = entry.timer.tick_by(delta).min(MAX_CALLBACKS_PER_UPDATE);
+      for time in 0 .. times {
+        fired.push((until_first + entry.timer.duration() * time, index));
+      }
+    }
+    fired.sort();
+    for (_, index) in fired {
+      if let Some(ref mut callback) = self.entries[index].callback {
+        callback();
+      }
+    }
+    self.entries.retain( |entry| !(entry.callback.is_some() && entry.timer.is_finished()) );
+  }
+}
+
+impl Default for Scheduler {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ::deltatime::Deltatime;
+use super::{
+  Timer,
+  Cooldown,
+  Scheduler,
+  MAX_CALLBACKS_PER_UPDATE
+};
+
+fn millis(value: u64) -> Duration {
+  Duration::from_millis(value)
+}
+
+#[test]
+fn one_shot_timer() {
+  let mut timer = Timer::once(millis(100));
+  let dt = Deltatime::fixed(millis(40));
+  assert_eq!(timer.tick(&dt), 0);
+  assert_eq!(timer.tick(&dt), 0);
+  assert_eq!(timer.remaining(), millis(20));
+  assert_eq!(timer.tick(&dt), 1);
+  assert!(timer.just_finished());
+  assert!(timer.is_finished());
+  assert_eq!(timer.tick(&dt), 0);
+  assert!(!timer.just_finished());
+  timer.reset();
+  assert_eq!(timer.progress(), 0.0);
+}
+
+#[test]
+fn repeating_timer_keeps_the_remainder() {
+  let mut timer = Timer::repeating(millis(100));
+  assert_eq!(timer.tick_by(millis(250)), 2);
+  assert_eq!(timer.elapsed(), millis(50));
+  assert_eq!(timer.tick_by(millis(50)), 1);
+  assert!(!timer.is_finished());
+  timer.pause();
+  assert_eq!(timer.tick_by(millis(500)), 0);
+  assert_eq!(timer.elapsed(), millis(0));
+}
+
+#[test]
+fn tiny_repeating_timers_finish_in_one_step() {
+  let mut timer = Timer::repeating(Duration::from_nanos(3));
+  assert_eq!(timer.tick_by(Duration::from_secs(1)), 333_333_333);
+  assert_eq!(timer.elapsed(), Duration::from_nanos(1));
+  let mut timer = Timer::repeating(Duration::from_nanos(1));
+  assert_eq!(timer.tick_by(Duration::MAX), u32::MAX);
+  assert_eq!(timer.tick_by(Duration::MAX), u32::MAX);
+}
+
+#[test]
+fn shortened_timers_finish_on_the_next_tick() {
+  let mut timer = Timer::once(millis(300));
+  timer.tick_by(millis(200));
+  timer.set_duration(millis(100));
+  assert_eq!(timer.remaining(), millis(0));
+  assert_eq!(timer.progress(), 1.0);
+  assert_eq!(timer.tick_by(millis(0)), 1);
+  assert!(timer.is_finished());
+
+  let mut cooldown = Cooldown::new(millis(300));
+  cooldown.trigger();
+  cooldown.tick_by(millis(200));
+  cooldown.finish();
+  assert!(cooldown.is_ready());
+
+  let mut scheduler = Scheduler::new();
+  let id = scheduler.add(Timer::repeating(millis(300)));
+  scheduler.update_by(millis(250));
+  scheduler.get_mut(id).unwrap().set_duration(millis(100));
+  scheduler.update_by(millis(50));
+  assert!(scheduler.just_finished(id));
+  assert_eq!(scheduler.get(id).unwrap().elapsed(), millis(50));
+}
+
+#[test]
+fn cooldown() {
+  let mut cooldown = Cooldown::new(millis(300));
+  assert!(cooldown.is_ready());
+  assert!(cooldown.trigger());
+  cooldown.tick_by(millis(200));
+  assert!(!cooldown.trigger());
+  assert_eq!(cooldown.remaining(), millis(100));
+  cooldown.finish();
+  assert!(cooldown.trigger());
+}
+
+#[test]
+fn scheduler_runs_callbacks_in_order() {
+  let log = Rc::new(RefCell::new(Vec::new()));
+  let mut scheduler = Scheduler::new();
+  let (first, second) = (log.clone(), log.clone());
+  scheduler.every(millis(100), move || first.borrow_mut().push("tick"));
+  scheduler.after(millis(150), move || second.borrow_mut().push("once"));
+  let polled = scheduler.add(Timer::once(millis(250)));
+
+  scheduler.update_by(millis(300));
+  assert_eq!(*log.borrow(), vec!["tick", "once", "tick", "tick"]);
+  assert!(scheduler.just_finished(polled));
+  assert_eq!(scheduler.len(), 2);
+  scheduler.update_by(millis(10));
+  assert!(!scheduler.just_finished(polled));
+}
+
+#[test]
+fn scheduler_time_scale_and_pause() {
+  let count = Rc::new(RefCell::new(0));
+  let mut scheduler = Scheduler::new();
+  let counter = count.clone();
+  let id = scheduler.every(millis(100), move || *counter.borrow_mut() += 1);
+
+  scheduler.set_time_scale(0.5);
+  scheduler.update(&Deltatime::fixed(millis(100)));
+  assert_eq!(*count.borrow(), 0);
+  scheduler.update(&Deltatime::fixed(millis(100)));
+  assert_eq!(*count.borrow(), 1);
+
+  scheduler.pause();
+  scheduler.update_by(millis(1000));
+  assert_eq!(*count.borrow(), 1);
+  scheduler.resume();
+  assert!(scheduler.cancel(id));
+  scheduler.update_by(millis(1000));
+  assert_eq!(*count.borrow(), 1);
+}
+
+#[test]
+fn scheduler_rejects_non_finite_time_scales() {
+  let mut scheduler = Scheduler::new();
+  let id = scheduler.add(Timer::once(millis(100)));
+  scheduler.set_time_scale(f32::NAN);
+  assert_eq!(scheduler.time_scale(), 0.0);
+  scheduler.update_by(millis(1000));
+  assert!(!scheduler.just_finished(id));
+  scheduler.set_time_scale(f32::INFINITY);
+  assert_eq!(scheduler.time_scale(), f32::MAX);
+  scheduler.update_by(millis(1000));
+  assert!(scheduler.just_finished(id));
+}
+
+#[test]
+fn scheduler_caps_callbacks_per_update() {
+  let count = Rc::new(RefCell::new(0));
+  let mut scheduler = Scheduler::new();
+  let counter = count.clone();
+  let id = scheduler.every(Duration::new(0, 1), move || *counter.borrow_mut() += 1);
+  scheduler.set_time_scale(f32::MAX);
+  scheduler.update_by(millis(1000));
+  assert_eq!(*count.borrow(), MAX_CALLBACKS_PER_UPDATE);
+  assert_eq!(scheduler.get(id).unwrap().times_finished(), u32::MAX);
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeSet;
+
+use ::geo::{
+  rect::Rect,
+  mask::Mask
+};
+use ::world::EntityId;
+
+/// When a `TriggerEvent` happened, relative to the entity's overlap with the trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TriggerPhase {
+  /// The entity started overlapping the trigger this frame.
+  Enter,
+  /// The entity was already overlapping the trigger last frame, and still is.
+  Stay,
+  /// The entity stopped overlapping the trigger this frame, or one of them was removed.
+  Exit
+}
+
+/// Reports that an entity entered, stayed in or exited a trigger.
+/// The `World` publishes these to its `EventBus` as `TriggerEvent<EntityId>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriggerEvent<K = EntityId> {
+  pub phase:   TriggerPhase,
+  pub trigger: K,
+  pub entity:  K
+}
+
+/// Tracks which entities overlap which trigger volumes between frames.
+/// Triggers are ordinary masks that don't block movement; they only report overlaps.
+/// The key type `K` identifies triggers and entities, for example `EntityId`s or indices.
+#[derive(Debug, Clone)]
+pub struct Triggers<K = EntityId> {
+  overlaps: BTreeSet<(K, K)>
+}
+
+impl<K: Ord + Clone> Triggers<K> {
+  pub fn new() -> Self {
+    Self {
+      overlaps: BTreeSet::new()
+    }
+  }
+
+  /// Checks all triggers against all entities and returns the events for this frame.
+  /// Triggers and entities which are missing from the passed lists count as removed,
+  /// so their overlaps exit. A key which is in both lists never triggers itself.
+  /// The events are sorted by trigger key, then by entity key, independent of the order of the lists,
+  /// so replays produce the same events in the same order.
+  pub fn update(&mut self, triggers: &[(K, &dyn Mask)], entities: &[(K, &dyn Mask)]) -> Vec<TriggerEvent<K>> {
+    self.update_filtered(triggers, entities, |_, _| true)
+  }
+
+  /// Same as `update`, but only pairs of a trigger and an entity for which `filter` returns `true`
+  /// can overlap; see `LayerMatrix::triggers`.
+  pub fn update_filtered<F>(&mut self, triggers: &[(K, &dyn Mask)], entities: &[(K, &dyn Mask)], filter: F) -> Vec<TriggerEvent<K>>
+  where F: Fn(&K, &K) -> bool {
+    let mut overlaps = BTreeSet::new();
+    for (trigger_key, trigger) in triggers {
+      let rect = Rect::new(trigger.point().clone(), trigger.size().clone(), trigger.origin().clone());
+      for (entity_key, entity) in entities {
+        if trigger_key != entity_key && filter(trigger_key, entity_key) && rect.intersects(*entity) {
+          overlaps.insert((trigger_key.clone(), entity_key.clone()));
+        }
+      }
+    }
+
+    let events = overlaps.union(&self.overlaps).map( |(trigger, entity)| {
+      let key = (trigger.clone(), entity.clone());
+      let phase = match (self.overlaps.contains(&key), overlaps.contains(&key)) {
+        (false, _)    => TriggerPhase::Enter,
+        (true, true)  => TriggerPhase::Stay,
+        (true, false) => TriggerPhase::Exit
+      };
+      TriggerEvent { phase, trigger: key.0, entity: key.1 }
+    }).collect();
+    self.overlaps = overlaps;
+    events
+  }
+
+  /// Returns `true` if the entity overlapped the trigger at the last `update`.
+  pub fn contains(&self, trigger: &K, entity: &K) -> bool {
+    self.overlaps.contains(&(trigger.clone(), entity.clone()))
+  }
+
+  /// Returns the entities that overlapped the trigger at the last `update`, sorted by key.
+  pub fn entities_in(&self, trigger: &K) -> Vec<K> {
+    self.overlaps.iter()
+      .filter( |(other, _)| other == trigger )
+      .map( |(_, entity)| entity.clone() )
+      .collect()
+  }
+
+  /// Forgets all overlaps, without any exit events.
+  pub fn clear(&mut self) {
+    self.overlaps.clear();
+  }
+}
+
+impl<K: Ord + Clone> Default for Triggers<K> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
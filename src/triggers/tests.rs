@@ -0,0 +1,56 @@
+use ::geo::prelude::*;
+use super::{
+  Triggers,
+  TriggerEvent,
+  TriggerPhase
+};
+
+fn event(phase: TriggerPhase, trigger: u32, entity: u32) -> TriggerEvent<u32> {
+  TriggerEvent { phase, trigger, entity }
+}
+
+#[test]
+fn enter_stay_and_exit() {
+  let mut triggers = Triggers::new();
+  let zone   = Rect::new_simple(0.0, 0.0, 20.0, 20.0);
+  let mut entity = Rect::new_simple(-20.0, 5.0, 10.0, 10.0);
+
+  assert!(triggers.update(&[(1, &zone)], &[(2, &entity)]).is_empty());
+  entity.point_mut().set_x(-5.0);
+  assert_eq!(triggers.update(&[(1, &zone)], &[(2, &entity)]), vec![event(TriggerPhase::Enter, 1, 2)]);
+  assert!(triggers.contains(&1, &2));
+  entity.point_mut().set_x(5.0);
+  assert_eq!(triggers.update(&[(1, &zone)], &[(2, &entity)]), vec![event(TriggerPhase::Stay, 1, 2)]);
+  entity.point_mut().set_x(20.0);
+  assert_eq!(triggers.update(&[(1, &zone)], &[(2, &entity)]), vec![event(TriggerPhase::Exit, 1, 2)]);
+  assert!(triggers.update(&[(1, &zone)], &[(2, &entity)]).is_empty());
+}
+
+#[test]
+fn removed_entities_exit() {
+  let mut triggers = Triggers::new();
+  let zone   = Rect::new_simple(0.0, 0.0, 20.0, 20.0);
+  let entity = Rect::new_simple(5.0, 5.0, 10.0, 10.0);
+  triggers.update(&[(1, &zone)], &[(2, &entity)]);
+  assert_eq!(triggers.update(&[(1, &zone)], &[]), vec![event(TriggerPhase::Exit, 1, 2)]);
+  assert!(triggers.entities_in(&1).is_empty());
+}
+
+#[test]
+fn events_are_ordered_by_key() {
+  let zone_a = Rect::new_simple(0.0, 0.0, 20.0, 20.0);
+  let zone_b = Rect::new_simple(10.0, 0.0, 20.0, 20.0);
+  let one    = Rect::new_simple(12.0, 5.0, 4.0, 4.0);
+  let two    = Rect::new_simple(2.0, 5.0, 4.0, 4.0);
+
+  let mut forwards  = Triggers::new();
+  let mut backwards = Triggers::new();
+  let events = forwards.update(&[(10, &zone_a), (20, &zone_b)], &[(1, &one), (2, &two)]);
+  assert_eq!(events, backwards.update(&[(20, &zone_b), (10, &zone_a)], &[(2, &two), (1, &one)]));
+  assert_eq!(events, vec![
+    event(TriggerPhase::Enter, 10, 1),
+    event(TriggerPhase::Enter, 10, 2),
+    event(TriggerPhase::Enter, 20, 1)
+  ]);
+  assert_eq!(forwards.entities_in(&10), vec![1, 2]);
+}
@@ -0,0 +1,132 @@
+use std::f32::consts::PI;
+
+/// The standard easing functions, see <https://easings.net>.
+/// `In` variants start slowly, `Out` variants end slowly, and `InOut` variants do both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Easing {
+  #[default]
+  Linear,
+  QuadIn,
+  QuadOut,
+  QuadInOut,
+  CubicIn,
+  CubicOut,
+  CubicInOut,
+  QuartIn,
+  QuartOut,
+  QuartInOut,
+  SineIn,
+  SineOut,
+  SineInOut,
+  ExpoIn,
+  ExpoOut,
+  ExpoInOut,
+  CircIn,
+  CircOut,
+  CircInOut,
+  /// Pulls back a little before moving.
+  BackIn,
+  /// Overshoots the target a little.
+  BackOut,
+  BackInOut,
+  ElasticIn,
+  /// Overshoots and wobbles around the target, like a spring.
+  ElasticOut,
+  ElasticInOut,
+  BounceIn,
+  /// Bounces off the target like a dropped ball.
+  BounceOut,
+  BounceInOut
+}
+
+const BACK:        f32 = 1.701_58;
+const BACK_IN_OUT: f32 = BACK * 1.525;
+
+fn bounce_out(t: f32) -> f32 {
+  const N: f32 = 7.5625;
+  const D: f32 = 2.75;
+  if t < 1.0 / D {
+    N * t * t
+  } else if t < 2.0 / D {
+    let t = t - 1.5 / D;
+    N * t * t + 0.75
+  } else if t < 2.5 / D {
+    let t = t - 2.25 / D;
+    N * t * t + 0.9375
+  } else {
+    let t = t - 2.625 / D;
+    N * t * t + 0.984_375
+  }
+}
+
+impl Easing {
+  /// Maps the linear progress `t` (from `0.0` to `1.0`) to the eased progress.
+  /// The result is `0.0` at `0.0` and `1.0` at `1.0`; `Back` and `Elastic` easings go beyond that in between.
+  /// # Example
+  ///   ```
+  ///   use noframe::tween::Easing;
+  ///
+  ///   assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+  ///   assert_eq!(Easing::QuadOut.apply(0.5), 0.75);
+  ///   ```
+  pub fn apply(&self, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    match self {
+      Easing::Linear     => t,
+      Easing::QuadIn     => t * t,
+      Easing::QuadOut    => 1.0 - (1.0 - t).powi(2),
+      Easing::QuadInOut  => if t < 0.5 { 2.0 * t * t } else { 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0 },
+      Easing::CubicIn    => t.powi(3),
+      Easing::CubicOut   => 1.0 - (1.0 - t).powi(3),
+      Easing::CubicInOut => if t < 0.5 { 4.0 * t.powi(3) } else { 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0 },
+      Easing::QuartIn    => t.powi(4),
+      Easing::QuartOut   => 1.0 - (1.0 - t).powi(4),
+      Easing::QuartInOut => if t < 0.5 { 8.0 * t.powi(4) } else { 1.0 - (-2.0 * t + 2.0).powi(4) / 2.0 },
+      Easing::SineIn     => 1.0 - (t * PI / 2.0).cos(),
+      Easing::SineOut    => (t * PI / 2.0).sin(),
+      Easing::SineInOut  => -((PI * t).cos() - 1.0) / 2.0,
+      Easing::ExpoIn     => if t == 0.0 { 0.0 } else { 2.0_f32.powf(10.0 * t - 10.0) },
+      Easing::ExpoOut    => if t == 1.0 { 1.0 } else { 1.0 - 2.0_f32.powf(-10.0 * t) },
+      Easing::ExpoInOut  => match t {
+        t if t == 0.0 || t == 1.0 => t,
+        t if t < 0.5              => 2.0_f32.powf(20.0 * t - 10.0) / 2.0,
+        t                         => (2.0 - 2.0_f32.powf(-20.0 * t + 10.0)) / 2.0
+      },
+      Easing::CircIn     => 1.0 - (1.0 - t * t).sqrt(),
+      Easing::CircOut    => (1.0 - (t - 1.0).powi(2)).sqrt(),
+      Easing::CircInOut  => if t < 0.5 {
+        (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0
+      } else {
+        ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) / 2.0
+      },
+      Easing::BackIn     => (BACK + 1.0) * t.powi(3) - BACK * t * t,
+      Easing::BackOut    => 1.0 + (BACK + 1.0) * (t - 1.0).powi(3) + BACK * (t - 1.0).powi(2),
+      Easing::BackInOut  => if t < 0.5 {
+        (2.0 * t).powi(2) * ((BACK_IN_OUT + 1.0) * 2.0 * t - BACK_IN_OUT) / 2.0
+      } else {
+        ((2.0 * t - 2.0).powi(2) * ((BACK_IN_OUT + 1.0) * (t * 2.0 - 2.0) + BACK_IN_OUT) + 2.0) / 2.0
+      },
+      Easing::ElasticIn  => match t {
+        t if t == 0.0 || t == 1.0 => t,
+        t => -2.0_f32.powf(10.0 * t - 10.0) * ((t * 10.0 - 10.75) * (2.0 * PI / 3.0)).sin()
+      },
+      Easing::ElasticOut => match t {
+        t if t == 0.0 || t == 1.0 => t,
+        t => 2.0_f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+      },
+      Easing::ElasticInOut => match t {
+        t if t == 0.0 || t == 1.0 => t,
+        t if t < 0.5 => -(2.0_f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * (2.0 * PI / 4.5)).sin()) / 2.0,
+        t            => 2.0_f32.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * (2.0 * PI / 4.5)).sin() / 2.0 + 1.0
+      },
+      Easing::BounceIn    => 1.0 - bounce_out(1.0 - t),
+      Easing::BounceOut   => bounce_out(t),
+      Easing::BounceInOut => if t < 0.5 {
+        (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0
+      } else {
+        (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0
+      }
+    }
+  }
+}
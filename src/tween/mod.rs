@@ -0,0 +1,376 @@
+use std::time::Duration;
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size,
+  mask::Mask
+};
+use ::color::Color;
+use ::deltatime::Deltatime;
+
+pub mod easing;
+
+pub use self::easing::Easing;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Values that can be interpolated by a `Tween`.
+pub trait Lerp {
+  /// Returns the value between `self` (at `t = 0.0`) and `to` (at `t = 1.0`).
+  /// `t` may be outside of that range, for overshooting easings.
+  fn lerp(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Lerp for NumType {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    self + (to - self) * t
+  }
+}
+
+impl Lerp for Point {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    Point::new(self.x.lerp(&to.x, t), self.y.lerp(&to.y, t))
+  }
+}
+
+impl Lerp for Size {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    Size::new(self.w.lerp(&to.w, t), self.h.lerp(&to.h, t))
+  }
+}
+
+impl Lerp for Color {
+  fn lerp(&self, to: &Self, t: f32) -> Self {
+    let mut color = *self;
+    for (channel, to) in color.iter_mut().zip(to.iter()) {
+      *channel = channel.lerp(to, t);
+    }
+    color
+  }
+}
+
+/// How often a `Tween` plays again after its first run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeat {
+  Times(u32),
+  Forever
+}
+
+/// Animates a value from one value to another over a duration, with an `Easing`.
+/// # Example
+///   ```
+///   use std::time::Duration;
+///   use noframe::tween::{ Tween, Easing };
+///
+///   let mut fade = Tween::new(0.0, 1.0, Duration::from_millis(200), Easing::Linear);
+///   fade.update_by(Duration::from_millis(50));
+///   assert_eq!(fade.value(), 0.25);
+///   ```
+pub struct Tween<T: Lerp> {
+  from:        T,
+  to:          T,
+  duration:    Duration,
+  easing:      Easing,
+  elapsed:     Duration,
+  cycle:       u32,
+  repeat:      Repeat,
+  yoyo:        bool,
+  finished:    bool,
+  on_complete: Option<Box<dyn FnMut()>>
+}
+
+impl<T: Lerp> Tween<T> {
+  pub fn new(from: T, to: T, duration: Duration, easing: Easing) -> Self {
+    Self {
+      from,
+      to,
+      duration,
+      easing,
+      elapsed:     Duration::new(0, 0),
+      cycle:       0,
+      repeat:      Repeat::Times(0),
+      yoyo:        false,
+      finished:    false,
+      on_complete: None
+    }
+  }
+
+  /// Plays the tween again after it finishes.
+  pub fn repeat(mut self, repeat: Repeat) -> Self {
+    self.repeat = repeat;
+    self
+  }
+
+  /// Plays every other run backwards, from `to` to `from`.
+  pub fn yoyo(mut self, yoyo: bool) -> Self {
+    self.yoyo = yoyo;
+    self
+  }
+
+  /// Calls the callback when the tween finishes, after all repeats.
+  pub fn on_complete<F: FnMut() + 'static>(mut self, callback: F) -> Self {
+    self.on_complete = Some(Box::new(callback));
+    self
+  }
+
+  /// Returns an `Animation` which applies the value of this tween to a target with `apply`,
+  /// so it can be used in a `Sequence` or `Parallel` group.
+  pub fn on<S: ?Sized, F: FnMut(&mut S, &T)>(self, apply: F) -> Track<T, F> {
+    Track {
+      tween: self,
+      apply
+    }
+  }
+
+  /// Advances the tween by the deltatime and returns its new value.
+  pub fn update(&mut self, dt: &Deltatime) -> T {
+    self.update_by(dt.get());
+    self.value()
+  }
+
+  /// Advances the tween by the `delta`; returns the part of the `delta` left over after it finished.
+  pub fn update_by(&mut self, delta: Duration) -> Duration {
+    if self.finished { return delta; }
+    self.elapsed = self.elapsed.saturating_add(delta);
+    if self.elapsed < self.duration { return Duration::new(0, 0); }
+    let repeats_left = match self.repeat {
+      Repeat::Times(times)                                   => u128::from(times.saturating_sub(self.cycle)),
+      Repeat::Forever if self.duration > Duration::new(0, 0) => u128::MAX,
+      Repeat::Forever                                        => 0
+    };
+    // Count the finished runs with one division instead of looping once per run, so tiny durations don't spin
+    let duration = self.duration.as_nanos();
+    let elapsed  = self.elapsed.as_nanos();
+    let runs     = elapsed.checked_div(duration).unwrap_or(u128::MAX);
+    let repeated = runs.min(repeats_left);
+    // Past the repeat count only the parity of `cycle` matters, for `yoyo`
+    self.cycle   = self.cycle.wrapping_add(repeated as u32);
+    let remainder = elapsed - repeated * duration;
+    self.elapsed = Duration::new((remainder / NANOS_PER_SEC) as u64, (remainder % NANOS_PER_SEC) as u32);
+    if repeated < runs {
+      let leftover = self.elapsed - self.duration;
+      self.elapsed  = self.duration;
+      self.finished = true;
+      if let Some(ref mut callback) = self.on_complete {
+        callback();
+      }
+      return leftover;
+    }
+    Duration::new(0, 0)
+  }
+
+  /// Returns the current value.
+  pub fn value(&self) -> T {
+    let t = if self.duration == Duration::new(0, 0) {
+      1.0
+    } else {
+      (self.elapsed.as_secs_f64() / self.duration.as_secs_f64()) as f32
+    };
+    let t = if self.yoyo && self.cycle % 2 == 1 { 1.0 - t } else { t };
+    self.from.lerp(&self.to, self.easing.apply(t))
+  }
+
+  pub fn is_finished(&self) -> bool {
+    self.finished
+  }
+
+  /// Restarts the tween from the beginning.
+  pub fn reset(&mut self) {
+    self.elapsed  = Duration::new(0, 0);
+    self.cycle    = 0;
+    self.finished = false;
+  }
+}
+
+impl Tween<Point> {
+  /// Advances the tween by the deltatime and moves the `Mask` to its new value.
+  pub fn update_mask<M: Mask + ?Sized>(&mut self, dt: &Deltatime, mask: &mut M) {
+    let point = self.update(dt);
+    mask.point_mut().set(&point);
+  }
+}
+
+/// Something that is animated over time and applied to a target of type `S`.
+pub trait Animation<S: ?Sized> {
+  /// Advances the animation by the `delta`, applies it to the `target`,
+  /// and returns the part of the `delta` left over after it finished.
+  fn advance(&mut self, delta: Duration, target: &mut S) -> Duration;
+  fn is_finished(&self) -> bool;
+  fn reset(&mut self);
+}
+
+/// A `Tween` applied to a target; see `Tween::on`.
+pub struct Track<T: Lerp, F> {
+  tween: Tween<T>,
+  apply: F
+}
+
+impl<T: Lerp, S: ?Sized, F: FnMut(&mut S, &T)> Animation<S> for Track<T, F> {
+  fn advance(&mut self, delta: Duration, target: &mut S) -> Duration {
+    let leftover = self.tween.update_by(delta);
+    (self.apply)(target, &self.tween.value());
+    leftover
+  }
+
+  fn is_finished(&self) -> bool {
+    self.tween.is_finished()
+  }
+
+  fn reset(&mut self) {
+    self.tween.reset();
+  }
+}
+
+/// Returns an `Animation` which moves a `Mask` with the tween, setting its `point_mut`.
+pub fn position<M: Mask + ?Sized>(tween: Tween<Point>) -> Track<Point, fn(&mut M, &Point)> {
+  fn apply<M: Mask + ?Sized>(mask: &mut M, point: &Point) {
+    mask.point_mut().set(point);
+  }
+  tween.on(apply::<M> as fn(&mut M, &Point))
+}
+
+/// Plays animations one after another.
+pub struct Sequence<S: ?Sized> {
+  animations:  Vec<Box<dyn Animation<S>>>,
+  current:     usize,
+  finished:    bool,
+  on_complete: Option<Box<dyn FnMut()>>
+}
+
+impl<S: ?Sized> Sequence<S> {
+  pub fn new() -> Self {
+    Self {
+      animations:  Vec::new(),
+      current:     0,
+      finished:    false,
+      on_complete: None
+    }
+  }
+
+  /// Adds an animation to the end of the sequence.
+  pub fn then<A: Animation<S> + 'static>(mut self, animation: A) -> Self {
+    self.animations.push(Box::new(animation));
+    self
+  }
+
+  /// Calls the callback when the last animation finishes.
+  pub fn on_complete<F: FnMut() + 'static>(mut self, callback: F) -> Self {
+    self.on_complete = Some(Box::new(callback));
+    self
+  }
+
+  pub fn update(&mut self, dt: &Deltatime, target: &mut S) {
+    self.advance(dt.get(), target);
+  }
+}
+
+impl<S: ?Sized> Default for Sequence<S> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<S: ?Sized> Animation<S> for Sequence<S> {
+  fn advance(&mut self, delta: Duration, target: &mut S) -> Duration {
+    if self.finished { return delta; }
+    let mut delta = delta;
+    while self.current < self.animations.len() {
+      delta = self.animations[self.current].advance(delta, target);
+      if !self.animations[self.current].is_finished() {
+        return Duration::new(0, 0);
+      }
+      self.current += 1;
+    }
+    // Also reached right away by an empty sequence
+    self.finished = true;
+    if let Some(ref mut callback) = self.on_complete {
+      callback();
+    }
+    delta
+  }
+
+  fn is_finished(&self) -> bool {
+    self.finished
+  }
+
+  fn reset(&mut self) {
+    self.current  = 0;
+    self.finished = false;
+    for animation in self.animations.iter_mut() {
+      animation.reset();
+    }
+  }
+}
+
+/// Plays animations at the same time; it finishes when all of them have finished.
+pub struct Parallel<S: ?Sized> {
+  animations:  Vec<Box<dyn Animation<S>>>,
+  finished:    bool,
+  on_complete: Option<Box<dyn FnMut()>>
+}
+
+impl<S: ?Sized> Parallel<S> {
+  pub fn new() -> Self {
+    Self {
+      animations:  Vec::new(),
+      finished:    false,
+      on_complete: None
+    }
+  }
+
+  /// Adds an animation to the group.
+  pub fn with<A: Animation<S> + 'static>(mut self, animation: A) -> Self {
+    self.animations.push(Box::new(animation));
+    self
+  }
+
+  /// Calls the callback when all animations have finished.
+  pub fn on_complete<F: FnMut() + 'static>(mut self, callback: F) -> Self {
+    self.on_complete = Some(Box::new(callback));
+    self
+  }
+
+  pub fn update(&mut self, dt: &Deltatime, target: &mut S) {
+    self.advance(dt.get(), target);
+  }
+}
+
+impl<S: ?Sized> Default for Parallel<S> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<S: ?Sized> Animation<S> for Parallel<S> {
+  fn advance(&mut self, delta: Duration, target: &mut S) -> Duration {
+    if self.finished { return delta; }
+    let mut leftover = delta;
+    for animation in self.animations.iter_mut() {
+      if animation.is_finished() { continue; }
+      leftover = leftover.min(animation.advance(delta, target));
+    }
+    if self.animations.iter().all( |animation| animation.is_finished() ) {
+      self.finished = true;
+      if let Some(ref mut callback) = self.on_complete {
+        callback();
+      }
+      return leftover;
+    }
+    Duration::new(0, 0)
+  }
+
+  fn is_finished(&self) -> bool {
+    self.finished
+  }
+
+  fn reset(&mut self) {
+    self.finished = false;
+    for animation in self.animations.iter_mut() {
+      animation.reset();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,154 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ::geo::prelude::*;
+use ::color;
+use ::deltatime::Deltatime;
+use ::testing::assert_near;
+use super::{
+  Easing,
+  Lerp,
+  Tween,
+  Repeat,
+  Sequence,
+  Parallel,
+  Animation,
+  position
+};
+
+const EASINGS: [Easing; 28] = [
+  Easing::Linear,
+  Easing::QuadIn,    Easing::QuadOut,    Easing::QuadInOut,
+  Easing::CubicIn,   Easing::CubicOut,   Easing::CubicInOut,
+  Easing::QuartIn,   Easing::QuartOut,   Easing::QuartInOut,
+  Easing::SineIn,    Easing::SineOut,    Easing::SineInOut,
+  Easing::ExpoIn,    Easing::ExpoOut,    Easing::ExpoInOut,
+  Easing::CircIn,    Easing::CircOut,    Easing::CircInOut,
+  Easing::BackIn,    Easing::BackOut,    Easing::BackInOut,
+  Easing::ElasticIn, Easing::ElasticOut, Easing::ElasticInOut,
+  Easing::BounceIn,  Easing::BounceOut,  Easing::BounceInOut
+];
+
+fn millis(value: u64) -> Duration {
+  Duration::from_millis(value)
+}
+
+#[test]
+fn easings_start_at_zero_and_end_at_one() {
+  for easing in EASINGS.iter() {
+    assert_near(easing.apply(0.0), 0.0);
+    assert_near(easing.apply(1.0), 1.0);
+  }
+  assert!(Easing::BackOut.apply(0.7) > 1.0);
+  assert!(Easing::BackIn.apply(0.2) < 0.0);
+  assert_near(Easing::BounceOut.apply(1.0 / 2.75), 1.0);
+}
+
+#[test]
+fn lerp_values() {
+  assert_eq!(Point::new(0.0, 10.0).lerp(&Point::new(10.0, 20.0), 0.5), Point::new(5.0, 15.0));
+  assert_eq!(Size::new(2.0, 4.0).lerp(&Size::new(4.0, 8.0), 0.5), Size::new(3.0, 6.0));
+  assert_eq!(color::BLACK.lerp(&color::WHITE, 0.5), [0.5, 0.5, 0.5, 1.0]);
+}
+
+#[test]
+fn tween_finishes_and_calls_back() {
+  let done = Rc::new(Cell::new(false));
+  let flag = done.clone();
+  let mut tween = Tween::new(Point::new(0.0, 0.0), Point::new(100.0, 0.0), millis(100), Easing::QuadIn)
+    .on_complete(move || flag.set(true));
+  assert_eq!(tween.update(&Deltatime::fixed(millis(50))), Point::new(25.0, 0.0));
+  assert!(!done.get());
+  assert_eq!(tween.update_by(millis(80)), millis(30));
+  assert_eq!(tween.value(), Point::new(100.0, 0.0));
+  assert!(tween.is_finished());
+  assert!(done.get());
+}
+
+#[test]
+fn tween_yoyo_and_repeat() {
+  let mut tween = Tween::new(0.0, 10.0, millis(100), Easing::Linear)
+    .repeat(Repeat::Times(2))
+    .yoyo(true);
+  tween.update_by(millis(150));
+  assert_near(tween.value(), 5.0);
+  tween.update_by(millis(75));
+  assert_near(tween.value(), 2.5);
+  tween.update_by(millis(75));
+  assert!(tween.is_finished());
+  assert_near(tween.value(), 10.0);
+
+  let mut forever = Tween::new(0.0, 10.0, millis(100), Easing::Linear).repeat(Repeat::Forever);
+  forever.update_by(millis(1030));
+  assert!(!forever.is_finished());
+  assert_near(forever.value(), 3.0);
+}
+
+#[test]
+fn tween_repeats_many_times_in_one_update() {
+  let mut forever = Tween::new(0.0, 10.0, Duration::new(0, 1), Easing::Linear)
+    .repeat(Repeat::Forever)
+    .yoyo(true);
+  forever.update_by(Duration::from_secs(3600));
+  assert!(!forever.is_finished());
+  assert_near(forever.value(), 0.0);
+
+  let mut times = Tween::new(0.0, 10.0, millis(10), Easing::Linear).repeat(Repeat::Times(3));
+  assert_eq!(times.update_by(millis(55)), millis(15));
+  assert!(times.is_finished());
+  assert_near(times.value(), 10.0);
+}
+
+#[test]
+fn sequence_moves_mask() {
+  let mut rect = Rect::new_simple(0.0, 0.0, 10.0, 10.0);
+  let done = Rc::new(Cell::new(false));
+  let flag = done.clone();
+  let mut path = Sequence::new()
+    .then(position(Tween::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0), millis(100), Easing::Linear)))
+    .then(position(Tween::new(Point::new(10.0, 0.0), Point::new(10.0, 20.0), millis(100), Easing::Linear)))
+    .on_complete(move || flag.set(true));
+  path.update(&Deltatime::fixed(millis(150)), &mut rect);
+  assert_eq!(rect.point(), &Point::new(10.0, 10.0));
+  path.update(&Deltatime::fixed(millis(100)), &mut rect);
+  assert_eq!(rect.point(), &Point::new(10.0, 20.0));
+  assert!(done.get());
+}
+
+#[test]
+fn empty_sequence_finishes_immediately() {
+  let mut rect = Rect::new_simple(0.0, 0.0, 10.0, 10.0);
+  let calls = Rc::new(Cell::new(0));
+  let counter = calls.clone();
+  let mut empty = Sequence::new().on_complete(move || counter.set(counter.get() + 1));
+  assert_eq!(empty.advance(millis(20), &mut rect), millis(20));
+  assert!(empty.is_finished());
+  empty.advance(millis(20), &mut rect);
+  assert_eq!(calls.get(), 1);
+  empty.reset();
+  assert!(!empty.is_finished());
+  empty.advance(millis(20), &mut rect);
+  assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn parallel_tweens_different_values() {
+  struct Sprite {
+    size:  Size,
+    color: color::Color
+  }
+  let mut sprite = Sprite { size: Size::new(0.0, 0.0), color: color::BLACK };
+  let mut group = Parallel::new()
+    .with(Tween::new(Size::new(0.0, 0.0), Size::new(8.0, 8.0), millis(100), Easing::Linear)
+      .on( |sprite: &mut Sprite, size: &Size| sprite.size = size.clone() ))
+    .with(Tween::new(color::BLACK, color::WHITE, millis(200), Easing::Linear)
+      .on( |sprite: &mut Sprite, color: &color::Color| sprite.color = *color ));
+  group.update(&Deltatime::fixed(millis(100)), &mut sprite);
+  assert_eq!(sprite.size, Size::new(8.0, 8.0));
+  assert_eq!(sprite.color, [0.5, 0.5, 0.5, 1.0]);
+  assert!(!group.is_finished());
+  group.update(&Deltatime::fixed(millis(100)), &mut sprite);
+  assert_eq!(sprite.color, color::WHITE);
+  assert!(group.is_finished());
+}
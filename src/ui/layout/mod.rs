@@ -0,0 +1,313 @@
+//! Screen-anchored layout for HUDs.
+//!
+//! A `Panel` describes where something goes relative to its parent: an anchor `Origin`, a size in pixels,
+//! percent of the parent or fitted to its content, and margins and padding.
+//! Panels can hold other panels, either each anchored on its own or stacked horizontally or vertically.
+//! `Panel::resolve` turns the tree into screen `Rect`s for a screen size, so resolve it again
+//! whenever the window or the virtual resolution changes.
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+
+/// A width or height of a `Panel`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Length {
+  Px(NumType),
+  /// Percent of the parent's size inside of its padding, less the panel's own margin;
+  /// `Percent(100.0)` fills the parent up to the margin.
+  Percent(NumType),
+  /// Fits the panel's children; a panel without children is `0.0` large.
+  Auto
+}
+
+impl Length {
+  fn resolve(&self, available: NumType, content: NumType) -> NumType {
+    match *self {
+      Length::Px(px)           => px,
+      Length::Percent(percent) => available * percent / 100.0,
+      Length::Auto             => content
+    }
+  }
+}
+
+/// Space on the four sides of a `Panel`, used for margins and padding.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Edges {
+  pub top:    NumType,
+  pub right:  NumType,
+  pub bottom: NumType,
+  pub left:   NumType
+}
+
+impl Edges {
+  /// Returns edges in clockwise order, starting at the top.
+  pub fn new(top: NumType, right: NumType, bottom: NumType, left: NumType) -> Self {
+    Self { top, right, bottom, left }
+  }
+
+  pub fn all(space: NumType) -> Self {
+    Self::new(space, space, space, space)
+  }
+
+  pub fn symmetric(vertical: NumType, horizontal: NumType) -> Self {
+    Self::new(vertical, horizontal, vertical, horizontal)
+  }
+
+  pub fn horizontal(&self) -> NumType {
+    self.left + self.right
+  }
+
+  pub fn vertical(&self) -> NumType {
+    self.top + self.bottom
+  }
+
+  /// Returns the `Rect` made smaller by the edges.
+  pub fn shrink(&self, rect: &Rect) -> Rect {
+    let top_left = rect.top_left();
+    let size = rect.size();
+    Rect::new(Point::new(top_left.x + self.left, top_left.y + self.top), self.shrink_size(size), Origin::TopLeft)
+  }
+
+  /// Returns the `Size` made smaller by the edges.
+  pub fn shrink_size(&self, size: &Size) -> Size {
+    Size::new((size.w - self.horizontal()).max(0.0), (size.h - self.vertical()).max(0.0))
+  }
+
+  /// Returns the `Size` made larger by the edges.
+  pub fn grow(&self, size: &Size) -> Size {
+    Size::new(size.w + self.horizontal(), size.h + self.vertical())
+  }
+}
+
+/// The direction in which a stacking `Panel` places its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+  /// Left to right.
+  Horizontal,
+  /// Top to bottom.
+  Vertical
+}
+
+/// Places children one after another, with `spacing` between them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Stack {
+  pub direction: Direction,
+  pub spacing:   NumType
+}
+
+/// A rectangular part of the screen, placed in its parent.
+/// # Example
+///   ```
+///   use noframe::geo::prelude::*;
+///   use noframe::ui::layout::{ Edges, Length, Panel };
+///
+///   let hud = Panel::fill()
+///     .with_padding(Edges::all(8.0))
+///     .with(Panel::new(Length::Px(100.0), Length::Px(10.0)).named("health"))
+///     .with(Panel::new(Length::Px(60.0), Length::Px(10.0)).named("score").anchored(Origin::TopRight))
+///     .with(Panel::new(Length::Percent(50.0), Length::Px(20.0)).named("prompt").anchored(Origin::BottomCenter));
+///
+///   let layout = hud.resolve(&Size::new(640.0, 480.0));
+///   assert_eq!(layout.get("health"), Some(&Rect::new_simple(8.0, 8.0, 100.0, 10.0)));
+///   assert_eq!(layout.get("score"),  Some(&Rect::new_simple(572.0, 8.0, 60.0, 10.0)));
+///   assert_eq!(layout.get("prompt"), Some(&Rect::new_simple(164.0, 452.0, 312.0, 20.0)));
+///   ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Panel {
+  /// The name under which the panel's `Rect` is found in the `Layout`.
+  pub name:     Option<String>,
+  /// The point of the parent the panel is attached to, which is also the point of the panel attached to it;
+  /// a `BottomRight` panel sits in the bottom-right corner of its parent.
+  /// In a stack, it only decides the position across the stack's direction.
+  pub anchor:   Origin,
+  pub width:    Length,
+  pub height:   Length,
+  /// The space between the panel and the edges of its parent, or its neighbours in a stack.
+  pub margin:   Edges,
+  /// The space between the panel's edges and its children.
+  pub padding:  Edges,
+  pub stack:    Option<Stack>,
+  pub children: Vec<Panel>
+}
+
+impl Default for Panel {
+  fn default() -> Self {
+    Self::new(Length::Auto, Length::Auto)
+  }
+}
+
+impl Panel {
+  pub fn new(width: Length, height: Length) -> Self {
+    Self {
+      name:     None,
+      anchor:   Origin::TopLeft,
+      width,
+      height,
+      margin:   Edges::default(),
+      padding:  Edges::default(),
+      stack:    None,
+      children: Vec::new()
+    }
+  }
+
+  /// Returns a panel which fills its parent.
+  pub fn fill() -> Self {
+    Self::new(Length::Percent(100.0), Length::Percent(100.0))
+  }
+
+  pub fn named(mut self, name: &str) -> Self {
+    self.name = Some(name.to_string());
+    self
+  }
+
+  pub fn anchored(mut self, anchor: Origin) -> Self {
+    self.anchor = anchor;
+    self
+  }
+
+  pub fn with_margin(mut self, margin: Edges) -> Self {
+    self.margin = margin;
+    self
+  }
+
+  pub fn with_padding(mut self, padding: Edges) -> Self {
+    self.padding = padding;
+    self
+  }
+
+  /// Stacks the children in the `direction` instead of anchoring each of them in the panel.
+  pub fn stacked(mut self, direction: Direction, spacing: NumType) -> Self {
+    self.stack = Some(Stack { direction, spacing });
+    self
+  }
+
+  pub fn with(mut self, child: Panel) -> Self {
+    self.children.push(child);
+    self
+  }
+
+  /// Returns the size of the panel without its margin, when `available` is its parent's content size less its margin.
+  pub fn size(&self, available: &Size) -> Size {
+    let is_auto = |length: &Length| *length == Length::Auto;
+    let content = if is_auto(&self.width) || is_auto(&self.height) {
+      // Percentages of an `Auto` sized panel's children refer to the space the panel was given
+      self.padding.grow(&self.content_size(&self.padding.shrink_size(available)))
+    } else {
+      Size::new(0.0, 0.0)
+    };
+    Size::new(
+      self.width.resolve(available.w, content.w),
+      self.height.resolve(available.h, content.h)
+    )
+  }
+
+  /// Returns the size the children take up, including their margins.
+  fn content_size(&self, inner: &Size) -> Size {
+    let sizes = self.children.iter()
+      .map( |child| child.margin.grow(&child.size(&child.margin.shrink_size(inner))) )
+      .collect::<Vec<Size>>();
+    let max_w = sizes.iter().map( |size| size.w ).fold(0.0, NumType::max);
+    let max_h = sizes.iter().map( |size| size.h ).fold(0.0, NumType::max);
+    let spacing = self.stack.map_or(0.0, |stack| stack.spacing * (sizes.len().max(1) - 1) as NumType);
+    match self.stack.map( |stack| stack.direction ) {
+      Some(Direction::Horizontal) => Size::new(sizes.iter().map( |size| size.w ).sum::<NumType>() + spacing, max_h),
+      Some(Direction::Vertical)   => Size::new(max_w, sizes.iter().map( |size| size.h ).sum::<NumType>() + spacing),
+      None                        => Size::new(max_w, max_h)
+    }
+  }
+
+  /// Returns the panel's `Rect` when it is anchored in `area`, inside of its margin.
+  fn place_in(&self, area: &Rect, size: Size) -> Rect {
+    let area = self.margin.shrink(area);
+    let anchored = Rect::new(area.origin_point(&self.anchor), size, self.anchor.clone());
+    Rect::new(anchored.top_left(), anchored.size().clone(), Origin::TopLeft)
+  }
+
+  /// Places the panel and its children on a screen of the passed size.
+  pub fn resolve(&self, screen: &Size) -> Layout {
+    self.resolve_in(&Rect::new(Point::new(0.0, 0.0), screen.clone(), Origin::TopLeft))
+  }
+
+  /// Places the panel and its children in `area`, like a part of the screen.
+  pub fn resolve_in(&self, area: &Rect) -> Layout {
+    let mut layout = Layout { rects: Vec::new() };
+    let rect = self.place_in(area, self.size(&self.margin.shrink_size(area.size())));
+    self.place(rect, &mut layout);
+    layout
+  }
+
+  fn place(&self, rect: Rect, layout: &mut Layout) {
+    let content = self.padding.shrink(&rect);
+    layout.rects.push((self.name.clone(), rect));
+    let available = content.size().clone();
+    let stack = match self.stack {
+      Some(stack) => stack,
+      None => {
+        for child in &self.children {
+          let child_rect = child.place_in(&content, child.size(&child.margin.shrink_size(&available)));
+          child.place(child_rect, layout);
+        }
+        return;
+      }
+    };
+    // Every child gets a slot as large as it is (with margins) along the stack, and as large as the panel across it
+    let top_left = content.top_left();
+    let mut position = 0.0;
+    for child in &self.children {
+      let size = child.size(&child.margin.shrink_size(&available));
+      let outer = child.margin.grow(&size);
+      let slot = match stack.direction {
+        Direction::Horizontal => Rect::new(
+          Point::new(top_left.x + position, top_left.y), Size::new(outer.w, available.h), Origin::TopLeft
+        ),
+        Direction::Vertical => Rect::new(
+          Point::new(top_left.x, top_left.y + position), Size::new(available.w, outer.h), Origin::TopLeft
+        )
+      };
+      position += stack.spacing + match stack.direction {
+        Direction::Horizontal => outer.w,
+        Direction::Vertical   => outer.h
+      };
+      let child_rect = child.place_in(&slot, size);
+      child.place(child_rect, layout);
+    }
+  }
+}
+
+/// The `Rect`s of a resolved `Panel` tree, in screen coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+  rects: Vec<(Option<String>, Rect)>
+}
+
+impl Layout {
+  /// Returns the `Rect` of the first panel with the name.
+  pub fn get(&self, name: &str) -> Option<&Rect> {
+    self.rects.iter()
+      .find( |(panel, _)| panel.as_ref().map_or(false, |panel| panel == name ) )
+      .map( |(_, rect)| rect )
+  }
+
+  /// Returns all panels' names and `Rect`s, parents before their children.
+  pub fn rects(&self) -> &[(Option<String>, Rect)] {
+    &self.rects
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,99 @@
+use super::*;
+
+fn screen() -> Size {
+  Size::new(640.0, 480.0)
+}
+
+fn px(w: NumType, h: NumType) -> Panel {
+  Panel::new(Length::Px(w), Length::Px(h))
+}
+
+#[test]
+fn panels_are_anchored_with_margins() {
+  let margin = Edges::new(10.0, 20.0, 30.0, 40.0);
+  let expected = [
+    (Origin::TopLeft,      Point::new(40.0,  10.0)),
+    (Origin::TopCenter,    Point::new(310.0, 10.0)),
+    (Origin::TopRight,     Point::new(580.0, 10.0)),
+    (Origin::CenterLeft,   Point::new(40.0,  220.0)),
+    (Origin::Center,       Point::new(310.0, 220.0)),
+    (Origin::CenterRight,  Point::new(580.0, 220.0)),
+    (Origin::BottomLeft,   Point::new(40.0,  430.0)),
+    (Origin::BottomCenter, Point::new(310.0, 430.0)),
+    (Origin::BottomRight,  Point::new(580.0, 430.0))
+  ];
+  for (anchor, top_left) in expected.iter().cloned() {
+    let panel = px(40.0, 20.0).named("panel").anchored(anchor.clone()).with_margin(margin);
+    let layout = panel.resolve(&screen());
+    assert_eq!(layout.get("panel"), Some(&Rect::new(top_left, Size::new(40.0, 20.0), Origin::TopLeft)), "{:?}", anchor);
+  }
+}
+
+#[test]
+fn layouts_follow_the_screen_size() {
+  let hud = Panel::fill()
+    .with(px(60.0, 10.0).named("score").anchored(Origin::TopRight).with_margin(Edges::all(5.0)));
+  assert_eq!(hud.resolve(&screen()).get("score").unwrap().top_left(), Point::new(575.0, 5.0));
+  assert_eq!(hud.resolve(&Size::new(320.0, 240.0)).get("score").unwrap().top_left(), Point::new(255.0, 5.0));
+  let area = Rect::new_simple(100.0, 100.0, 200.0, 100.0);
+  assert_eq!(hud.resolve_in(&area).get("score").unwrap().top_left(), Point::new(235.0, 105.0));
+}
+
+#[test]
+fn percentages_refer_to_the_parent_content() {
+  let hud = Panel::fill()
+    .with_padding(Edges::symmetric(10.0, 20.0))
+    .with(Panel::new(Length::Percent(50.0), Length::Percent(10.0)).named("bar").anchored(Origin::BottomCenter))
+    .with(Panel::fill().named("inset").with_margin(Edges::all(5.0)));
+  let layout = hud.resolve(&screen());
+  assert_eq!(layout.get("bar"), Some(&Rect::new_simple(170.0, 424.0, 300.0, 46.0)));
+  assert_eq!(layout.get("inset"), Some(&Rect::new_simple(25.0, 15.0, 590.0, 450.0)));
+}
+
+#[test]
+fn horizontal_stacks_fit_their_children() {
+  let hud = Panel::fill().with(
+    Panel::default().named("hearts").anchored(Origin::TopRight)
+      .with_margin(Edges::all(8.0))
+      .with_padding(Edges::all(2.0))
+      .stacked(Direction::Horizontal, 4.0)
+      .with(px(16.0, 16.0).named("one"))
+      .with(px(16.0, 8.0).named("two").anchored(Origin::BottomLeft))
+      .with(px(16.0, 16.0).named("three").with_margin(Edges::new(0.0, 0.0, 0.0, 6.0)))
+  );
+  let layout = hud.resolve(&screen());
+  // 2 + 16 + 4 + 16 + 4 + 6 + 16 + 2 pixels wide
+  assert_eq!(layout.get("hearts"), Some(&Rect::new_simple(566.0, 8.0, 66.0, 20.0)));
+  assert_eq!(layout.get("one"),    Some(&Rect::new_simple(568.0, 10.0, 16.0, 16.0)));
+  assert_eq!(layout.get("two"),    Some(&Rect::new_simple(588.0, 18.0, 16.0, 8.0)));
+  assert_eq!(layout.get("three"),  Some(&Rect::new_simple(614.0, 10.0, 16.0, 16.0)));
+}
+
+#[test]
+fn vertical_stacks_align_across() {
+  let menu = Panel::new(Length::Px(100.0), Length::Auto)
+    .anchored(Origin::Center)
+    .stacked(Direction::Vertical, 10.0)
+    .with(Panel::new(Length::Percent(100.0), Length::Px(20.0)).named("title"))
+    .with(px(50.0, 20.0).named("left"))
+    .with(px(50.0, 20.0).named("center").anchored(Origin::TopCenter))
+    .with(px(50.0, 20.0).named("right").anchored(Origin::CenterRight));
+  let layout = menu.resolve(&screen());
+  assert_eq!(layout.rects()[0], (None, Rect::new_simple(270.0, 185.0, 100.0, 110.0)));
+  assert_eq!(layout.get("title"),  Some(&Rect::new_simple(270.0, 185.0, 100.0, 20.0)));
+  assert_eq!(layout.get("left"),   Some(&Rect::new_simple(270.0, 215.0, 50.0, 20.0)));
+  assert_eq!(layout.get("center"), Some(&Rect::new_simple(295.0, 245.0, 50.0, 20.0)));
+  assert_eq!(layout.get("right"),  Some(&Rect::new_simple(320.0, 275.0, 50.0, 20.0)));
+}
+
+#[test]
+fn rects_are_listed_parents_first() {
+  let hud = Panel::fill().named("hud")
+    .with(Panel::default().named("box").with(px(10.0, 10.0).named("inner")))
+    .with(px(5.0, 5.0).named("other"));
+  let layout = hud.resolve(&screen());
+  let names = layout.rects().iter().map( |(name, _)| name.clone().unwrap() ).collect::<Vec<String>>();
+  assert_eq!(names, vec!["hud", "box", "inner", "other"]);
+  assert_eq!(layout.get("box").unwrap().size(), &Size::new(10.0, 10.0));
+  assert_eq!(layout.get("missing"), None);
+}
@@ -0,0 +1,222 @@
+use ::ggez::{
+  GameResult,
+  Context
+};
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::text::DrawText;
+use super::{
+  Nav,
+  UiEvent,
+  UiInput,
+  UiStyle,
+  Widget
+};
+
+/// An event of one of a `Menu`'s widgets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuEvent {
+  /// The index of the widget, in the order it was added.
+  pub index: usize,
+  pub event: UiEvent
+}
+
+/// Widgets of equal size, stacked vertically, with up and down moving the focus between them.
+/// The mouse focuses the widget under the cursor when it moves.
+/// # Example
+///   ```
+///   use noframe::geo::prelude::*;
+///   use noframe::ui::{ Button, Label, Menu, MenuEvent, Nav, UiEvent, UiInput };
+///
+///   let mut menu = Menu::new(Point::new(320.0, 240.0), Origin::Center, Size::new(200.0, 30.0))
+///     .with(Label::new("Paused"))
+///     .with(Button::new("Continue"))
+///     .with(Button::new("Quit"));
+///   assert_eq!(menu.focus(), Some(1));
+///   menu.update(&UiInput::new().with_nav(Nav::Down));
+///   let event = menu.update(&UiInput::new().with_nav(Nav::Activate));
+///   assert_eq!(event, Some(MenuEvent { index: 2, event: UiEvent::Clicked }));
+///   ```
+pub struct Menu {
+  widgets:      Vec<Box<dyn Widget>>,
+  focus:        Option<usize>,
+  point:        Point,
+  origin:       Origin,
+  item_size:    Size,
+  spacing:      NumType,
+  wrap:         bool,
+  last_pointer: Option<Point>
+}
+
+impl Menu {
+  /// Returns an empty menu, whose `origin` is at `point`; every widget gets the size `item_size`.
+  pub fn new(point: Point, origin: Origin, item_size: Size) -> Self {
+    Self {
+      widgets:      Vec::new(),
+      focus:        None,
+      point,
+      origin,
+      item_size,
+      spacing:      4.0,
+      wrap:         true,
+      last_pointer: None
+    }
+  }
+
+  /// Sets the vertical space between widgets.
+  pub fn with_spacing(mut self, spacing: NumType) -> Self {
+    self.spacing = spacing;
+    self.layout();
+    self
+  }
+
+  /// Whether moving the focus past the last widget goes back to the first one, and the other way round.
+  pub fn with_wrap(mut self, wrap: bool) -> Self {
+    self.wrap = wrap;
+    self
+  }
+
+  pub fn with<W: Widget + 'static>(mut self, widget: W) -> Self {
+    self.push(widget);
+    self
+  }
+
+  /// Adds a widget at the bottom; the first focusable widget gets the focus.
+  pub fn push<W: Widget + 'static>(&mut self, widget: W) {
+    self.widgets.push(Box::new(widget));
+    self.layout();
+    if self.focus.is_none() {
+      self.focus = self.step_focus(None, 1);
+      self.apply_focus();
+    }
+  }
+
+  pub fn widgets(&self) -> &[Box<dyn Widget>] {
+    &self.widgets
+  }
+
+  pub fn widget(&self, index: usize) -> Option<&dyn Widget> {
+    self.widgets.get(index).map( |widget| widget.as_ref() )
+  }
+
+  pub fn widget_mut(&mut self, index: usize) -> Option<&mut Box<dyn Widget>> {
+    self.widgets.get_mut(index)
+  }
+
+  pub fn focus(&self) -> Option<usize> {
+    self.focus
+  }
+
+  /// Focuses the widget at `index`, if it is focusable.
+  pub fn set_focus(&mut self, index: usize) {
+    if self.widgets.get(index).map_or(false, |widget| widget.is_focusable() ) {
+      self.focus = Some(index);
+      self.apply_focus();
+    }
+  }
+
+  /// Moves the menu, so its `origin` is at `point`.
+  pub fn move_to(&mut self, point: &Point) {
+    self.point.set(point);
+    self.layout();
+  }
+
+  /// Returns the `Rect` around all widgets.
+  pub fn rect(&self) -> Rect {
+    let count = self.widgets.len() as NumType;
+    let height = if self.widgets.is_empty() {
+      0.0
+    } else {
+      self.item_size.h * count + self.spacing * (count - 1.0)
+    };
+    Rect::new(self.point.clone(), Size::new(self.item_size.w, height), self.origin.clone())
+  }
+
+  fn layout(&mut self) {
+    let top_left = self.rect().top_left();
+    let advance = self.item_size.h + self.spacing;
+    for (index, widget) in self.widgets.iter_mut().enumerate() {
+      widget.set_rect(Rect::new(
+        Point::new(top_left.x, top_left.y + advance * index as NumType),
+        self.item_size.clone(),
+        Origin::TopLeft
+      ));
+    }
+  }
+
+  /// Returns the next focusable widget from `from` in the `direction` (`1` or `-1`).
+  fn step_focus(&self, from: Option<usize>, direction: isize) -> Option<usize> {
+    let count = self.widgets.len() as isize;
+    let mut index = match from {
+      Some(from) => from as isize,
+      None       => if direction > 0 { -1 } else { count }
+    };
+    for _ in 0 .. count {
+      index += direction;
+      if index < 0 || index >= count {
+        if !self.wrap && from.is_some() {
+          return from;
+        }
+        index = (index + count) % count;
+      }
+      if self.widgets[index as usize].is_focusable() {
+        return Some(index as usize);
+      }
+    }
+    None
+  }
+
+  fn apply_focus(&mut self) {
+    let focus = self.focus;
+    for (index, widget) in self.widgets.iter_mut().enumerate() {
+      widget.state_mut().focused = Some(index) == focus;
+    }
+  }
+
+  /// Moves the focus and updates all widgets; returns the first event of a widget.
+  pub fn update(&mut self, input: &UiInput) -> Option<MenuEvent> {
+    if self.focus.map_or(false, |focus| !self.widgets[focus].is_focusable() ) {
+      self.focus = None;
+    }
+    for nav in &input.nav {
+      match nav {
+        Nav::Up   => self.focus = self.step_focus(self.focus, -1),
+        Nav::Down => self.focus = self.step_focus(self.focus, 1),
+        _         => ()
+      }
+    }
+    let pointer_moved = input.pointer.is_some() && input.pointer != self.last_pointer;
+    if pointer_moved || input.pointer_down {
+      let hovered = self.widgets.iter().position( |widget| widget.is_focusable() && input.is_over(widget.rect()) );
+      if hovered.is_some() {
+        self.focus = hovered;
+      }
+    }
+    self.last_pointer = input.pointer.clone();
+    self.apply_focus();
+
+    let mut result = None;
+    for (index, widget) in self.widgets.iter_mut().enumerate() {
+      if let Some(event) = widget.update(input) {
+        result = result.or(Some(MenuEvent { index, event }));
+      }
+    }
+    result
+  }
+
+  pub fn draw(&self, ctx: &mut Context, font: &mut dyn DrawText, style: &UiStyle) -> GameResult<()> {
+    for widget in &self.widgets {
+      widget.draw(ctx, font, style)?;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,290 @@
+//! Widgets for title screens, pause menus and options screens.
+//!
+//! Widgets are retained: a `Button`, `Toggle` or `Slider` keeps its own state between frames,
+//! is updated once per frame with a `UiInput` and returns a `UiEvent` when it was used.
+//! A `Menu` stacks widgets vertically and moves the keyboard or gamepad focus between them.
+//!
+//! `UiInput` is a snapshot of the input of one frame; it is usually made from the `InputManager`,
+//! but can also be built by hand, which is how gamepad input gets in and how widgets are tested.
+//! Widgets are drawn in screen space: their `Rect`s are window coordinates and the `Camera` is not involved,
+//! so draw them after the world.
+
+use ::ggez::{
+  GameResult,
+  Context,
+  event::{
+    Keycode,
+    MouseButton
+  },
+  graphics::{
+    self,
+    DrawMode
+  }
+};
+
+use ::geo::{
+  NumType,
+  point::Point,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::color::{
+  self,
+  Color
+};
+use ::input_manager::InputManager;
+use ::text::{
+  self,
+  DrawText,
+  TextStyle
+};
+
+pub mod widgets;
+pub mod menu;
+pub mod layout;
+
+pub use self::widgets::{
+  Label,
+  Button,
+  Toggle,
+  Slider
+};
+pub use self::layout::{
+  Edges,
+  Layout,
+  Panel
+};
+pub use self::menu::{
+  Menu,
+  MenuEvent
+};
+
+/// A direction or action from the keyboard or a gamepad, for the focused widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nav {
+  Up,
+  Down,
+  Left,
+  Right,
+  Activate
+}
+
+/// The keys which are turned into `Nav`s by `UiInput::from_input`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiKeys {
+  pub up:       Vec<Keycode>,
+  pub down:     Vec<Keycode>,
+  pub left:     Vec<Keycode>,
+  pub right:    Vec<Keycode>,
+  pub activate: Vec<Keycode>
+}
+
+impl Default for UiKeys {
+  /// Arrow keys and WASD, and return or space to activate.
+  fn default() -> Self {
+    Self {
+      up:       vec![Keycode::Up,    Keycode::W],
+      down:     vec![Keycode::Down,  Keycode::S],
+      left:     vec![Keycode::Left,  Keycode::A],
+      right:    vec![Keycode::Right, Keycode::D],
+      activate: vec![Keycode::Return, Keycode::Space]
+    }
+  }
+}
+
+/// The input of one frame, as seen by widgets.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UiInput {
+  /// The mouse cursor, in screen coordinates.
+  pub pointer:      Option<Point>,
+  /// The left mouse button went down this frame.
+  pub pointer_down: bool,
+  /// The left mouse button is held down.
+  pub pointer_held: bool,
+  /// The left mouse button was released this frame.
+  pub pointer_up:   bool,
+  pub nav:          Vec<Nav>
+}
+
+impl UiInput {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Reads the mouse state and the navigation keys pressed this frame from the `InputManager`.
+  /// Call this before `InputManager::update` clears the frame's input.
+  pub fn from_input(input: &InputManager, keys: &UiKeys) -> Self {
+    let mut nav = Vec::new();
+    for key in input.keys_down() {
+      let pressed = [
+        (&keys.up,       Nav::Up),
+        (&keys.down,     Nav::Down),
+        (&keys.left,     Nav::Left),
+        (&keys.right,    Nav::Right),
+        (&keys.activate, Nav::Activate)
+      ].iter().find( |(keys, _)| keys.contains(key) ).map( |&(_, nav)| nav );
+      if let Some(pressed) = pressed {
+        nav.push(pressed);
+      }
+    }
+    Self {
+      pointer:      Some(input.mouse_position().clone()),
+      pointer_down: input.mouse_down().contains(&MouseButton::Left),
+      pointer_held: input.mouse_pressed().contains(&MouseButton::Left),
+      pointer_up:   input.mouse_up().contains(&MouseButton::Left),
+      nav
+    }
+  }
+
+  /// Adds a `Nav`, for example from a gamepad button in ggez's `controller_button_down_event`.
+  pub fn with_nav(mut self, nav: Nav) -> Self {
+    self.nav.push(nav);
+    self
+  }
+
+  /// Moves the pointer, for example from window to virtual screen coordinates.
+  pub fn with_pointer(mut self, pointer: Option<Point>) -> Self {
+    self.pointer = pointer;
+    self
+  }
+
+  pub fn has_nav(&self, nav: Nav) -> bool {
+    self.nav.contains(&nav)
+  }
+
+  /// Returns `true` if the pointer is inside of the `Rect`.
+  pub fn is_over(&self, rect: &Rect) -> bool {
+    self.pointer.as_ref().map_or(false, |pointer| rect.intersects_point(pointer) )
+  }
+}
+
+/// What happened to a widget during its update.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UiEvent {
+  Clicked,
+  Toggled(bool),
+  Changed(NumType)
+}
+
+/// The interaction state of a widget, which decides how it is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WidgetState {
+  pub hovered: bool,
+  /// The mouse button went down on the widget and wasn't released yet.
+  pub pressed: bool,
+  /// The widget receives `Nav`s.
+  pub focused: bool
+}
+
+/// Colors and spacing of widgets.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UiStyle {
+  pub text:          Color,
+  pub disabled_text: Color,
+  pub background:    Color,
+  pub hovered:       Color,
+  pub pressed:       Color,
+  /// The outline of the focused widget.
+  pub focus:         Color,
+  /// A `Slider`'s filled part, and a `Toggle`'s box when it is on.
+  pub accent:        Color,
+  /// The space between a widget's edge and its text.
+  pub padding:       NumType,
+  /// Where text is aligned in buttons, toggles and sliders.
+  pub align:         Origin
+}
+
+impl Default for UiStyle {
+  fn default() -> Self {
+    Self {
+      text:          color::WHITE,
+      disabled_text: [0.5, 0.5, 0.5, 1.0],
+      background:    [0.15, 0.15, 0.2, 0.9],
+      hovered:       [0.25, 0.25, 0.3, 0.9],
+      pressed:       [0.1, 0.1, 0.15, 0.9],
+      focus:         [1.0, 0.8, 0.2, 1.0],
+      accent:        [0.3, 0.5, 0.9, 1.0],
+      padding:       4.0,
+      align:         Origin::Center
+    }
+  }
+}
+
+impl UiStyle {
+  /// Returns the background color for the state.
+  pub fn background_for(&self, state: &WidgetState) -> Color {
+    if state.pressed {
+      self.pressed
+    } else if state.hovered {
+      self.hovered
+    } else {
+      self.background
+    }
+  }
+}
+
+/// Something which is updated and drawn as part of a UI, like a `Button` or a `Menu` entry.
+pub trait Widget {
+  fn rect(&self) -> &Rect;
+  fn set_rect(&mut self, rect: Rect);
+  fn state(&self) -> &WidgetState;
+  fn state_mut(&mut self) -> &mut WidgetState;
+
+  /// Returns `false` for widgets which can't be used, like labels and disabled buttons;
+  /// a `Menu` skips them when moving the focus.
+  fn is_focusable(&self) -> bool {
+    true
+  }
+
+  fn update(&mut self, input: &UiInput) -> Option<UiEvent>;
+  fn draw(&self, ctx: &mut Context, font: &mut dyn DrawText, style: &UiStyle) -> GameResult<()>;
+}
+
+/// Returns the `Rect` made smaller by `padding` on every side.
+pub fn inset(rect: &Rect, padding: NumType) -> Rect {
+  Edges::all(padding).shrink(rect)
+}
+
+fn ggez_rect(rect: &Rect) -> graphics::Rect {
+  let top_left = rect.top_left();
+  let size = rect.size();
+  [
+    top_left.x.round(),
+    top_left.y.round(),
+    size.w.round(),
+    size.h.round()
+  ].into()
+}
+
+/// Fills the `Rect` with the color.
+pub fn fill(ctx: &mut Context, rect: &Rect, color: Color) -> GameResult<()> {
+  graphics::set_color(ctx, color.into())?;
+  graphics::rectangle(ctx, DrawMode::Fill, ggez_rect(rect))?;
+  graphics::set_color(ctx, color::WHITE.into())
+}
+
+/// Draws a line along the edge of the `Rect`.
+pub fn outline(ctx: &mut Context, rect: &Rect, color: Color, width: NumType) -> GameResult<()> {
+  graphics::set_color(ctx, color.into())?;
+  graphics::rectangle(ctx, DrawMode::Line(width), ggez_rect(rect))?;
+  graphics::set_color(ctx, color::WHITE.into())
+}
+
+/// Draws a single line of text in the `Rect`.
+pub fn label(ctx: &mut Context, font: &mut dyn DrawText, text: &str, rect: &Rect, align: &Origin, color: Color) -> GameResult<()> {
+  text::draw(ctx, font, text, rect, &TextStyle {
+    align: align.clone(),
+    wrap:  false,
+    color,
+    .. TextStyle::default()
+  })?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,205 @@
+use ::geo::size::Size;
+use ::ggez::event::Mod;
+
+use super::*;
+
+fn rect() -> Rect {
+  Rect::new_simple(10.0, 10.0, 100.0, 20.0)
+}
+
+fn at(x: NumType, y: NumType) -> UiInput {
+  UiInput::new().with_pointer(Some(Point::new(x, y)))
+}
+
+fn press(x: NumType, y: NumType) -> UiInput {
+  UiInput { pointer_down: true, pointer_held: true, .. at(x, y) }
+}
+
+fn hold(x: NumType, y: NumType) -> UiInput {
+  UiInput { pointer_held: true, .. at(x, y) }
+}
+
+fn release(x: NumType, y: NumType) -> UiInput {
+  UiInput { pointer_up: true, .. at(x, y) }
+}
+
+fn nav(nav: Nav) -> UiInput {
+  UiInput::new().with_nav(nav)
+}
+
+fn menu() -> Menu {
+  Menu::new(Point::new(0.0, 0.0), Origin::TopLeft, Size::new(100.0, 20.0))
+    .with_spacing(10.0)
+    .with(Label::new("Options"))
+    .with(Toggle::new("Fullscreen", false))
+    .with(Slider::new("Volume", 50.0, 0.0, 100.0))
+    .with(Button::new("Online").with_enabled(false))
+    .with(Button::new("Back"))
+}
+
+#[test]
+fn input_is_read_from_the_input_manager() {
+  let mut input = InputManager::new();
+  input.key_down(Keycode::S, Mod::empty(), false);
+  input.key_down(Keycode::Return, Mod::empty(), false);
+  input.key_down(Keycode::X, Mod::empty(), false);
+  input.mouse_motion(5, 6);
+  input.add_mouse_down(MouseButton::Left, 7, 8);
+  let ui = UiInput::from_input(&input, &UiKeys::default());
+  assert_eq!(ui.nav, vec![Nav::Down, Nav::Activate]);
+  assert_eq!(ui.pointer, Some(Point::new(7.0, 8.0)));
+  assert!(ui.pointer_down && ui.pointer_held && !ui.pointer_up);
+
+  input.update();
+  input.add_mouse_up(MouseButton::Left, 7, 8);
+  let ui = UiInput::from_input(&input, &UiKeys::default());
+  assert!(ui.nav.is_empty());
+  assert!(!ui.pointer_down && !ui.pointer_held && ui.pointer_up);
+}
+
+#[test]
+fn buttons_are_clicked_by_releasing_over_them() {
+  let mut button = Button::new("Start").with_rect(rect());
+  assert_eq!(button.update(&at(50.0, 20.0)), None);
+  assert!(button.state().hovered);
+  assert_eq!(button.update(&press(50.0, 20.0)), None);
+  assert_eq!(button.update(&hold(60.0, 20.0)), None);
+  assert!(button.state().pressed);
+  assert_eq!(button.update(&release(60.0, 20.0)), Some(UiEvent::Clicked));
+  assert!(!button.state().pressed);
+}
+
+#[test]
+fn buttons_are_not_clicked_when_released_elsewhere() {
+  let mut button = Button::new("Start").with_rect(rect());
+  button.update(&press(50.0, 20.0));
+  assert_eq!(button.update(&release(200.0, 20.0)), None);
+  // Pressing outside and releasing over the button doesn't click either
+  button.update(&press(200.0, 20.0));
+  assert_eq!(button.update(&release(50.0, 20.0)), None);
+}
+
+#[test]
+fn focused_buttons_are_activated() {
+  let mut button = Button::new("Start").with_rect(rect());
+  assert_eq!(button.update(&nav(Nav::Activate)), None);
+  button.state_mut().focused = true;
+  assert_eq!(button.update(&nav(Nav::Activate)), Some(UiEvent::Clicked));
+}
+
+#[test]
+fn disabled_buttons_do_nothing() {
+  let mut button = Button::new("Start").with_rect(rect()).with_enabled(false);
+  assert!(!button.is_focusable());
+  button.update(&press(50.0, 20.0));
+  assert_eq!(button.update(&release(50.0, 20.0)), None);
+  assert_eq!(button.state(), &WidgetState::default());
+}
+
+#[test]
+fn toggles_flip() {
+  let mut toggle = Toggle::new("Fullscreen", false).with_rect(rect());
+  toggle.update(&press(50.0, 20.0));
+  assert_eq!(toggle.update(&release(50.0, 20.0)), Some(UiEvent::Toggled(true)));
+  toggle.state_mut().focused = true;
+  assert_eq!(toggle.update(&nav(Nav::Activate)), Some(UiEvent::Toggled(false)));
+  assert!(!toggle.value);
+}
+
+#[test]
+fn sliders_are_dragged() {
+  let mut slider = Slider::new("Volume", 50.0, 0.0, 100.0).with_rect(rect()).with_step(0.0);
+  assert_eq!(slider.update(&press(35.0, 20.0)), Some(UiEvent::Changed(25.0)));
+  assert_eq!(slider.update(&hold(85.0, 20.0)), Some(UiEvent::Changed(75.0)));
+  // Dragging past the end clamps the value
+  assert_eq!(slider.update(&hold(500.0, 20.0)), Some(UiEvent::Changed(100.0)));
+  assert_eq!(slider.update(&release(500.0, 20.0)), None);
+  assert_eq!(slider.update(&at(10.0, 20.0)), None);
+  assert_eq!(slider.value(), 100.0);
+}
+
+#[test]
+fn sliders_are_stepped() {
+  let mut slider = Slider::new("Volume", 43.0, 0.0, 100.0).with_rect(rect()).with_step(25.0);
+  assert_eq!(slider.value(), 50.0);
+  assert_eq!(slider.update(&nav(Nav::Right)), None);
+  slider.state_mut().focused = true;
+  assert_eq!(slider.update(&nav(Nav::Right)), Some(UiEvent::Changed(75.0)));
+  slider.update(&nav(Nav::Right));
+  assert_eq!(slider.update(&nav(Nav::Right)), None);
+  assert_eq!(slider.value(), 100.0);
+  assert_eq!(slider.update(&nav(Nav::Left)), Some(UiEvent::Changed(75.0)));
+  assert_eq!(slider.fraction(), 0.75);
+}
+
+#[test]
+fn menus_are_stacked() {
+  let menu = menu();
+  assert_eq!(menu.rect().size(), &Size::new(100.0, 140.0));
+  let tops = menu.widgets().iter().map( |widget| widget.rect().top_left().y ).collect::<Vec<NumType>>();
+  assert_eq!(tops, vec![0.0, 30.0, 60.0, 90.0, 120.0]);
+
+  let centered = Menu::new(Point::new(100.0, 100.0), Origin::Center, Size::new(100.0, 20.0))
+    .with_spacing(0.0)
+    .with(Button::new("A"))
+    .with(Button::new("B"));
+  assert_eq!(centered.widget(0).unwrap().rect().top_left(), Point::new(50.0, 80.0));
+  assert_eq!(centered.widget(1).unwrap().rect().top_left(), Point::new(50.0, 100.0));
+}
+
+#[test]
+fn focus_skips_labels_and_disabled_widgets() {
+  let mut menu = menu();
+  assert_eq!(menu.focus(), Some(1));
+  assert!(menu.widget(1).unwrap().state().focused);
+  menu.update(&nav(Nav::Down));
+  assert_eq!(menu.focus(), Some(2));
+  menu.update(&nav(Nav::Down));
+  assert_eq!(menu.focus(), Some(4));
+  menu.update(&nav(Nav::Down));
+  assert_eq!(menu.focus(), Some(1));
+  menu.update(&nav(Nav::Up));
+  assert_eq!(menu.focus(), Some(4));
+  assert!(!menu.widget(1).unwrap().state().focused);
+  assert!(menu.widget(4).unwrap().state().focused);
+}
+
+#[test]
+fn focus_stops_at_the_ends_without_wrapping() {
+  let mut menu = menu().with_wrap(false);
+  menu.update(&nav(Nav::Up));
+  assert_eq!(menu.focus(), Some(1));
+  menu.set_focus(4);
+  menu.update(&nav(Nav::Down));
+  assert_eq!(menu.focus(), Some(4));
+  // Labels can't be focused
+  menu.set_focus(0);
+  assert_eq!(menu.focus(), Some(4));
+}
+
+#[test]
+fn only_the_focused_widget_gets_navigation() {
+  let mut menu = menu();
+  assert_eq!(menu.update(&nav(Nav::Activate)), Some(MenuEvent { index: 1, event: UiEvent::Toggled(true) }));
+  menu.update(&nav(Nav::Down));
+  assert_eq!(menu.update(&nav(Nav::Right)), Some(MenuEvent { index: 2, event: UiEvent::Changed(60.0) }));
+}
+
+#[test]
+fn the_mouse_focuses_when_it_moves() {
+  let mut menu = menu();
+  menu.update(&at(50.0, 130.0));
+  assert_eq!(menu.focus(), Some(4));
+  // A resting mouse doesn't take the focus back from the keyboard
+  menu.update(&UiInput { nav: vec![Nav::Up], .. at(50.0, 130.0) });
+  assert_eq!(menu.focus(), Some(2));
+  menu.update(&at(50.0, 130.0));
+  assert_eq!(menu.focus(), Some(2));
+  // Hovering over a disabled widget keeps the focus
+  menu.update(&at(50.0, 100.0));
+  assert_eq!(menu.focus(), Some(2));
+
+  menu.update(&press(50.0, 130.0));
+  let event = menu.update(&release(50.0, 130.0));
+  assert_eq!(event, Some(MenuEvent { index: 4, event: UiEvent::Clicked }));
+}
@@ -0,0 +1,436 @@
+use ::ggez::{
+  GameResult,
+  Context
+};
+
+use ::geo::{
+  NumType,
+  point::Point,
+  size::Size,
+  rect::Rect,
+  mask::{
+    Mask,
+    misc::Origin
+  }
+};
+use ::text::DrawText;
+use super::{
+  Nav,
+  UiEvent,
+  UiInput,
+  UiStyle,
+  Widget,
+  WidgetState,
+  fill,
+  inset,
+  label,
+  outline
+};
+
+fn empty_rect() -> Rect {
+  Rect::new_simple(0.0, 0.0, 0.0, 0.0)
+}
+
+/// Updates the hovered and pressed state; returns `true` if the widget was clicked,
+/// which is when the mouse button is released over the widget it went down on.
+fn click(state: &mut WidgetState, rect: &Rect, input: &UiInput) -> bool {
+  state.hovered = input.is_over(rect);
+  if input.pointer_down && state.hovered {
+    state.pressed = true;
+  }
+  let mut clicked = false;
+  if input.pointer_up {
+    clicked = state.pressed && state.hovered;
+    state.pressed = false;
+  } else if !input.pointer_held && !input.pointer_down {
+    state.pressed = false;
+  }
+  clicked || (state.focused && input.has_nav(Nav::Activate))
+}
+
+fn draw_frame(ctx: &mut Context, rect: &Rect, state: &WidgetState, style: &UiStyle) -> GameResult<()> {
+  fill(ctx, rect, style.background_for(state))?;
+  if state.focused {
+    outline(ctx, rect, style.focus, 2.0)?;
+  }
+  Ok(())
+}
+
+/// Text which can't be interacted with, like a menu's title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+  pub text:  String,
+  pub align: Origin,
+  rect:      Rect,
+  state:     WidgetState
+}
+
+impl Label {
+  pub fn new(text: &str) -> Self {
+    Self {
+      text:  text.to_string(),
+      align: Origin::Center,
+      rect:  empty_rect(),
+      state: WidgetState::default()
+    }
+  }
+
+  pub fn with_rect(mut self, rect: Rect) -> Self {
+    self.rect = rect;
+    self
+  }
+
+  pub fn with_align(mut self, align: Origin) -> Self {
+    self.align = align;
+    self
+  }
+}
+
+impl Widget for Label {
+  fn rect(&self) -> &Rect {
+    &self.rect
+  }
+
+  fn set_rect(&mut self, rect: Rect) {
+    self.rect = rect;
+  }
+
+  fn state(&self) -> &WidgetState {
+    &self.state
+  }
+
+  fn state_mut(&mut self) -> &mut WidgetState {
+    &mut self.state
+  }
+
+  fn is_focusable(&self) -> bool {
+    false
+  }
+
+  fn update(&mut self, _input: &UiInput) -> Option<UiEvent> {
+    None
+  }
+
+  fn draw(&self, ctx: &mut Context, font: &mut dyn DrawText, style: &UiStyle) -> GameResult<()> {
+    label(ctx, font, &self.text, &inset(&self.rect, style.padding), &self.align, style.text)
+  }
+}
+
+/// A button, which is clicked with the mouse or activated while focused.
+/// # Example
+///   ```
+///   use noframe::geo::prelude::*;
+///   use noframe::ui::{ Button, UiEvent, UiInput, Widget };
+///
+///   let mut button = Button::new("Start").with_rect(Rect::new_simple(0.0, 0.0, 100.0, 20.0));
+///   let pointer = Some(Point::new(50.0, 10.0));
+///   let press = UiInput { pointer: pointer.clone(), pointer_down: true, pointer_held: true, .. UiInput::new() };
+///   let release = UiInput { pointer, pointer_up: true, .. UiInput::new() };
+///   assert_eq!(button.update(&press), None);
+///   assert!(button.state().pressed);
+///   assert_eq!(button.update(&release), Some(UiEvent::Clicked));
+///   ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Button {
+  pub text:    String,
+  pub enabled: bool,
+  rect:        Rect,
+  state:       WidgetState
+}
+
+impl Button {
+  pub fn new(text: &str) -> Self {
+    Self {
+      text:    text.to_string(),
+      enabled: true,
+      rect:    empty_rect(),
+      state:   WidgetState::default()
+    }
+  }
+
+  pub fn with_rect(mut self, rect: Rect) -> Self {
+    self.rect = rect;
+    self
+  }
+
+  pub fn with_enabled(mut self, enabled: bool) -> Self {
+    self.enabled = enabled;
+    self
+  }
+}
+
+impl Widget for Button {
+  fn rect(&self) -> &Rect {
+    &self.rect
+  }
+
+  fn set_rect(&mut self, rect: Rect) {
+    self.rect = rect;
+  }
+
+  fn state(&self) -> &WidgetState {
+    &self.state
+  }
+
+  fn state_mut(&mut self) -> &mut WidgetState {
+    &mut self.state
+  }
+
+  fn is_focusable(&self) -> bool {
+    self.enabled
+  }
+
+  fn update(&mut self, input: &UiInput) -> Option<UiEvent> {
+    if !self.enabled {
+      self.state = WidgetState::default();
+      return None;
+    }
+    if click(&mut self.state, &self.rect, input) {
+      Some(UiEvent::Clicked)
+    } else {
+      None
+    }
+  }
+
+  fn draw(&self, ctx: &mut Context, font: &mut dyn DrawText, style: &UiStyle) -> GameResult<()> {
+    draw_frame(ctx, &self.rect, &self.state, style)?;
+    let color = if self.enabled { style.text } else { style.disabled_text };
+    label(ctx, font, &self.text, &inset(&self.rect, style.padding), &style.align, color)
+  }
+}
+
+/// A checkbox-like button with an on and off state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toggle {
+  pub text:    String,
+  pub value:   bool,
+  pub enabled: bool,
+  rect:        Rect,
+  state:       WidgetState
+}
+
+impl Toggle {
+  pub fn new(text: &str, value: bool) -> Self {
+    Self {
+      text:    text.to_string(),
+      value,
+      enabled: true,
+      rect:    empty_rect(),
+      state:   WidgetState::default()
+    }
+  }
+
+  pub fn with_rect(mut self, rect: Rect) -> Self {
+    self.rect = rect;
+    self
+  }
+
+  pub fn with_enabled(mut self, enabled: bool) -> Self {
+    self.enabled = enabled;
+    self
+  }
+}
+
+impl Widget for Toggle {
+  fn rect(&self) -> &Rect {
+    &self.rect
+  }
+
+  fn set_rect(&mut self, rect: Rect) {
+    self.rect = rect;
+  }
+
+  fn state(&self) -> &WidgetState {
+    &self.state
+  }
+
+  fn state_mut(&mut self) -> &mut WidgetState {
+    &mut self.state
+  }
+
+  fn is_focusable(&self) -> bool {
+    self.enabled
+  }
+
+  fn update(&mut self, input: &UiInput) -> Option<UiEvent> {
+    if !self.enabled {
+      self.state = WidgetState::default();
+      return None;
+    }
+    if click(&mut self.state, &self.rect, input) {
+      self.value = !self.value;
+      Some(UiEvent::Toggled(self.value))
+    } else {
+      None
+    }
+  }
+
+  fn draw(&self, ctx: &mut Context, font: &mut dyn DrawText, style: &UiStyle) -> GameResult<()> {
+    draw_frame(ctx, &self.rect, &self.state, style)?;
+    // A square box at the right edge, filled while the toggle is on
+    let inner = inset(&self.rect, style.padding);
+    let side = inner.size().h;
+    let top_right = inner.top_right();
+    let check = Rect::new(top_right.clone(), Size::new(side, side), Origin::TopRight);
+    outline(ctx, &check, style.text, 1.0)?;
+    if self.value {
+      fill(ctx, &inset(&check, 2.0), style.accent)?;
+    }
+    let text = Rect::new(inner.top_left(), Size::new((inner.size().w - side - style.padding).max(0.0), side), Origin::TopLeft);
+    let color = if self.enabled { style.text } else { style.disabled_text };
+    label(ctx, font, &self.text, &text, &style.align, color)
+  }
+}
+
+/// A value between `min` and `max`, which is dragged with the mouse or changed by `step` with left and right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slider {
+  pub text:    String,
+  pub enabled: bool,
+  value:       NumType,
+  min:         NumType,
+  max:         NumType,
+  step:        NumType,
+  rect:        Rect,
+  state:       WidgetState
+}
+
+impl Slider {
+  /// Returns a slider from `min` to `max`; the `step` is a tenth of the range.
+  pub fn new(text: &str, value: NumType, min: NumType, max: NumType) -> Self {
+    let mut slider = Self {
+      text:    text.to_string(),
+      enabled: true,
+      value:   min,
+      min,
+      max:     max.max(min),
+      step:    (max - min).abs() / 10.0,
+      rect:    empty_rect(),
+      state:   WidgetState::default()
+    };
+    slider.set_value(value);
+    slider
+  }
+
+  pub fn with_rect(mut self, rect: Rect) -> Self {
+    self.rect = rect;
+    self
+  }
+
+  pub fn with_enabled(mut self, enabled: bool) -> Self {
+    self.enabled = enabled;
+    self
+  }
+
+  /// Values are snapped to multiples of the `step` from `min`; with a `step` of `0.0` any value is allowed.
+  pub fn with_step(mut self, step: NumType) -> Self {
+    self.step = step.max(0.0);
+    let value = self.value;
+    self.set_value(value);
+    self
+  }
+
+  pub fn value(&self) -> NumType {
+    self.value
+  }
+
+  /// Sets the value, clamped between `min` and `max` and snapped to the `step`.
+  pub fn set_value(&mut self, value: NumType) {
+    let value = if self.step > 0.0 {
+      self.min + ((value - self.min) / self.step).round() * self.step
+    } else {
+      value
+    };
+    self.value = value.clamp(self.min, self.max);
+  }
+
+  /// Returns how far the value is between `min` and `max`, from `0.0` to `1.0`.
+  pub fn fraction(&self) -> NumType {
+    if self.max > self.min {
+      (self.value - self.min) / (self.max - self.min)
+    } else {
+      0.0
+    }
+  }
+
+  fn value_at(&self, pointer: &Point) -> NumType {
+    let left = self.rect.top_left().x;
+    let width = self.rect.size().w;
+    let fraction = if width > 0.0 { ((pointer.x - left) / width).clamp(0.0, 1.0) } else { 0.0 };
+    self.min + (self.max - self.min) * fraction
+  }
+}
+
+impl Widget for Slider {
+  fn rect(&self) -> &Rect {
+    &self.rect
+  }
+
+  fn set_rect(&mut self, rect: Rect) {
+    self.rect = rect;
+  }
+
+  fn state(&self) -> &WidgetState {
+    &self.state
+  }
+
+  fn state_mut(&mut self) -> &mut WidgetState {
+    &mut self.state
+  }
+
+  fn is_focusable(&self) -> bool {
+    self.enabled
+  }
+
+  fn update(&mut self, input: &UiInput) -> Option<UiEvent> {
+    if !self.enabled {
+      self.state = WidgetState::default();
+      return None;
+    }
+    let previous = self.value;
+    self.state.hovered = input.is_over(&self.rect);
+    if input.pointer_down && self.state.hovered {
+      self.state.pressed = true;
+    }
+    if self.state.pressed {
+      if let Some(pointer) = input.pointer.as_ref() {
+        let value = self.value_at(pointer);
+        self.set_value(value);
+      }
+      if input.pointer_up || !(input.pointer_held || input.pointer_down) {
+        self.state.pressed = false;
+      }
+    }
+    if self.state.focused {
+      let step = if self.step > 0.0 { self.step } else { (self.max - self.min) / 10.0 };
+      for nav in &input.nav {
+        let value = match nav {
+          Nav::Left  => self.value - step,
+          Nav::Right => self.value + step,
+          _          => continue
+        };
+        self.set_value(value);
+      }
+    }
+    if self.value != previous {
+      Some(UiEvent::Changed(self.value))
+    } else {
+      None
+    }
+  }
+
+  fn draw(&self, ctx: &mut Context, font: &mut dyn DrawText, style: &UiStyle) -> GameResult<()> {
+    draw_frame(ctx, &self.rect, &self.state, style)?;
+    let filled = Rect::new(
+      self.rect.top_left(),
+      Size::new(self.rect.size().w * self.fraction(), self.rect.size().h),
+      Origin::TopLeft
+    );
+    fill(ctx, &filled, style.accent)?;
+    if self.state.focused {
+      outline(ctx, &self.rect, style.focus, 2.0)?;
+    }
+    let color = if self.enabled { style.text } else { style.disabled_text };
+    label(ctx, font, &self.text, &inset(&self.rect, style.padding), &style.align, color)
+  }
+}
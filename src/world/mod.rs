@@ -0,0 +1,350 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use ::ggez::{
+  GameResult,
+  Context
+};
+
+use ::geo::{
+  rect::Rect,
+  mask::Mask
+};
+use ::entity::Entity;
+use ::camera::Camera;
+use ::events::EventBus;
+use ::triggers::Triggers;
+use ::layers::{
+  Layers,
+  LayerMatrix
+};
+
+/// A stable handle to an entity in a `World`.
+/// When an entity is despawned, its slot may be reused by a new entity,
+/// but the new entity gets a new _generation_, so old IDs never point to the wrong entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntityId {
+  index:      usize,
+  generation: u32
+}
+
+impl EntityId {
+  pub(crate) fn new(index: usize, generation: u32) -> Self {
+    Self { index, generation }
+  }
+
+  /// Returns the slot index of this ID.
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  /// Returns the generation of this ID's slot.
+  pub fn generation(&self) -> u32 {
+    self.generation
+  }
+}
+
+/// Any `Entity` which can be stored in a `World`.
+/// This trait is implemented automatically for all `'static` types implementing `Entity`,
+/// it adds the methods needed to downcast the stored trait objects back to their concrete types.
+pub trait WorldEntity: Entity + Any {
+  fn as_any(&self)         -> &dyn Any;
+  fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<E: Entity + Any> WorldEntity for E {
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+  fn as_any_mut(&mut self) -> &mut dyn Any {
+    self
+  }
+}
+
+struct Entry {
+  generation: u32,
+  /// The entity was spawned and not yet despawned; `entity` is also `None` while it is taken out by `with_mut`.
+  alive:      bool,
+  entity:     Option<Box<dyn WorldEntity>>
+}
+
+#[derive(Default)]
+struct Commands {
+  spawns:   Vec<(EntityId, Box<dyn WorldEntity>)>,
+  despawns: Vec<EntityId>,
+  free:     Vec<usize>,
+  reserved: usize
+}
+
+fn as_masks(rects: &[(EntityId, Rect)]) -> Vec<(EntityId, &dyn Mask)> {
+  rects.iter().map( |(id, rect)| (*id, rect as &dyn Mask) ).collect()
+}
+
+/// A container for heterogeneous entities.
+/// Entities are spawned and despawned through a queue, which is applied with `maintain`
+/// (`update` calls it at the end of the frame). Because the queue only needs a shared reference,
+/// entities can be spawned and despawned while another entity is being updated with `with_mut`.
+pub struct World {
+  entries:  Vec<Entry>,
+  order:    Vec<EntityId>,
+  commands: RefCell<Commands>,
+  events:   EventBus,
+  triggers: Triggers,
+  layers:   LayerMatrix
+}
+
+impl World {
+  pub fn new() -> Self {
+    Self {
+      entries:  Vec::new(),
+      order:    Vec::new(),
+      commands: RefCell::new(Commands::default()),
+      events:   EventBus::new(),
+      triggers: Triggers::new(),
+      layers:   LayerMatrix::new()
+    }
+  }
+
+  /// Returns the `World`'s `EventBus`.
+  /// Events can be published through the shared reference, for example from `Entity::update_in_world`.
+  pub fn events(&self) -> &EventBus {
+    &self.events
+  }
+
+  pub fn events_mut(&mut self) -> &mut EventBus {
+    &mut self.events
+  }
+
+  /// Returns the `LayerMatrix` used by `colliding` and `update_triggers`.
+  pub fn layer_matrix(&self) -> &LayerMatrix {
+    &self.layers
+  }
+
+  pub fn layer_matrix_mut(&mut self) -> &mut LayerMatrix {
+    &mut self.layers
+  }
+
+  /// Queues the entity to be spawned at the next `maintain` and returns its ID.
+  /// The ID is valid immediately, but the entity can only be accessed after `maintain`.
+  pub fn spawn<E: Entity + 'static>(&self, entity: E) -> EntityId {
+    let mut commands = self.commands.borrow_mut();
+    let id = if let Some(index) = commands.free.pop() {
+      EntityId::new(index, self.entries[index].generation)
+    } else {
+      let index = self.entries.len() + commands.reserved;
+      commands.reserved += 1;
+      EntityId::new(index, 0)
+    };
+    commands.spawns.push((id, Box::new(entity)));
+    id
+  }
+
+  /// Queues the entity with the given ID to be despawned at the next `maintain`.
+  pub fn despawn(&self, id: EntityId) {
+    self.commands.borrow_mut().despawns.push(id);
+  }
+
+  /// Applies all queued spawns and despawns.
+  /// Spawns are applied before despawns, so an entity spawned and despawned in the same frame
+  /// is never updated or drawn.
+  pub fn maintain(&mut self) {
+    let commands = self.commands.get_mut();
+    for (id, entity) in commands.spawns.drain(..) {
+      while self.entries.len() <= id.index {
+        self.entries.push(Entry { generation: 0, alive: false, entity: None });
+      }
+      let entry = &mut self.entries[id.index];
+      entry.alive = true;
+      entry.entity = Some(entity);
+      self.order.push(id);
+    }
+    commands.reserved = 0;
+    let mut despawned = false;
+    for id in commands.despawns.drain(..) {
+      // IDs made up or from another World may point past the entries
+      let entry = match self.entries.get_mut(id.index) {
+        Some(entry) => entry,
+        None        => continue
+      };
+      if entry.generation != id.generation || !entry.alive {
+        continue;
+      }
+      entry.alive = false;
+      entry.entity = None;
+      entry.generation += 1;
+      commands.free.push(id.index);
+      despawned = true;
+    }
+    // Despawned IDs are removed from the spawn order all at once, instead of one by one
+    if despawned {
+      let entries = &self.entries;
+      self.order.retain( |id| entries[id.index].generation == id.generation );
+    }
+  }
+
+  /// Returns `true` if the entity with the given ID has been spawned and not yet despawned.
+  pub fn is_alive(&self, id: EntityId) -> bool {
+    self.entry(id).map_or(false, |entry| entry.alive )
+  }
+
+  /// Returns the number of spawned entities.
+  pub fn len(&self) -> usize {
+    self.order.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.order.is_empty()
+  }
+
+  fn entry(&self, id: EntityId) -> Option<&Entry> {
+    self.entries.get(id.index).filter( |entry| entry.generation == id.generation )
+  }
+
+  fn entry_mut(&mut self, id: EntityId) -> Option<&mut Entry> {
+    self.entries.get_mut(id.index).filter( |entry| entry.generation == id.generation )
+  }
+
+  /// Returns the entity with the given ID.
+  pub fn get(&self, id: EntityId) -> Option<&dyn WorldEntity> {
+    self.entry(id).and_then( |entry| entry.entity.as_ref() ).map( |entity| &**entity )
+  }
+
+  /// Returns the entity with the given ID mutably.
+  pub fn get_mut(&mut self, id: EntityId) -> Option<&mut dyn WorldEntity> {
+    match self.entry_mut(id) {
+      Some(entry) => match entry.entity {
+        Some(ref mut entity) => Some(&mut **entity),
+        None                 => None
+      },
+      None => None
+    }
+  }
+
+  /// Returns the entity with the given ID, if it is of type `T`.
+  pub fn get_as<T: Entity + 'static>(&self, id: EntityId) -> Option<&T> {
+    self.get(id).and_then( |entity| entity.as_any().downcast_ref::<T>() )
+  }
+
+  /// Returns the entity with the given ID mutably, if it is of type `T`.
+  pub fn get_mut_as<T: Entity + 'static>(&mut self, id: EntityId) -> Option<&mut T> {
+    self.get_mut(id).and_then( |entity| entity.as_any_mut().downcast_mut::<T>() )
+  }
+
+  /// Iterates over all spawned entities, in the order they were spawned.
+  pub fn iter<'a>(&'a self) -> impl Iterator<Item = (EntityId, &'a dyn WorldEntity)> + 'a {
+    self.order.iter().filter_map( move |&id| self.get(id).map( |entity| (id, entity) ) )
+  }
+
+  /// Returns the IDs of all entities intersecting with the given `Mask`, in the order they were spawned.
+  /// This includes triggers; use `colliding` for collision checks.
+  pub fn intersecting<M: Mask>(&self, mask: &M) -> Vec<EntityId> {
+    self.iter()
+      .filter( |(_, entity)| mask.intersects(*entity) )
+      .map( |(id, _)| id )
+      .collect()
+  }
+
+  /// Same as `intersecting`, but only returns solid entities on layers that collide with `layers`,
+  /// according to the `World`'s `LayerMatrix`. Triggers never block movement, so they are left out.
+  ///   ```ignore
+  ///   world.with_mut_as( player_id, |player: &mut Player, world| {
+  ///     let layers = player.layers();
+  ///     player.move_while( |rect| world.colliding(rect, layers).is_empty() );
+  ///   });
+  ///   ```
+  pub fn colliding<M: Mask>(&self, mask: &M, layers: Layers) -> Vec<EntityId> {
+    self.iter()
+      .filter( |(_, entity)| {
+        !entity.is_trigger() && self.layers.collides(layers, entity.layers()) && mask.intersects(*entity)
+      })
+      .map( |(id, _)| id )
+      .collect()
+  }
+
+  /// Calls the closure with the entity with the given ID mutably and the rest of the `World` immutably.
+  /// While the closure runs, the entity is taken out of the `World`, so it won't show up in
+  /// any lookups or queries done through the passed `World` reference.
+  /// This is what makes `Movement::move_while` usable with collision checks against the other entities:
+  ///   ```ignore
+  ///   world.with_mut_as( player_id, |player: &mut Player, world| {
+  ///     player.move_while( |rect| world.intersecting(rect).is_empty() );
+  ///   });
+  ///   ```
+  pub fn with_mut<R, F>(&mut self, id: EntityId, f: F) -> Option<R>
+  where F: FnOnce(&mut dyn WorldEntity, &World) -> R {
+    let mut entity = self.entry_mut(id)?.entity.take()?;
+    let result = f(&mut *entity, self);
+    self.entries[id.index].entity = Some(entity);
+    Some(result)
+  }
+
+  /// Same as `with_mut`, but only calls the closure if the entity is of type `T`.
+  pub fn with_mut_as<T, R, F>(&mut self, id: EntityId, f: F) -> Option<R>
+  where T: Entity + 'static,
+        F: FnOnce(&mut T, &World) -> R {
+    self.with_mut(id, |entity, world| {
+      entity.as_any_mut().downcast_mut::<T>().map( |entity| f(entity, world) )
+    }).and_then( |result| result )
+  }
+
+  /// Checks all trigger entities (see `Entity::is_trigger`) against all other entities,
+  /// and publishes a `TriggerEvent` for every entity entering, staying in or exiting a trigger,
+  /// ordered by the IDs of the trigger and the entity.
+  /// Only entities on layers that trigger with the trigger's layers are reported, see `LayerMatrix`.
+  pub fn update_triggers(&mut self) {
+    let mut triggers = Vec::new();
+    let mut entities = Vec::new();
+    let mut layers   = HashMap::new();
+    for (id, entity) in self.iter() {
+      layers.insert(id, entity.layers());
+      let rect = Rect::new(entity.point().clone(), entity.size().clone(), entity.origin().clone());
+      if entity.is_trigger() {
+        triggers.push((id, rect));
+      } else {
+        entities.push((id, rect));
+      }
+    }
+    let matrix = &self.layers;
+    let events = self.triggers.update_filtered(&as_masks(&triggers), &as_masks(&entities), |trigger, entity| {
+      matrix.triggers(layers[trigger], layers[entity])
+    });
+    for event in events {
+      self.events.publish(event);
+    }
+  }
+
+  /// Calls `Entity::update_in_world` on all entities, in the order they were spawned,
+  /// then applies queued spawns and despawns, checks the triggers with `update_triggers`,
+  /// and finally updates the `EventBus`, so events published during this update can be read during the next one.
+  pub fn update(&mut self, ctx: &mut Context) -> GameResult<()> {
+    // Spawns and despawns are queued until `maintain`, so the order can't change during the loop
+    for index in 0 .. self.order.len() {
+      let id = self.order[index];
+      if let Some(result) = self.with_mut(id, |entity, world| entity.update_in_world(ctx, world)) {
+        result?;
+      }
+    }
+    self.maintain();
+    self.update_triggers();
+    self.events.update();
+    Ok(())
+  }
+
+  /// Draws all entities through the `Camera`, in the order they were spawned.
+  pub fn draw(&self, ctx: &mut Context, camera: &Camera) -> GameResult<()> {
+    for (_, entity) in self.iter() {
+      camera.draw(ctx, entity)?;
+    }
+    Ok(())
+  }
+}
+
+impl Default for World {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,248 @@
+use ::geo::prelude::*;
+use ::entity::prelude::*;
+use ::triggers::{
+  TriggerEvent,
+  TriggerPhase
+};
+use ::layers::Layers;
+use ::testing::Body;
+use super::World;
+
+struct Wall {
+  rect: Rect
+}
+
+impl Wall {
+  pub fn new(x: NumType, y: NumType) -> Self {
+    Self { rect: Rect::new_simple(x, y, 10.0, 10.0) }
+  }
+}
+
+impl Mask for Wall {
+  fn point(&self) -> &Point {
+    self.rect.point()
+  }
+  fn point_mut(&mut self) -> &mut Point {
+    self.rect.point_mut()
+  }
+  fn size(&self) -> &Size {
+    self.rect.size()
+  }
+  fn size_mut(&mut self) -> &mut Size {
+    self.rect.size_mut()
+  }
+  fn origin(&self) -> &Origin {
+    self.rect.origin()
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    self.rect.origin_mut()
+  }
+}
+
+impl Entity for Wall {}
+
+#[test]
+fn spawn_is_deferred() {
+  let mut world = World::new();
+  let id = world.spawn(Wall::new(0.0, 0.0));
+  assert!(world.get(id).is_none());
+  world.maintain();
+  assert!(world.is_alive(id));
+  assert_eq!(world.get(id).unwrap().point(), &Point::new(0.0, 0.0));
+}
+
+#[test]
+fn despawned_ids_stay_invalid() {
+  let mut world = World::new();
+  let old_id = world.spawn(Wall::new(0.0, 0.0));
+  world.maintain();
+  world.despawn(old_id);
+  assert!(world.is_alive(old_id));
+  world.maintain();
+  assert!(!world.is_alive(old_id));
+
+  let new_id = world.spawn(Wall::new(10.0, 0.0));
+  // The slot is reused, but the new entity only lives after `maintain`
+  assert!(!world.is_alive(new_id));
+  world.maintain();
+  assert!(world.is_alive(new_id));
+  assert_eq!(world.len(), 1);
+  assert_eq!(world.with_mut(new_id, |_, world| world.is_alive(new_id)), Some(true));
+  assert_eq!(new_id.index(), old_id.index());
+  assert_ne!(new_id, old_id);
+  assert!(world.get(old_id).is_none());
+  assert!(world.get(new_id).is_some());
+}
+
+#[test]
+fn despawning_unknown_ids_is_ignored() {
+  let other = World::new();
+  other.spawn(Wall::new(0.0, 0.0));
+  let foreign_id = other.spawn(Wall::new(10.0, 0.0));
+  let mut world = World::new();
+  let id = world.spawn(Wall::new(0.0, 0.0));
+  world.maintain();
+  world.despawn(foreign_id);
+  world.maintain();
+  assert!(world.is_alive(id));
+  assert_eq!(world.len(), 1);
+}
+
+#[test]
+fn downcast_to_concrete_type() {
+  let mut world = World::new();
+  let wall   = world.spawn(Wall::new(0.0, 0.0));
+  let player = world.spawn(Body::new(20.0, 0.0));
+  world.maintain();
+  assert!(world.get_as::<Wall>(wall).is_some());
+  assert!(world.get_as::<Body>(wall).is_none());
+  world.get_mut_as::<Body>(player).unwrap().set_velocity_x(5.0);
+  assert_eq!(world.get_as::<Body>(player).unwrap().velocity(), &Point::new(5.0, 0.0));
+}
+
+#[test]
+fn iterate_in_spawn_order() {
+  let mut world = World::new();
+  let first = world.spawn(Wall::new(0.0, 0.0));
+  let second = world.spawn(Wall::new(10.0, 0.0));
+  world.maintain();
+  world.despawn(first);
+  world.maintain();
+  let third = world.spawn(Wall::new(20.0, 0.0));
+  world.maintain();
+  let ids: Vec<_> = world.iter().map( |(id, _)| id ).collect();
+  assert_eq!(ids, vec![second, third]);
+}
+
+#[test]
+fn move_while_against_other_entities() {
+  let mut world = World::new();
+  world.spawn(Wall::new(30.0, 0.0));
+  let player = world.spawn(Body::new(0.0, 0.0));
+  world.maintain();
+
+  world.with_mut_as( player, |player: &mut Body, world| {
+    player.set_velocity_x(15.0);
+    player.move_while( |rect| world.intersecting(rect).is_empty() );
+  });
+  assert_eq!(world.get(player).unwrap().point(), &Point::new(15.0, 0.0));
+
+  world.with_mut_as( player, |player: &mut Body, world| {
+    player.move_while( |rect| world.intersecting(rect).is_empty() );
+  });
+  assert_eq!(world.get(player).unwrap().point(), &Point::new(20.0, 0.0));
+}
+
+#[test]
+fn spawn_while_updating_an_entity() {
+  let mut world = World::new();
+  let player_id = world.spawn(Body::new(0.0, 0.0));
+  world.maintain();
+  let bullet = world.with_mut_as( player_id, |player: &mut Body, world| {
+    assert!(world.get(player_id).is_none());
+    world.spawn(Wall::new(player.point().x, 0.0))
+  }).unwrap();
+  assert!(world.get(bullet).is_none());
+  world.maintain();
+  assert_eq!(world.len(), 2);
+}
+
+#[derive(Debug, PartialEq)]
+struct BulletFired {
+  x: NumType
+}
+
+#[test]
+fn publish_events_while_updating_an_entity() {
+  let mut world = World::new();
+  let player_id = world.spawn(Body::new(5.0, 0.0));
+  world.maintain();
+  world.with_mut_as( player_id, |player: &mut Body, world| {
+    world.events().publish(BulletFired { x: player.point().x });
+  });
+  world.events_mut().update();
+  assert_eq!(world.events().read::<BulletFired>(), &[BulletFired { x: 5.0 }]);
+}
+
+struct Checkpoint {
+  rect: Rect
+}
+
+impl Mask for Checkpoint {
+  fn point(&self) -> &Point {
+    self.rect.point()
+  }
+  fn point_mut(&mut self) -> &mut Point {
+    self.rect.point_mut()
+  }
+  fn size(&self) -> &Size {
+    self.rect.size()
+  }
+  fn size_mut(&mut self) -> &mut Size {
+    self.rect.size_mut()
+  }
+  fn origin(&self) -> &Origin {
+    self.rect.origin()
+  }
+  fn origin_mut(&mut self) -> &mut Origin {
+    self.rect.origin_mut()
+  }
+}
+
+impl Entity for Checkpoint {
+  fn is_trigger(&self) -> bool {
+    true
+  }
+}
+
+#[test]
+fn trigger_events_are_published() {
+  let mut world = World::new();
+  let checkpoint_id = world.spawn(Checkpoint { rect: Rect::new_simple(20.0, 0.0, 10.0, 10.0) });
+  let player_id     = world.spawn(Body::new(5.0, 0.0));
+  let wall_id       = world.spawn(Wall::new(25.0, 0.0));
+  world.maintain();
+  world.update_triggers();
+  world.events_mut().update();
+  assert_eq!(world.events().read::<TriggerEvent>(), &[
+    TriggerEvent { phase: TriggerPhase::Enter, trigger: checkpoint_id, entity: wall_id }
+  ]);
+
+  world.get_mut(player_id).unwrap().point_mut().set_x(15.0);
+  world.update_triggers();
+  world.events_mut().update();
+  assert_eq!(world.events().read::<TriggerEvent>(), &[
+    TriggerEvent { phase: TriggerPhase::Enter, trigger: checkpoint_id, entity: player_id },
+    TriggerEvent { phase: TriggerPhase::Stay,  trigger: checkpoint_id, entity: wall_id }
+  ]);
+
+  world.despawn(wall_id);
+  world.maintain();
+  world.update_triggers();
+  world.events_mut().update();
+  assert_eq!(world.events().read::<TriggerEvent>(), &[
+    TriggerEvent { phase: TriggerPhase::Stay, trigger: checkpoint_id, entity: player_id },
+    TriggerEvent { phase: TriggerPhase::Exit, trigger: checkpoint_id, entity: wall_id }
+  ]);
+}
+
+#[test]
+fn layers_filter_collisions_and_triggers() {
+  let mut world = World::new();
+  world.spawn(Checkpoint { rect: Rect::new_simple(0.0, 0.0, 10.0, 10.0) });
+  let wall_id = world.spawn(Wall::new(5.0, 0.0));
+  world.maintain();
+  let probe = Rect::new_simple(8.0, 0.0, 4.0, 4.0);
+  // The checkpoint is a trigger, so it doesn't collide
+  assert_eq!(world.colliding(&probe, Layers::DEFAULT), vec![wall_id]);
+  assert_eq!(world.colliding(&probe, Layers::layer(4)), vec![wall_id]);
+
+  world.layer_matrix_mut().set_collides(Layers::layer(4), Layers::DEFAULT, false);
+  world.layer_matrix_mut().set_triggers(Layers::DEFAULT, Layers::DEFAULT, false);
+  assert!(world.colliding(&probe, Layers::layer(4)).is_empty());
+  assert_eq!(world.intersecting(&probe).len(), 2);
+  world.update_triggers();
+  world.events_mut().update();
+  assert!(world.events().read::<TriggerEvent>().is_empty());
+  assert!(world.is_alive(wall_id));
+}